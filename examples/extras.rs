@@ -1484,8 +1484,11 @@ fn log_camera_move_end(_event: On<CameraMoveEnd>, mut log: ResMut<EventLog>) {
 
 fn log_zoom_begin(event: On<ZoomBegin>, mut log: ResMut<EventLog>) {
     log.push(format!(
-        "ZoomBegin\n  margin={:.2}\n  duration={:.0}ms\n  easing={:?}",
-        event.margin,
+        "ZoomBegin\n  margin=L{:.2} R{:.2} T{:.2} B{:.2}\n  duration={:.0}ms\n  easing={:?}",
+        event.margin.left,
+        event.margin.right,
+        event.margin.top,
+        event.margin.bottom,
         event.duration.as_secs_f32() * 1000.0,
         event.easing,
     ));
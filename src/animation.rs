@@ -19,12 +19,14 @@ use crate::events::CameraMoveBegin;
 use crate::events::CameraMoveEnd;
 use crate::events::ZoomCancelled;
 use crate::events::ZoomEnd;
+use crate::support::OrbitLimits;
 
 /// Individual camera movement with target position and duration.
 ///
-/// Two variants allow different ways to specify the target:
+/// Three variants allow different ways to specify the target:
 /// - `ToPosition` — world-space translation + focus (for cinematic sequences)
 /// - `ToOrbit` — orbital parameters around a focus (for zoom-to-fit, avoids gimbal lock)
+/// - `ToOrbitWithFov` — orbital parameters plus a perspective field of view (for lens zooms)
 #[derive(Clone, Reflect)]
 pub enum CameraMove {
     /// Animate to a world-space position looking at a focus point.
@@ -46,12 +48,26 @@ pub enum CameraMove {
         duration: Duration,
         easing:   EaseFunction,
     },
+    /// Animate to orbital parameters around a focus point while changing the
+    /// `PerspectiveProjection::fov` (radians), as for a lens zoom or a dolly zoom.
+    /// Cameras without a perspective projection ignore `fov`.
+    ToOrbitWithFov {
+        focus:    Vec3,
+        yaw:      f32,
+        pitch:    f32,
+        radius:   f32,
+        fov:      f32,
+        duration: Duration,
+        easing:   EaseFunction,
+    },
 }
 
 impl CameraMove {
     pub const fn duration(&self) -> Duration {
         match self {
-            Self::ToPosition { duration, .. } | Self::ToOrbit { duration, .. } => *duration,
+            Self::ToPosition { duration, .. }
+            | Self::ToOrbit { duration, .. }
+            | Self::ToOrbitWithFov { duration, .. } => *duration,
         }
     }
 
@@ -59,13 +75,17 @@ impl CameraMove {
 
     pub const fn easing(&self) -> EaseFunction {
         match self {
-            Self::ToPosition { easing, .. } | Self::ToOrbit { easing, .. } => *easing,
+            Self::ToPosition { easing, .. }
+            | Self::ToOrbit { easing, .. }
+            | Self::ToOrbitWithFov { easing, .. } => *easing,
         }
     }
 
     pub const fn focus(&self) -> Vec3 {
         match self {
-            Self::ToPosition { focus, .. } | Self::ToOrbit { focus, .. } => *focus,
+            Self::ToPosition { focus, .. }
+            | Self::ToOrbit { focus, .. }
+            | Self::ToOrbitWithFov { focus, .. } => *focus,
         }
     }
 
@@ -80,6 +100,13 @@ impl CameraMove {
                 pitch,
                 radius,
                 ..
+            }
+            | Self::ToOrbitWithFov {
+                focus,
+                yaw,
+                pitch,
+                radius,
+                ..
             } => {
                 let yaw_rot = Quat::from_axis_angle(Vec3::Y, *yaw);
                 let pitch_rot = Quat::from_axis_angle(Vec3::X, -*pitch);
@@ -98,9 +125,20 @@ impl CameraMove {
             } => orbital_params_from_offset(*translation - *focus),
             Self::ToOrbit {
                 yaw, pitch, radius, ..
+            }
+            | Self::ToOrbitWithFov {
+                yaw, pitch, radius, ..
             } => (*yaw, *pitch, *radius),
         }
     }
+
+    /// Returns the target field of view, if this move changes it.
+    pub const fn fov(&self) -> Option<f32> {
+        match self {
+            Self::ToOrbitWithFov { fov, .. } => Some(*fov),
+            Self::ToPosition { .. } | Self::ToOrbit { .. } => None,
+        }
+    }
 }
 
 /// Decomposes an offset vector (camera position minus focus) into orbital parameters.
@@ -113,9 +151,29 @@ pub fn orbital_params_from_offset(offset: Vec3) -> (f32, f32, f32) {
     (yaw, pitch, radius)
 }
 
+/// Sets the field of view of a perspective `projection`. Other projections are left unchanged,
+/// and not marked as changed.
+pub fn set_perspective_fov(projection: Option<&mut Mut<Projection>>, fov: f32) {
+    let Some(projection) = projection else {
+        return;
+    };
+    if let Projection::Perspective(perspective) = projection.bypass_change_detection() {
+        perspective.fov = fov;
+        projection.set_changed();
+    }
+}
+
+/// Returns the field of view of a perspective `projection`.
+pub const fn perspective_fov(projection: Option<&Projection>) -> Option<f32> {
+    match projection {
+        Some(Projection::Perspective(perspective)) => Some(perspective.fov),
+        _ => None,
+    }
+}
+
 /// Tolerance for detecting external camera input during animations.
 /// Values within this threshold are considered unchanged (accounts for floating point noise).
-const EXTERNAL_INPUT_TOLERANCE: f32 = 1e-6;
+pub(crate) const EXTERNAL_INPUT_TOLERANCE: f32 = 1e-6;
 
 /// State tracking for the current camera movement
 #[derive(Clone, Reflect, Default, Debug)]
@@ -126,6 +184,8 @@ enum MoveState {
         start_pitch:         f32,
         start_radius:        f32,
         start_yaw:           f32,
+        /// Field of view at the start of the move, for perspective cameras.
+        start_fov:           Option<f32>,
         /// Values written by the animation last frame — if the camera's current
         /// values differ, external input occurred and the animation may interrupt
         /// depending on `CameraInputInterruptBehavior`.
@@ -239,7 +299,7 @@ fn handle_empty_queue(
         commands.entity(entity).remove::<ZoomAnimationMarker>();
         commands.trigger(ZoomEnd {
             camera:   entity,
-            target:   marker.0.target,
+            target:   marker.0.target.clone(),
             margin:   marker.0.margin,
             duration: marker.0.duration,
             easing:   marker.0.easing,
@@ -254,6 +314,7 @@ fn handle_camera_input_interrupt(
     commands: &mut Commands,
     entity: Entity,
     pan_orbit: &mut PanOrbitCamera,
+    projection: Option<&mut Mut<Projection>>,
     queue: &CameraMoveList,
     interrupt_behavior: &CameraInputInterruptBehavior,
     source: AnimationSource,
@@ -276,7 +337,7 @@ fn handle_camera_input_interrupt(
                 commands.entity(entity).remove::<ZoomAnimationMarker>();
                 commands.trigger(ZoomCancelled {
                     camera:   entity,
-                    target:   marker.0.target,
+                    target:   marker.0.target.clone(),
                     margin:   marker.0.margin,
                     duration: marker.0.duration,
                     easing:   marker.0.easing,
@@ -292,7 +353,11 @@ fn handle_camera_input_interrupt(
                 pan_orbit.target_yaw = yaw;
                 pan_orbit.target_pitch = pitch;
                 pan_orbit.target_radius = radius;
+                OrbitLimits::from_camera(pan_orbit).clamp_targets(pan_orbit);
                 pan_orbit.force_update = true;
+                if let Some(fov) = final_move.fov() {
+                    set_perspective_fov(projection, fov);
+                }
             }
             // Fire normal end events
             commands
@@ -306,7 +371,7 @@ fn handle_camera_input_interrupt(
                 commands.entity(entity).remove::<ZoomAnimationMarker>();
                 commands.trigger(ZoomEnd {
                     camera:   entity,
-                    target:   marker.0.target,
+                    target:   marker.0.target.clone(),
                     margin:   marker.0.margin,
                     duration: marker.0.duration,
                     easing:   marker.0.easing,
@@ -323,6 +388,7 @@ fn handle_ready_state(
     commands: &mut Commands,
     entity: Entity,
     pan_orbit: &mut PanOrbitCamera,
+    projection: Option<&mut Mut<Projection>>,
     queue: &mut CameraMoveList,
     current_move: &CameraMove,
 ) -> bool {
@@ -337,7 +403,11 @@ fn handle_ready_state(
         pan_orbit.target_radius = target_radius;
         pan_orbit.target_yaw = target_yaw;
         pan_orbit.target_pitch = target_pitch;
+        OrbitLimits::from_camera(pan_orbit).clamp_targets(pan_orbit);
         pan_orbit.force_update = true;
+        if let Some(fov) = current_move.fov() {
+            set_perspective_fov(projection, fov);
+        }
 
         commands.trigger(CameraMoveEnd {
             camera:      entity,
@@ -354,6 +424,7 @@ fn handle_ready_state(
         start_radius:        pan_orbit.target_radius,
         start_yaw:           pan_orbit.target_yaw,
         start_pitch:         pan_orbit.target_pitch,
+        start_fov:           perspective_fov(projection.as_deref().map(|projection| &**projection)),
         last_written_focus:  pan_orbit.target_focus,
         last_written_yaw:    pan_orbit.target_yaw,
         last_written_pitch:  pan_orbit.target_pitch,
@@ -374,6 +445,7 @@ fn handle_in_progress(
     commands: &mut Commands,
     entity: Entity,
     pan_orbit: &mut PanOrbitCamera,
+    projection: Option<&mut Mut<Projection>>,
     queue: &mut CameraMoveList,
     current_move: &CameraMove,
     delta_secs: f32,
@@ -384,6 +456,7 @@ fn handle_in_progress(
         start_radius,
         start_yaw,
         start_pitch,
+        start_fov,
         last_written_focus,
         last_written_yaw,
         last_written_pitch,
@@ -437,7 +510,17 @@ fn handle_in_progress(
     pan_orbit.target_radius = (canonical_radius - *start_radius).mul_add(t_interp, *start_radius);
     pan_orbit.target_yaw = yaw_diff.mul_add(t_interp, *start_yaw);
     pan_orbit.target_pitch = pitch_diff.mul_add(t_interp, *start_pitch);
+    // Stop at the camera's limits rather than running past them and snapping back.
+    OrbitLimits::from_camera(pan_orbit).clamp_targets(pan_orbit);
     pan_orbit.force_update = true;
+    if let (Some(start_fov), Some(fov)) = (*start_fov, current_move.fov()) {
+        let fov = if is_final_frame {
+            fov
+        } else {
+            (fov - start_fov).mul_add(t_interp, start_fov)
+        };
+        set_perspective_fov(projection, fov);
+    }
 
     // Save what we wrote so we can detect external changes next frame
     *last_written_focus = pan_orbit.target_focus;
@@ -472,10 +555,18 @@ pub fn process_camera_move_list(
         &CameraInputInterruptBehavior,
         Option<&ZoomAnimationMarker>,
         Option<&AnimationSourceMarker>,
+        Option<&mut Projection>,
     )>,
 ) {
-    for (entity, mut pan_orbit, mut queue, interrupt_behavior, zoom_marker, source_marker) in
-        &mut camera_query
+    for (
+        entity,
+        mut pan_orbit,
+        mut queue,
+        interrupt_behavior,
+        zoom_marker,
+        source_marker,
+        mut projection,
+    ) in &mut camera_query
     {
         let source = source_marker.map_or(AnimationSource::PlayAnimation, |m| m.0);
 
@@ -489,6 +580,7 @@ pub fn process_camera_move_list(
                 &mut commands,
                 entity,
                 &mut pan_orbit,
+                projection.as_mut(),
                 &queue,
                 interrupt_behavior,
                 source,
@@ -509,6 +601,7 @@ pub fn process_camera_move_list(
                     &mut commands,
                     entity,
                     &mut pan_orbit,
+                    projection.as_mut(),
                     &mut queue,
                     &current_move,
                 ) {
@@ -520,6 +613,7 @@ pub fn process_camera_move_list(
                     &mut commands,
                     entity,
                     &mut pan_orbit,
+                    projection.as_mut(),
                     &mut queue,
                     &current_move,
                     time.delta_secs(),
@@ -0,0 +1,126 @@
+//! Solving fits on the `AsyncComputeTaskPool` for cameras with [`FitExecution::Async`].
+
+use std::time::Duration;
+
+use bevy::ecs::system::SystemParam;
+use bevy::prelude::*;
+use bevy::tasks::AsyncComputeTaskPool;
+use bevy::tasks::Task;
+use bevy::tasks::futures::check_ready;
+use bevy_panorbit_camera::PanOrbitCamera;
+
+use crate::animation::CameraMoveList;
+use crate::components::AnimationConflictPolicy;
+use crate::components::FitExecution;
+use crate::events::AnimationRejected;
+use crate::fit::FitError;
+use crate::fit::FitSolution;
+use crate::observers::FitInputs;
+use crate::observers::FitJob;
+use crate::observers::FitRequest;
+use crate::observers::complete_fit;
+
+/// A fit being solved in the background for this camera.
+///
+/// Present from the fit event until its solve finishes, when the fit animates (or snaps) exactly
+/// as an [`Immediate`](FitExecution::Immediate) fit would have. Removing this component cancels
+/// the fit without any lifecycle events; a newer fit request (even one that fails) or an accepted
+/// [`PlayAnimation`](crate::PlayAnimation) under
+/// [`LastWins`](AnimationConflictPolicy::LastWins) does so.
+#[derive(Component)]
+pub struct PendingFit {
+    task:    Task<Result<FitSolution, FitError>>,
+    request: FitRequest,
+}
+
+/// Hands fits to the `AsyncComputeTaskPool` for cameras with [`FitExecution::Async`].
+#[derive(SystemParam)]
+#[allow(clippy::type_complexity)]
+pub struct AsyncFits<'w, 's> {
+    cameras: Query<
+        'w,
+        's,
+        (
+            Option<&'static FitExecution>,
+            Option<&'static AnimationConflictPolicy>,
+            Has<PendingFit>,
+            Has<CameraMoveList>,
+        ),
+    >,
+}
+
+impl AsyncFits<'_, '_> {
+    /// Returns `true` if fits on `camera` are solved in the background.
+    pub fn is_async(&self, camera: Entity) -> bool {
+        self.cameras.get(camera).is_ok_and(|(execution, ..)| {
+            execution.copied().unwrap_or_default() == FitExecution::Async
+        })
+    }
+
+    /// Cancels the fit pending on `camera` for a newer request that failed before it could be
+    /// spawned, as [`spawn`](Self::spawn) would have replaced it. Under
+    /// [`FirstWins`](AnimationConflictPolicy::FirstWins) the pending fit keeps running.
+    pub fn cancel_superseded(&self, commands: &mut Commands, camera: Entity) {
+        let Ok((_, policy, pending, _)) = self.cameras.get(camera) else {
+            return;
+        };
+        if pending && policy.copied().unwrap_or_default() == AnimationConflictPolicy::LastWins {
+            commands.entity(camera).remove::<PendingFit>();
+        }
+    }
+
+    /// Starts solving `job` in the background, replacing (and so cancelling) any fit already
+    /// pending on the camera — unless the camera's [`AnimationConflictPolicy`] is
+    /// [`FirstWins`](AnimationConflictPolicy::FirstWins), which rejects the new request instead.
+    /// `FirstWins` also rejects an animated request while an animation is playing, as it would
+    /// once solved; instant fits snap regardless, as immediate ones do.
+    pub fn spawn(
+        &self,
+        commands: &mut Commands,
+        request: FitRequest,
+        inputs: FitInputs,
+        job: FitJob,
+    ) {
+        let Ok((_, policy, pending, animating)) = self.cameras.get(request.camera) else {
+            return;
+        };
+        let animated = request.duration > Duration::ZERO;
+        if (pending || (animating && animated))
+            && policy.copied().unwrap_or_default() == AnimationConflictPolicy::FirstWins
+        {
+            commands.trigger(AnimationRejected {
+                camera: request.camera,
+                source: request.source,
+            });
+            return;
+        }
+
+        let task = AsyncComputeTaskPool::get().spawn(async move { inputs.solve(job) });
+        commands
+            .entity(request.camera)
+            .insert(PendingFit { task, request });
+    }
+}
+
+/// System that applies background fits once their solve finishes.
+pub fn poll_pending_fits(
+    mut commands: Commands,
+    mut camera_query: Query<(
+        Entity,
+        &mut PendingFit,
+        &mut PanOrbitCamera,
+        &mut Projection,
+    )>,
+) {
+    for (entity, mut pending, mut panorbit, mut projection) in &mut camera_query {
+        let Some(result) = check_ready(&mut pending.task) else {
+            continue;
+        };
+        commands.entity(entity).remove::<PendingFit>();
+        let request = pending.request.clone();
+        match result {
+            Ok(fit) => complete_fit(&mut commands, &mut panorbit, &mut projection, request, &fit),
+            Err(reason) => request.fail(&mut commands, reason),
+        }
+    }
+}
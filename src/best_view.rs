@@ -0,0 +1,322 @@
+//! Viewing angle selection for [`AutoFit`](crate::AutoFit).
+//!
+//! Every [`BestView`] strategy frames the target with [`calculate_fit`](crate::fit::calculate_fit)
+//! from the orientations it considers, so candidates are compared at the radius and focus they
+//! would actually be viewed from.
+
+use std::f32::consts::TAU;
+
+use bevy::prelude::*;
+
+use crate::convex_hull::convex_hull_2d;
+use crate::events::BestView;
+use crate::fit::FitError;
+use crate::fit::FitSolution;
+use crate::projection::FitProjection;
+use crate::support::CameraBasis;
+use crate::support::project_point;
+
+/// Number of evenly spaced yaws tried per pitch, starting from the current yaw.
+const CANDIDATE_YAW_STEPS: usize = 16;
+/// Pitches tried, in degrees. Views from below the target rarely suit an inspection view, so
+/// candidates look level or down at it.
+const CANDIDATE_PITCHES_DEGREES: [f32; 5] = [0.0, 15.0, 30.0, 45.0, 60.0];
+/// Candidates whose silhouette fill is within this fraction of the best are considered equally
+/// good; the one closest to the current view wins.
+const FILL_TIE_TOLERANCE: f32 = 0.01;
+/// [`BestView::AvoidEdgeOn`] keeps a view whose silhouette fill is at least this fraction of the
+/// best candidate's.
+const EDGE_ON_FILL_RATIO: f32 = 0.5;
+/// Maximum Jacobi sweeps when diagonalizing the covariance matrix.
+const JACOBI_MAX_SWEEPS: usize = 16;
+
+/// A fitted candidate orientation and how much of the frame its silhouette fills.
+struct ScoredView {
+    fit:      FitSolution,
+    fill:     f32,
+    /// Angle between this view direction and the current one, in radians.
+    rotation: f32,
+}
+
+/// Chooses the orientation to frame `points` from and returns the fit for it.
+///
+/// `current` is the camera's current `(yaw, pitch)`, used to break ties and, for
+/// [`BestView::AvoidEdgeOn`], as the preferred view. `fit` frames the points from a yaw and pitch
+/// (clamped to the camera's limits) with the caller's margins, frame and projection.
+pub fn choose_best_view(
+    view: BestView,
+    points: &[Vec3],
+    current: (f32, f32),
+    projection: &dyn FitProjection,
+    fit: impl Fn(f32, f32) -> Result<FitSolution, FitError>,
+) -> Result<FitSolution, FitError> {
+    let (current_yaw, current_pitch) = current;
+    if view == BestView::PrincipalAxes {
+        let (yaw, pitch) = principal_axes_view(points, view_direction(current_yaw, current_pitch))
+            .unwrap_or(current);
+        return fit(yaw, pitch);
+    }
+
+    let current_direction = view_direction(current_yaw, current_pitch);
+    let mut candidates = vec![current];
+    candidates.extend(CANDIDATE_PITCHES_DEGREES.iter().flat_map(|pitch| {
+        (0..CANDIDATE_YAW_STEPS).map(move |step| {
+            (
+                current_yaw + TAU * step as f32 / CANDIDATE_YAW_STEPS as f32,
+                pitch.to_radians(),
+            )
+        })
+    }));
+
+    let mut first_error = None;
+    let scored: Vec<ScoredView> = candidates
+        .into_iter()
+        .filter_map(|(yaw, pitch)| match fit(yaw, pitch) {
+            Ok(fit) => {
+                let fill = silhouette_fill(points, &fit, projection)?;
+                let rotation = view_direction(fit.yaw, fit.pitch).angle_between(current_direction);
+                Some(ScoredView {
+                    fit,
+                    fill,
+                    rotation,
+                })
+            },
+            Err(error) => {
+                first_error.get_or_insert(error);
+                None
+            },
+        })
+        .collect();
+
+    let best_fill = scored.iter().map(|view| view.fill).fold(0.0, f32::max);
+    let threshold = match view {
+        BestView::AvoidEdgeOn => {
+            // The current view is always the first candidate.
+            if let Some(current) = scored.first().filter(|view| view.rotation == 0.0)
+                && current.fill >= best_fill * EDGE_ON_FILL_RATIO
+            {
+                return Ok(current.fit);
+            }
+            best_fill * EDGE_ON_FILL_RATIO
+        },
+        BestView::MaxSilhouette | BestView::PrincipalAxes => best_fill * (1.0 - FILL_TIE_TOLERANCE),
+    };
+    scored
+        .into_iter()
+        .filter(|view| view.fill >= threshold)
+        .min_by(|a, b| a.rotation.total_cmp(&b.rotation))
+        .map(|view| view.fit)
+        .ok_or(first_error.unwrap_or(FitError::PointsBehindCamera))
+}
+
+/// Direction from the focus to a camera at `yaw` and `pitch`.
+fn view_direction(yaw: f32, pitch: f32) -> Vec3 {
+    Quat::from_euler(EulerRot::YXZ, yaw, -pitch, 0.0) * Vec3::Z
+}
+
+/// Area of the fitted silhouette's convex hull in normalized screen units, relative to the
+/// visible area at the fitted radius. Only comparable between fits with the same margins, frame
+/// and projection.
+fn silhouette_fill(
+    points: &[Vec3],
+    fit: &FitSolution,
+    projection: &dyn FitProjection,
+) -> Option<f32> {
+    let rot = Quat::from_euler(EulerRot::YXZ, fit.yaw, -fit.pitch, 0.0);
+    let cam_pos = fit.focus + rot * Vec3::new(0.0, 0.0, projection.camera_distance(fit.radius));
+    let cam = CameraBasis::from_global_transform(&GlobalTransform::from(
+        Transform::from_translation(cam_pos).with_rotation(rot),
+    ));
+    let projected: Vec<(f32, f32)> = points
+        .iter()
+        .map(|point| project_point(*point, &cam, projection).map(|(x, y, _)| (x, y)))
+        .collect::<Option<_>>()?;
+
+    let hull = convex_hull_2d(&projected);
+    let area = hull
+        .iter()
+        .zip(hull.iter().cycle().skip(1))
+        .map(|(a, b)| a.0 * b.1 - b.0 * a.1)
+        .sum::<f32>()
+        .abs()
+        * 0.5;
+    // Field-of-view fits widen the visible area by `tan(fov / 2)` instead of the radius.
+    let scale =
+        projection.radius_extent_scale(fit.radius) * fit.fov.map_or(1.0, |fov| (fov * 0.5).tan());
+    Some(area / (scale * scale))
+}
+
+/// The 3/4 view along the diagonal of the points' principal axes, choosing among the eight
+/// diagonals the one from above the target closest to `current_direction`. Returns `None` for
+/// an empty point set.
+fn principal_axes_view(points: &[Vec3], current_direction: Vec3) -> Option<(f32, f32)> {
+    let axes = principal_axes(points)?;
+    let direction = (0..8)
+        .map(|signs: u8| {
+            let sign = |bit: u8| if signs & bit == 0 { 1.0 } else { -1.0 };
+            (axes.x_axis * sign(1) + axes.y_axis * sign(2) + axes.z_axis * sign(4)).normalize()
+        })
+        .filter(|direction| direction.y >= 0.0)
+        .max_by(|a, b| {
+            a.dot(current_direction)
+                .total_cmp(&b.dot(current_direction))
+        })?;
+
+    let yaw = direction.x.atan2(direction.z);
+    let pitch = direction.y.atan2(direction.x.hypot(direction.z));
+    Some((yaw, pitch))
+}
+
+/// Eigenvectors of the points' covariance matrix (as columns), found with cyclic Jacobi
+/// rotations. Returns `None` for an empty point set.
+fn principal_axes(points: &[Vec3]) -> Option<Mat3> {
+    if points.is_empty() {
+        return None;
+    }
+    let mean = points.iter().copied().sum::<Vec3>() / points.len() as f32;
+    let mut covariance = [[0.0_f32; 3]; 3];
+    for point in points {
+        let d = *point - mean;
+        for (row, covariance_row) in covariance.iter_mut().enumerate() {
+            for (col, value) in covariance_row.iter_mut().enumerate() {
+                *value += d[row] * d[col];
+            }
+        }
+    }
+
+    let mut a = Mat3::from_cols_array_2d(&covariance);
+    let mut eigenvectors = Mat3::IDENTITY;
+    let scale = a
+        .to_cols_array()
+        .iter()
+        .map(|v| v.abs())
+        .fold(0.0, f32::max);
+    for _ in 0..JACOBI_MAX_SWEEPS {
+        let mut rotated = false;
+        for (p, q) in [(0, 1), (0, 2), (1, 2)] {
+            let a_pq = a.col(q)[p];
+            if a_pq.abs() <= scale * f32::EPSILON {
+                continue;
+            }
+            rotated = true;
+            let theta = 0.5 * (2.0 * a_pq).atan2(a.col(p)[p] - a.col(q)[q]);
+            let (sin, cos) = theta.sin_cos();
+            let mut rotation = Mat3::IDENTITY;
+            rotation.col_mut(p)[p] = cos;
+            rotation.col_mut(p)[q] = sin;
+            rotation.col_mut(q)[p] = -sin;
+            rotation.col_mut(q)[q] = cos;
+            a = rotation.transpose() * a * rotation;
+            eigenvectors *= rotation;
+        }
+        if !rotated {
+            break;
+        }
+    }
+    Some(eigenvectors)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::events::FitMargins;
+    use crate::fit::FitParams;
+    use crate::fit::calculate_fit;
+    use crate::support::OrbitLimits;
+
+    /// A box stretched along `half_size` and rotated by `rotation`, sampled at its corners.
+    fn box_corners(half_size: Vec3, rotation: Quat) -> Vec<Vec3> {
+        (0..8)
+            .map(|i| {
+                let sign = |bit: i32| if i & bit == 0 { -1.0 } else { 1.0 };
+                rotation * (half_size * Vec3::new(sign(1), sign(2), sign(4)))
+            })
+            .collect()
+    }
+
+    fn choose(view: BestView, points: &[Vec3], current: (f32, f32)) -> FitSolution {
+        let projection = PerspectiveProjection::default();
+        choose_best_view(view, points, current, &projection, |yaw, pitch| {
+            calculate_fit(
+                points,
+                Vec3::ZERO,
+                &FitParams {
+                    yaw,
+                    pitch,
+                    margins: FitMargins::uniform(0.1),
+                    ..default()
+                },
+                &projection,
+                &Camera::default(),
+                &OrbitLimits::default(),
+            )
+        })
+        .expect("box should produce a valid fit")
+    }
+
+    #[test]
+    fn principal_axes_of_rotated_box_follow_its_edges() {
+        let rotation = Quat::from_rotation_y(0.4);
+        let axes = principal_axes(&box_corners(Vec3::new(3.0, 1.0, 0.5), rotation))
+            .expect("box corners should have principal axes");
+
+        for local in [Vec3::X, Vec3::Y, Vec3::Z] {
+            let expected = rotation * local;
+            let aligned = [axes.x_axis, axes.y_axis, axes.z_axis]
+                .iter()
+                .any(|axis| axis.dot(expected).abs() > 0.999);
+            assert!(
+                aligned,
+                "{expected:?} should be a principal axis of {axes:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn principal_axes_view_looks_down_a_diagonal_of_the_box() {
+        let rotation = Quat::from_rotation_y(0.4);
+        let points = box_corners(Vec3::new(3.0, 1.0, 0.5), rotation);
+
+        let fit = choose(BestView::PrincipalAxes, &points, (0.4, 0.2));
+
+        let direction = rotation.inverse() * view_direction(fit.yaw, fit.pitch);
+        assert!(fit.pitch > 0.0, "view should look down at the box");
+        for component in direction.to_array() {
+            assert!(
+                (component.abs() - 1.0 / 3.0_f32.sqrt()).abs() < 1e-3,
+                "view {direction:?} should be a diagonal of the box's axes"
+            );
+        }
+    }
+
+    #[test]
+    fn max_silhouette_turns_away_from_edge_on_view() {
+        // A thin plate seen edge-on from the front.
+        let points = box_corners(Vec3::new(2.0, 2.0, 0.01), Quat::from_rotation_y(TAU / 4.0));
+
+        let edge_on = choose(BestView::AvoidEdgeOn, &points, (0.0, 0.0));
+        let best = choose(BestView::MaxSilhouette, &points, (0.0, 0.0));
+
+        let projection = PerspectiveProjection::default();
+        let best_fill = silhouette_fill(&points, &best, &projection).expect("best view projects");
+        let edge_on_fill =
+            silhouette_fill(&points, &edge_on, &projection).expect("edge-on view projects");
+        assert!(
+            edge_on_fill >= best_fill * EDGE_ON_FILL_RATIO,
+            "AvoidEdgeOn should leave the edge-on view ({edge_on_fill} vs best {best_fill})"
+        );
+        assert!(
+            view_direction(best.yaw, best.pitch).x.abs() > 0.9,
+            "the plate faces +X, so the best view should look along X"
+        );
+    }
+
+    #[test]
+    fn avoid_edge_on_keeps_a_good_current_view() {
+        let points = box_corners(Vec3::new(1.0, 0.8, 0.6), Quat::IDENTITY);
+
+        let fit = choose(BestView::AvoidEdgeOn, &points, (0.3, 0.25));
+
+        assert_eq!((fit.yaw, fit.pitch), (0.3, 0.25));
+    }
+}
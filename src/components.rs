@@ -3,6 +3,12 @@
 use bevy::prelude::*;
 
 use crate::events::AnimationSource;
+use crate::events::FitAnchor;
+use crate::events::FitFrame;
+use crate::events::FitMargins;
+use crate::events::FitPose;
+use crate::events::FitTarget;
+use crate::events::FitVolume;
 use crate::events::ZoomContext;
 
 /// Controls what happens when **user input to the camera** (orbit, pan, zoom) occurs during an
@@ -21,6 +27,8 @@ use crate::events::ZoomContext;
 ///   `*Cancelled` events
 /// - [`Complete`](CameraInputInterruptBehavior::Complete) — jump to the final position of the
 ///   entire queue and fire normal `*End` events
+///
+/// The same setting decides how a [`TrackTarget`](crate::TrackTarget) responds to user input.
 #[derive(Component, Reflect, Default, Clone, Copy, Debug, PartialEq, Eq)]
 #[reflect(Component, Default)]
 pub enum CameraInputInterruptBehavior {
@@ -48,6 +56,10 @@ pub enum CameraInputInterruptBehavior {
 ///   new one. Fires appropriate `*Cancelled` events for the interrupted operation.
 /// - [`FirstWins`](AnimationConflictPolicy::FirstWins) — reject the incoming request. Fires
 ///   [`AnimationRejected`](crate::AnimationRejected).
+///
+/// A [`PendingFit`](crate::PendingFit) still solving in the background and a
+/// [`DeferredFit`](crate::DeferredFit) waiting for meshes to load count as in flight; `LastWins`
+/// drops them without lifecycle events, since neither has begun.
 #[derive(Component, Reflect, Default, Clone, Copy, Debug, PartialEq, Eq)]
 #[reflect(Component, Default)]
 pub enum AnimationConflictPolicy {
@@ -56,11 +68,159 @@ pub enum AnimationConflictPolicy {
     FirstWins,
 }
 
-/// Marks the entity that the camera is currently fitted to.
+/// Selects how fits on this camera solve for the radius and focus.
+///
+/// Insert this component on a camera entity to choose the solver used by
+/// [`ZoomToFit`](crate::ZoomToFit), [`AnimateToFit`](crate::AnimateToFit),
+/// [`AutoFit`](crate::AutoFit) and [`LookAtAndZoomToFit`](crate::LookAtAndZoomToFit). If not
+/// present, defaults to [`BinarySearch`](FitAlgorithm::BinarySearch). Both produce the same
+/// framing.
+///
+/// - [`BinarySearch`](FitAlgorithm::BinarySearch) — searches the radius, re-projecting and
+///   re-centering every point at each step.
+/// - [`Analytic`](FitAlgorithm::Analytic) — solves the radius and focus directly in a few passes
+///   over the points, for fitting meshes with millions of vertices within a frame. Falls back to
+///   the binary search when it has no solution, such as when points would end up behind the camera.
+#[derive(Component, Reflect, Default, Clone, Copy, Debug, PartialEq, Eq)]
+#[reflect(Component, Default)]
+pub enum FitAlgorithm {
+    #[default]
+    BinarySearch,
+    Analytic,
+}
+
+/// Selects what fits on this camera change to frame the target.
+///
+/// Insert this component on a camera entity to keep the camera where it is and zoom the lens
+/// instead, like a security camera on a pole or a dolly-zoom setup. If not present, defaults to
+/// [`Radius`](FitStrategy::Radius).
+///
+/// - [`Radius`](FitStrategy::Radius) — solves the orbit radius (the scale for orthographic cameras)
+///   and keeps the projection as is.
+/// - [`FieldOfView`](FitStrategy::FieldOfView) — holds the current radius and solves
+///   `PerspectiveProjection::fov`. Animated fits change it through
+///   [`CameraMove::ToOrbitWithFov`](crate::CameraMove::ToOrbitWithFov). Cameras without a
+///   perspective projection fit by radius.
+#[derive(Component, Reflect, Default, Clone, Copy, Debug, PartialEq, Eq)]
+#[reflect(Component, Default)]
+pub enum FitStrategy {
+    #[default]
+    Radius,
+    FieldOfView,
+}
+
+/// Selects where fits on this camera are solved.
+///
+/// Insert this component on a camera entity whose fit targets are large enough that solving a
+/// fit would stall a frame. If not present, defaults to [`Immediate`](FitExecution::Immediate).
+///
+/// - [`Immediate`](FitExecution::Immediate) — solves inside the fit event's observer and starts the
+///   animation in the same frame.
+/// - [`Async`](FitExecution::Async) — snapshots the target's world-space points, solves on the
+///   `AsyncComputeTaskPool` and starts the animation in the frame the solve finishes. The camera
+///   carries a [`PendingFit`](crate::PendingFit) until then, which the [`AnimationConflictPolicy`]
+///   treats like a playing animation; a newer fit replaces a pending one.
+#[derive(Component, Reflect, Default, Clone, Copy, Debug, PartialEq, Eq)]
+#[reflect(Component, Default)]
+pub enum FitExecution {
+    #[default]
+    Immediate,
+    Async,
+}
+
+/// Selects which meshes under an entity target fits on this camera frame.
+///
+/// Insert this component on a camera entity to leave hidden meshes or meshes the camera does
+/// not render out of its fits and its visualization. If not present, every `Mesh3d` under the
+/// target counts, except those marked [`FitIgnore`].
+///
+/// - `visible_only` — skips meshes whose `InheritedVisibility` is false.
+/// - `render_layers` — skips meshes whose `RenderLayers` share no layer with the camera's (both
+///   default to layer 0 when absent).
+#[derive(Component, Reflect, Default, Clone, Copy, Debug, PartialEq, Eq)]
+#[reflect(Component, Default)]
+pub struct FitFilter {
+    pub visible_only:  bool,
+    pub render_layers: bool,
+}
+
+/// Configures fits on this camera for targets with little or no extent, such as an empty marker
+/// entity, a single vertex or a zero-scale mesh.
+///
+/// Insert this component on a camera entity to frame such targets like any other. If not
+/// present, there is no fallback: a target whose points all coincide fails with
+/// [`FitError::ZeroExtent`](crate::FitError::ZeroExtent), and an entity without meshes with
+/// [`FitError::NoMesh`](crate::FitError::NoMesh).
+///
+/// - `min_radius` — the smallest radius fits frame a target at. A target whose points all lie
+///   within this distance of their center is framed as a sphere of this radius around it, and the
+///   fit reports [`FitLimits::min_radius`](crate::FitLimits::min_radius).
+/// - `use_transform` — frames entity targets with no `Mesh3d` at their `GlobalTransform`
+///   translation (the mean translation for groups), as a point framed at `min_radius`, instead of
+///   failing. Targets whose meshes are all left out by [`FitIgnore`] or [`FitFilter`] still fail. A
+///   fit waiting for its target to load ([`ZoomToFit::wait_for_load`](crate::ZoomToFit)) treats
+///   such an entity as loaded once it has a `GlobalTransform`.
+#[derive(Component, Reflect, Default, Clone, Copy, Debug, PartialEq)]
+#[reflect(Component, Default)]
+pub struct FitFallback {
+    pub min_radius:    f32,
+    pub use_transform: bool,
+}
+
+impl FitFallback {
+    /// Frames targets smaller than `min_radius`, including entities without meshes, as a sphere
+    /// of that radius.
+    pub const fn new(min_radius: f32) -> Self {
+        Self {
+            min_radius,
+            use_transform: true,
+        }
+    }
+}
+
+/// Leaves this entity and its descendants out of fits on any camera, e.g. a ground-shadow quad,
+/// a debug helper or a collider mesh under a model. A target marked itself has nothing to frame
+/// and fails with [`FitError::NoMesh`](crate::FitError::NoMesh).
+#[derive(Component, Reflect, Default, Clone, Copy, Debug)]
+#[reflect(Component, Default)]
+pub struct FitIgnore;
+
+/// Marks the [`FitTarget`] (entity or group of entities) that the camera is currently fitted to.
 /// Persists after fit completes to enable persistent visualization.
 #[derive(Component, Reflect, Debug)]
 #[reflect(Component)]
-pub struct CurrentFitTarget(pub Entity);
+pub struct CurrentFitTarget(pub FitTarget);
+
+/// The framing options requested by the most recent fit (or [`SetFitTarget`](crate::SetFitTarget))
+/// on this camera. Kept alongside [`CurrentFitTarget`] so the visualization draws the margins,
+/// frame, anchor and silhouette that were fitted, and [`MaintainFit`](crate::MaintainFit) re-fits
+/// with them. The `frame` keeps [`FitFrame::Unoccluded`] unresolved so it follows panel changes.
+#[derive(Component, Reflect, Debug, Clone, Copy, Default)]
+#[reflect(Component, Default)]
+pub struct CurrentFitOptions {
+    pub margin: FitMargins,
+    pub frame:  FitFrame,
+    pub anchor: FitAnchor,
+    pub volume: FitVolume,
+    pub pose:   FitPose,
+}
+
+/// The part of the camera's viewport not covered by UI, in normalized viewport coordinates
+/// (origin top-left, `y` down). Fits requested with [`FitFrame::Unoccluded`] frame the target
+/// inside this rectangle.
+///
+/// With the `ui_occluders` feature this is kept up to date from `bevy_ui` nodes marked with
+/// `FitOccluder`; otherwise insert and update it yourself.
+#[derive(Component, Reflect, Debug, Clone, Copy, PartialEq)]
+#[reflect(Component)]
+pub struct UnoccludedViewport(pub Rect);
+
+/// Marks a `bevy_ui` node that covers part of the 3D view, such as a side panel or toolbar.
+/// The node's rectangle is excluded from the [`UnoccludedViewport`] of the camera it renders to.
+#[cfg(feature = "ui_occluders")]
+#[derive(Component, Reflect, Default)]
+#[reflect(Component, Default)]
+pub struct FitOccluder;
 
 /// Marker component that tracks a zoom-to-fit operation routed through the animation system.
 /// When `AnimationEnd` fires on an entity with this marker, `ZoomEnd` is triggered and the
@@ -0,0 +1,353 @@
+//! Convex hulls used to reduce fit geometry: 2D (Andrew's monotone chain) for screen-space
+//! silhouettes and planar point sets, and 3D (quickhull) for
+//! [`FitVolume::ConvexHull`](crate::FitVolume::ConvexHull).
+
+use std::collections::HashSet;
+
+use bevy::prelude::*;
+
+/// Relative tolerance (scaled by the point set's extent) for treating a point as on a hull
+/// plane rather than outside it.
+const HULL_EPSILON: f32 = 1e-5;
+
+/// 2D cross product for three points (for convex hull turn detection).
+fn cross_2d(o: (f32, f32), a: (f32, f32), b: (f32, f32)) -> f32 {
+    (a.0 - o.0) * (b.1 - o.1) - (a.1 - o.1) * (b.0 - o.0)
+}
+
+/// Andrew's monotone chain algorithm for 2D convex hull.
+/// Returns hull vertices in counter-clockwise order. Non-finite points are ignored.
+pub fn convex_hull_2d(points: &[(f32, f32)]) -> Vec<(f32, f32)> {
+    let mut sorted: Vec<(f32, f32)> = points
+        .iter()
+        .copied()
+        .filter(|(x, y)| x.is_finite() && y.is_finite())
+        .collect();
+    sorted.sort_by(|a, b| a.0.total_cmp(&b.0).then(a.1.total_cmp(&b.1)));
+    sorted.dedup();
+
+    if sorted.len() <= 1 {
+        return sorted;
+    }
+
+    let mut lower: Vec<(f32, f32)> = Vec::new();
+    for &p in &sorted {
+        while lower.len() >= 2 && cross_2d(lower[lower.len() - 2], lower[lower.len() - 1], p) <= 0.0
+        {
+            lower.pop();
+        }
+        lower.push(p);
+    }
+
+    let mut upper: Vec<(f32, f32)> = Vec::new();
+    for &p in sorted.iter().rev() {
+        while upper.len() >= 2 && cross_2d(upper[upper.len() - 2], upper[upper.len() - 1], p) <= 0.0
+        {
+            upper.pop();
+        }
+        upper.push(p);
+    }
+
+    lower.pop();
+    upper.pop();
+
+    lower.extend(upper);
+    lower
+}
+
+/// A hull face with an outward normal and the points still outside it.
+struct HullFace {
+    vertices: [usize; 3],
+    normal:   Vec3,
+    offset:   f32,
+    outside:  Vec<usize>,
+    alive:    bool,
+}
+
+impl HullFace {
+    fn new(points: &[Vec3], a: usize, b: usize, c: usize) -> Self {
+        let normal = (points[b] - points[a])
+            .cross(points[c] - points[a])
+            .normalize_or_zero();
+        Self {
+            vertices: [a, b, c],
+            normal,
+            offset: normal.dot(points[a]),
+            outside: Vec::new(),
+            alive: true,
+        }
+    }
+
+    fn distance(&self, point: Vec3) -> f32 { self.normal.dot(point) - self.offset }
+
+    fn edges(&self) -> [(usize, usize); 3] {
+        let [a, b, c] = self.vertices;
+        [(a, b), (b, c), (c, a)]
+    }
+}
+
+/// Returns the vertices of the 3D convex hull of `points` (quickhull). Every point of the input
+/// projects inside the silhouette of the result from any view, so fitting the hull frames the
+/// same region as fitting all points.
+///
+/// Coplanar input falls back to the 2D hull within the plane, and collinear input to the two
+/// extreme points. Non-finite points are ignored.
+pub fn convex_hull_3d(points: &[Vec3]) -> Vec<Vec3> {
+    let points: Vec<Vec3> = points.iter().copied().filter(|p| p.is_finite()).collect();
+    if points.len() < 4 {
+        return points;
+    }
+
+    let (min, max) = points
+        .iter()
+        .fold((Vec3::INFINITY, Vec3::NEG_INFINITY), |(min, max), p| {
+            (min.min(*p), max.max(*p))
+        });
+    let epsilon = HULL_EPSILON * (max - min).max_element().max(f32::EPSILON);
+
+    // Initial simplex: the farthest pair among the axis extremes, then the point farthest from
+    // their line, then the point farthest from their plane.
+    let extremes: Vec<usize> = (0..3)
+        .flat_map(|axis| {
+            [
+                farthest(&points, |p| -p[axis]),
+                farthest(&points, |p| p[axis]),
+            ]
+        })
+        .collect();
+    let mut p0 = extremes[0];
+    let mut p1 = extremes[1];
+    for &i in &extremes {
+        for &j in &extremes {
+            if points[i].distance_squared(points[j]) > points[p0].distance_squared(points[p1]) {
+                (p0, p1) = (i, j);
+            }
+        }
+    }
+    if points[p0].distance(points[p1]) <= epsilon {
+        return vec![points[p0]];
+    }
+
+    let line = (points[p1] - points[p0]).normalize();
+    let line_distance = |p: Vec3| (p - points[p0]).reject_from_normalized(line).length();
+    let p2 = farthest(&points, line_distance);
+    if line_distance(points[p2]) <= epsilon {
+        return vec![points[p0], points[p1]];
+    }
+
+    let plane_normal = line.cross(points[p2] - points[p0]).normalize();
+    let plane_distance = |p: Vec3| (p - points[p0]).dot(plane_normal);
+    let p3 = farthest(&points, |p| plane_distance(p).abs());
+    if plane_distance(points[p3]).abs() <= epsilon {
+        return planar_hull(&points, points[p0], line, plane_normal);
+    }
+
+    // Orient the simplex faces outward (away from the opposite vertex).
+    let simplex = [p0, p1, p2, p3];
+    let mut faces: Vec<HullFace> = [[0, 1, 2], [0, 3, 1], [0, 2, 3], [1, 3, 2]]
+        .into_iter()
+        .map(|[a, b, c]| {
+            let opposite = simplex[6 - a - b - c];
+            let face = HullFace::new(&points, simplex[a], simplex[b], simplex[c]);
+            if face.distance(points[opposite]) > 0.0 {
+                HullFace::new(&points, simplex[a], simplex[c], simplex[b])
+            } else {
+                face
+            }
+        })
+        .collect();
+
+    assign_outside(&points, &mut faces, 0, 0..points.len(), epsilon);
+
+    while let Some(face_index) = faces
+        .iter()
+        .position(|face| face.alive && !face.outside.is_empty())
+    {
+        let face = &faces[face_index];
+        let apex = *face
+            .outside
+            .iter()
+            .max_by(|a, b| {
+                face.distance(points[**a])
+                    .total_cmp(&face.distance(points[**b]))
+            })
+            .unwrap_or(&face.outside[0]);
+
+        // Faces visible from the apex are replaced by a cone from their horizon to the apex.
+        let mut visible_edges = HashSet::new();
+        let mut orphans = Vec::new();
+        for face in faces.iter_mut().filter(|face| face.alive) {
+            if face.distance(points[apex]) > epsilon {
+                face.alive = false;
+                visible_edges.extend(face.edges());
+                orphans.append(&mut face.outside);
+            }
+        }
+        let horizon: Vec<(usize, usize)> = visible_edges
+            .iter()
+            .filter(|(a, b)| !visible_edges.contains(&(*b, *a)))
+            .copied()
+            .collect();
+
+        let first_new = faces.len();
+        faces.extend(
+            horizon
+                .into_iter()
+                .map(|(a, b)| HullFace::new(&points, a, b, apex)),
+        );
+        assign_outside(
+            &points,
+            &mut faces,
+            first_new,
+            orphans.into_iter().filter(|&i| i != apex),
+            epsilon,
+        );
+    }
+
+    let mut hull_indices: Vec<usize> = faces
+        .iter()
+        .filter(|face| face.alive)
+        .flat_map(|face| face.vertices)
+        .collect();
+    hull_indices.sort_unstable();
+    hull_indices.dedup();
+    hull_indices.into_iter().map(|i| points[i]).collect()
+}
+
+/// Returns the index of the point that maximizes `metric`.
+fn farthest(points: &[Vec3], metric: impl Fn(Vec3) -> f32) -> usize {
+    (0..points.len())
+        .max_by(|a, b| metric(points[*a]).total_cmp(&metric(points[*b])))
+        .unwrap_or(0)
+}
+
+/// Adds each point to the outside set of the first live face from `first_face` onward that it
+/// lies in front of. Points behind every such face are inside the hull and dropped.
+fn assign_outside(
+    points: &[Vec3],
+    faces: &mut [HullFace],
+    first_face: usize,
+    candidates: impl IntoIterator<Item = usize>,
+    epsilon: f32,
+) {
+    for point in candidates {
+        if let Some(face) = faces[first_face..]
+            .iter_mut()
+            .find(|face| face.alive && face.distance(points[point]) > epsilon)
+        {
+            face.outside.push(point);
+        }
+    }
+}
+
+/// 2D hull of coplanar points, computed in the plane's `(u, v)` basis and mapped back.
+fn planar_hull(points: &[Vec3], origin: Vec3, u: Vec3, normal: Vec3) -> Vec<Vec3> {
+    let v = normal.cross(u);
+    let projected: Vec<(f32, f32)> = points
+        .iter()
+        .map(|p| {
+            let offset = *p - origin;
+            (offset.dot(u), offset.dot(v))
+        })
+        .collect();
+    convex_hull_2d(&projected)
+        .into_iter()
+        .map(|(x, y)| origin + u * x + v * y)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn cube_corners() -> Vec<Vec3> {
+        (0..8)
+            .map(|i| {
+                Vec3::new(
+                    if i & 1 == 0 { -1.0 } else { 1.0 },
+                    if i & 2 == 0 { -1.0 } else { 1.0 },
+                    if i & 4 == 0 { -1.0 } else { 1.0 },
+                )
+            })
+            .collect()
+    }
+
+    #[test]
+    fn hull_2d_ignores_non_finite_points() {
+        let hull = convex_hull_2d(&[
+            (0.0, 0.0),
+            (f32::NAN, 1.0),
+            (1.0, 0.0),
+            (0.5, f32::INFINITY),
+            (0.0, 1.0),
+        ]);
+
+        assert_eq!(hull, vec![(0.0, 0.0), (1.0, 0.0), (0.0, 1.0)]);
+    }
+
+    #[test]
+    fn hull_of_cube_with_interior_points_is_its_corners() {
+        let mut points = cube_corners();
+        points.extend([Vec3::ZERO, Vec3::splat(0.5), Vec3::new(-0.3, 0.9, 0.1)]);
+
+        let hull = convex_hull_3d(&points);
+
+        assert_eq!(hull.len(), 8);
+        for corner in cube_corners() {
+            assert!(
+                hull.contains(&corner),
+                "hull should contain corner {corner:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn hull_of_sphere_samples_keeps_every_sample() {
+        let points: Vec<Vec3> = (0..200)
+            .map(|i| {
+                let golden = std::f32::consts::PI * (3.0 - 5.0_f32.sqrt());
+                let y = 1.0 - 2.0 * (i as f32 + 0.5) / 200.0;
+                let ring = (1.0 - y * y).sqrt();
+                let theta = golden * i as f32;
+                Vec3::new(ring * theta.cos(), y, ring * theta.sin())
+            })
+            .collect();
+
+        assert_eq!(convex_hull_3d(&points).len(), points.len());
+    }
+
+    #[test]
+    fn hull_of_coplanar_grid_is_its_outline() {
+        let points: Vec<Vec3> = (0..5)
+            .flat_map(|x| (0..5).map(move |z| Vec3::new(x as f32, 2.0, z as f32)))
+            .collect();
+
+        let hull = convex_hull_3d(&points);
+
+        // Collinear edge points may survive rounding, but nothing from the interior.
+        for p in &hull {
+            assert!(
+                (p.y - 2.0).abs() < 1e-4,
+                "hull point {p:?} should stay in the plane"
+            );
+            let on_outline = [p.x, p.z]
+                .iter()
+                .any(|c| c.abs() < 1e-4 || (c - 4.0).abs() < 1e-4);
+            assert!(
+                on_outline,
+                "hull point {p:?} should lie on the grid's outline"
+            );
+        }
+        for corner in [
+            Vec3::new(0.0, 2.0, 0.0),
+            Vec3::new(4.0, 2.0, 0.0),
+            Vec3::new(0.0, 2.0, 4.0),
+            Vec3::new(4.0, 2.0, 4.0),
+        ] {
+            assert!(
+                hull.iter().any(|p| p.distance(corner) < 1e-4),
+                "hull should contain corner {corner:?}"
+            );
+        }
+    }
+}
@@ -0,0 +1,182 @@
+//! Holding fit requests with a `load_timeout` until their target's meshes have loaded, via
+//! [`DeferredFit`].
+
+use std::time::Duration;
+
+use bevy::ecs::system::SystemParam;
+use bevy::prelude::*;
+
+use crate::components::AnimationConflictPolicy;
+use crate::events::AnimateToFit;
+use crate::events::AnimationRejected;
+use crate::events::AutoFit;
+use crate::events::FitTarget;
+use crate::events::LookAtAndZoomToFit;
+use crate::events::ZoomToFit;
+use crate::observers::FitRequest;
+use crate::support::FitTargetQueries;
+use crate::support::fit_target_loaded;
+
+/// A fit request waiting on this camera for its target's meshes to load.
+///
+/// Inserted by a fit event with a `load_timeout` (see
+/// [`ZoomToFit::wait_for_load`]) whose target has no meshes yet, a mesh asset still loading or a
+/// mesh without a `GlobalTransform` — typically a `SceneRoot` spawned this frame. Once every mesh
+/// is available the request runs as if it had just been triggered, so `ZoomBegin` (or
+/// `AnimationBegin`) fires only when the fit can be computed. When the timeout passes first, the
+/// request runs with whatever has loaded and fails with [`FitFailed`](crate::FitFailed) if
+/// nothing has.
+///
+/// A newer fit request on the camera replaces a waiting one, and so does an accepted
+/// [`PlayAnimation`](crate::PlayAnimation) under
+/// [`LastWins`](AnimationConflictPolicy::LastWins). Under
+/// [`FirstWins`](AnimationConflictPolicy::FirstWins) the waiting request counts as in flight, so
+/// newer fits and animations are rejected with [`AnimationRejected`] instead. Removing this
+/// component drops the request without any lifecycle events.
+#[derive(Component)]
+pub struct DeferredFit {
+    request: DeferredRequest,
+    timeout: Duration,
+    waited:  Duration,
+}
+
+/// The fit event a [`DeferredFit`] re-triggers.
+#[derive(Clone)]
+#[allow(clippy::enum_variant_names)]
+pub enum DeferredRequest {
+    ZoomToFit(ZoomToFit),
+    AnimateToFit(AnimateToFit),
+    AutoFit(AutoFit),
+    LookAtAndZoomToFit(LookAtAndZoomToFit),
+}
+
+impl DeferredRequest {
+    fn target(&self) -> &FitTarget {
+        match self {
+            Self::ZoomToFit(event) => &event.target,
+            Self::AnimateToFit(event) => &event.target,
+            Self::AutoFit(event) => &event.target,
+            Self::LookAtAndZoomToFit(event) => &event.target,
+        }
+    }
+
+    /// Triggers the event again without a `load_timeout`, so it fits right away.
+    fn trigger(self, commands: &mut Commands) {
+        match self {
+            Self::ZoomToFit(event) => commands.trigger(ZoomToFit {
+                load_timeout: None,
+                ..event
+            }),
+            Self::AnimateToFit(event) => commands.trigger(AnimateToFit {
+                load_timeout: None,
+                ..event
+            }),
+            Self::AutoFit(event) => commands.trigger(AutoFit {
+                load_timeout: None,
+                ..event
+            }),
+            Self::LookAtAndZoomToFit(event) => commands.trigger(LookAtAndZoomToFit {
+                load_timeout: None,
+                ..event
+            }),
+        }
+    }
+}
+
+impl From<ZoomToFit> for DeferredRequest {
+    fn from(event: ZoomToFit) -> Self { Self::ZoomToFit(event) }
+}
+
+impl From<AnimateToFit> for DeferredRequest {
+    fn from(event: AnimateToFit) -> Self { Self::AnimateToFit(event) }
+}
+
+impl From<AutoFit> for DeferredRequest {
+    fn from(event: AutoFit) -> Self { Self::AutoFit(event) }
+}
+
+impl From<LookAtAndZoomToFit> for DeferredRequest {
+    fn from(event: LookAtAndZoomToFit) -> Self { Self::LookAtAndZoomToFit(event) }
+}
+
+/// Holds fit requests with a `load_timeout` as [`DeferredFit`]s until their targets have loaded.
+#[derive(SystemParam)]
+#[allow(clippy::type_complexity)]
+pub struct DeferredFits<'w, 's> {
+    cameras: Query<'w, 's, (Option<&'static AnimationConflictPolicy>, Has<DeferredFit>)>,
+}
+
+impl DeferredFits<'_, '_> {
+    /// Defers a fit `event` when it has a `load_timeout` and its target is not loaded yet,
+    /// returning `true` if it was deferred or rejected and needs no further handling. A request
+    /// already waiting on the camera is superseded by `event`, unless the camera's
+    /// [`AnimationConflictPolicy`] is [`FirstWins`](AnimationConflictPolicy::FirstWins), which
+    /// rejects `event` instead.
+    pub fn defer_until_loaded<E>(
+        &self,
+        commands: &mut Commands,
+        request: &FitRequest,
+        load_timeout: Option<Duration>,
+        queries: &FitTargetQueries,
+        event: &E,
+    ) -> bool
+    where
+        E: Clone + Into<DeferredRequest>,
+    {
+        let camera = request.camera;
+        let Ok((policy, waiting)) = self.cameras.get(camera) else {
+            return false;
+        };
+        if waiting && policy.copied().unwrap_or_default() == AnimationConflictPolicy::FirstWins {
+            commands.trigger(AnimationRejected {
+                camera,
+                source: request.source,
+            });
+            return true;
+        }
+
+        let mut entity_commands = commands.entity(camera);
+        match load_timeout {
+            Some(timeout) if !fit_target_loaded(&request.target, camera, queries) => {
+                debug!(
+                    "deferring fit of {:?} until it has loaded (timeout {timeout:?})",
+                    request.target
+                );
+                entity_commands.insert(DeferredFit {
+                    request: event.clone().into(),
+                    timeout,
+                    waited: Duration::ZERO,
+                });
+                true
+            },
+            _ => {
+                entity_commands.remove::<DeferredFit>();
+                false
+            },
+        }
+    }
+}
+
+/// System that runs deferred fits once their target has loaded or their timeout has passed.
+pub fn resolve_deferred_fits(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut camera_query: Query<(Entity, &mut DeferredFit)>,
+    queries: FitTargetQueries,
+) {
+    for (camera, mut deferred) in &mut camera_query {
+        deferred.waited += time.delta();
+        let target = deferred.request.target();
+        if !fit_target_loaded(target, camera, &queries) {
+            if deferred.waited < deferred.timeout {
+                continue;
+            }
+            debug!(
+                "fit of {target:?} still loading after {:?}; fitting what has loaded",
+                deferred.timeout
+            );
+        }
+        commands.entity(camera).remove::<DeferredFit>();
+        deferred.request.clone().trigger(&mut commands);
+    }
+}
@@ -67,6 +67,31 @@
 //! AnimationBegin → AnimationEnd
 //! ```
 //!
+//! ## Camera limits
+//!
+//! Fits respect the camera's `PanOrbitCamera` yaw, pitch and zoom limits. When a limit keeps a
+//! fit from framing its target as requested, or a target smaller than the camera's
+//! [`FitFallback::min_radius`](crate::FitFallback) is framed as a sphere of that radius instead,
+//! [`FitLimited`] fires before the operation's begin event:
+//!
+//! ```text
+//! FitLimited → ZoomBegin → …
+//! ```
+//!
+//! ## Fit failure
+//!
+//! When a fit request cannot be computed (no mesh, mesh still loading, unsupported projection,
+//! …), the camera does not move and only [`FitFailed`] fires — no `ZoomBegin` or
+//! `AnimationBegin`:
+//!
+//! ```text
+//! FitFailed
+//! ```
+//!
+//! A fit event with a `load_timeout` ([`ZoomToFit::wait_for_load`]) on a target that is still
+//! loading fires nothing until the target has loaded, then runs as usual; `FitFailed` fires only
+//! if the timeout passes with nothing to frame.
+//!
 //! ## User input interruption ([`CameraInputInterruptBehavior`](crate::CameraInputInterruptBehavior))
 //!
 //! When the user physically moves the camera during an animation:
@@ -127,14 +152,21 @@
 //! | [`AnimationRejected`]    | yes             | —               | —        | —          | —        | yes      | —             |
 //! | [`CameraMoveBegin`]      | yes             | —               | —        | —          | —        | —        | yes           |
 //! | [`CameraMoveEnd`]        | yes             | —               | —        | —          | —        | —        | yes           |
+//! | [`FitFailed`]            | yes             | yes             | —        | —          | —        | yes      | —             |
+//! | [`FitLimited`]           | yes             | yes             | —        | —          | —        | yes      | —             |
 
 use std::collections::VecDeque;
+use std::ops::Range;
 use std::time::Duration;
 
+use bevy::math::bounding::Aabb3d;
+use bevy::math::bounding::BoundingSphere;
 use bevy::math::curve::easing::EaseFunction;
 use bevy::prelude::*;
 
 use crate::animation::CameraMove;
+use crate::fit::FitError;
+use crate::fit::FitLimits;
 
 /// Context for a zoom-to-fit operation, passed through [`PlayAnimation`] so
 /// that `on_play_animation` can fire [`ZoomBegin`] and insert
@@ -142,12 +174,374 @@ use crate::animation::CameraMove;
 /// single point where conflict resolution has already completed.
 #[derive(Clone, Reflect)]
 pub struct ZoomContext {
-    pub target:   Entity,
-    pub margin:   f32,
+    pub target:   FitTarget,
+    pub margin:   FitMargins,
     pub duration: Duration,
     pub easing:   EaseFunction,
 }
 
+/// What a fit operation frames — a single entity, a group of entities framed together, or
+/// world-space geometry with no entity behind it.
+///
+/// Converts from an [`Entity`], a `Vec<Entity>`, an array of entities, an [`Aabb3d`], or a
+/// [`BoundingSphere`], so fit events accept any of them directly (e.g.
+/// `ZoomToFit::new(camera, entity)`, `ZoomToFit::new(camera, vec![a, b, c])`, or
+/// `ZoomToFit::new(camera, Aabb3d::new(center, half_size))`). Use [`FitTarget::points`] for
+/// an arbitrary point set.
+///
+/// For [`Entities`](FitTarget::Entities), the vertices of every entity are combined before
+/// fitting, so the group is framed as one silhouette. Entities without a loaded `Mesh3d`
+/// are skipped; the fit fails only if no entity contributes any vertices.
+///
+/// The geometry variants ([`Points`](FitTarget::Points), [`Aabb`](FitTarget::Aabb),
+/// [`Sphere`](FitTarget::Sphere)) are in world space and need no mesh — use them to frame a
+/// region of a procedural world, a selection volume, or any bounds you already have.
+///
+/// [`MeshRegion`](FitTarget::MeshRegion) frames part of one mesh — a face, a vertex selection or
+/// a submesh — by transforming only the selected positions through the entity's
+/// `GlobalTransform`. Create one with [`FitTarget::mesh_region`].
+#[derive(Clone, Debug, PartialEq, Reflect)]
+pub enum FitTarget {
+    /// A single entity; must have a `Mesh3d` or [`FitGeometry`](crate::FitGeometry) (direct or on
+    /// descendants).
+    Entity(Entity),
+    /// A group of entities framed as one; each should have a `Mesh3d` or
+    /// [`FitGeometry`](crate::FitGeometry) (direct or on descendants).
+    Entities(Vec<Entity>),
+    /// World-space points, framed by their combined silhouette.
+    Points(Vec<Vec3>),
+    /// A world-space axis-aligned box, framed by its 8 corners.
+    Aabb(Aabb3d),
+    /// A world-space sphere, framed by points sampled just outside its surface so the framing
+    /// never clips it.
+    Sphere(BoundingSphere),
+    /// Part of the `Mesh3d` on `entity` itself (not its descendants), selected in its bind pose.
+    MeshRegion { entity: Entity, region: MeshRegion },
+}
+
+impl FitTarget {
+    /// Creates a [`FitTarget::Points`] target from world-space points.
+    pub fn points(points: impl IntoIterator<Item = Vec3>) -> Self {
+        Self::Points(points.into_iter().collect())
+    }
+
+    /// Creates a [`FitTarget::MeshRegion`] target framing `region` of the mesh on `entity`.
+    pub const fn mesh_region(entity: Entity, region: MeshRegion) -> Self {
+        Self::MeshRegion { entity, region }
+    }
+
+    /// Returns the entities that make up this target. Empty for the geometry variants.
+    pub fn entities(&self) -> &[Entity] {
+        match self {
+            Self::Entity(entity) | Self::MeshRegion { entity, .. } => core::slice::from_ref(entity),
+            Self::Entities(entities) => entities,
+            Self::Points(_) | Self::Aabb(_) | Self::Sphere(_) => &[],
+        }
+    }
+}
+
+impl From<Entity> for FitTarget {
+    fn from(entity: Entity) -> Self { Self::Entity(entity) }
+}
+
+impl From<Vec<Entity>> for FitTarget {
+    fn from(entities: Vec<Entity>) -> Self { Self::Entities(entities) }
+}
+
+impl<const N: usize> From<[Entity; N]> for FitTarget {
+    fn from(entities: [Entity; N]) -> Self { Self::Entities(entities.to_vec()) }
+}
+
+impl From<&[Entity]> for FitTarget {
+    fn from(entities: &[Entity]) -> Self { Self::Entities(entities.to_vec()) }
+}
+
+impl From<Aabb3d> for FitTarget {
+    fn from(aabb: Aabb3d) -> Self { Self::Aabb(aabb) }
+}
+
+impl From<BoundingSphere> for FitTarget {
+    fn from(sphere: BoundingSphere) -> Self { Self::Sphere(sphere) }
+}
+
+/// The part of a mesh a [`FitTarget::MeshRegion`] frames, in the mesh's own vertex data.
+///
+/// Selections that match no vertices — indices past the end of the mesh, an empty range or a box
+/// containing no positions — fail with [`FitError::NoMesh`].
+#[derive(Clone, Debug, PartialEq, Reflect)]
+pub enum MeshRegion {
+    /// The vertices at these indices into `Mesh::ATTRIBUTE_POSITION`.
+    Vertices(Vec<u32>),
+    /// Triangles `start..end` of a `TriangleList` mesh: the vertices referenced by indices
+    /// `3 * start..3 * end`, or those vertices themselves for a mesh without indices. Meshes
+    /// with another topology have no triangles to select.
+    Triangles(Range<u32>),
+    /// The vertices inside this box, in the mesh's local space.
+    LocalAabb(Aabb3d),
+}
+
+impl PartialEq<Entity> for FitTarget {
+    fn eq(&self, other: &Entity) -> bool { matches!(self, Self::Entity(entity) if entity == other) }
+}
+
+/// Unit in which [`FitMargins`] edge values are expressed.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Reflect)]
+pub enum MarginUnit {
+    /// Fraction of the screen: `left`/`right` of the width, `top`/`bottom` of the height.
+    #[default]
+    Fraction,
+    /// Logical pixels, converted using the camera's `logical_viewport_size`. Fits fail with no
+    /// viewport.
+    LogicalPixels,
+    /// World-space clearance around the target, measured perpendicular to the view direction at
+    /// the depth of the target's extreme point on each edge (1:1 for orthographic).
+    World,
+}
+
+/// Per-edge screen margins for fit operations.
+///
+/// By default each edge is a fraction of the screen size: `left` and `right` are fractions of
+/// the screen width, `top` and `bottom` fractions of the screen height. Set [`unit`] to
+/// express the edges in logical pixels or world units instead (see [`MarginUnit`]).
+///
+/// The fit places the target inside the area that remains after insetting each screen edge by
+/// its margin, centered within that area. The constraining dimension meets its margins
+/// exactly; the other dimension gets equal extra space on both sides.
+///
+/// Asymmetric margins shift the framed target away from the screen center, e.g.
+/// `FitMargins::new(0.3, 0.05, 0.05, 0.15)` keeps it clear of a left panel and a bottom
+/// toolbar.
+///
+/// Converts from an `f32` via [`FitMargins::uniform`]. Fraction edges (including converted
+/// pixel edges) are clamped to `[0, 1)` and opposite edges are scaled down if together they
+/// would leave no room for the target. Negative or NaN edges become zero in every unit.
+///
+/// [`unit`]: FitMargins::unit
+#[derive(Clone, Copy, Debug, Default, PartialEq, Reflect)]
+pub struct FitMargins {
+    pub left:   f32,
+    pub right:  f32,
+    pub top:    f32,
+    pub bottom: f32,
+    pub unit:   MarginUnit,
+}
+
+impl FitMargins {
+    /// Per-edge margins as fractions of the screen size.
+    pub const fn new(left: f32, right: f32, top: f32, bottom: f32) -> Self {
+        Self {
+            left,
+            right,
+            top,
+            bottom,
+            unit: MarginUnit::Fraction,
+        }
+    }
+
+    /// A total screen fraction split equally across both sides of each dimension
+    /// (e.g. `0.25` → 12.5% per edge).
+    pub const fn uniform(margin: f32) -> Self {
+        let half = margin * 0.5;
+        Self::new(half, half, half, half)
+    }
+
+    /// The same number of logical pixels on every edge.
+    pub const fn logical_pixels(pixels: f32) -> Self {
+        Self::new(pixels, pixels, pixels, pixels).unit(MarginUnit::LogicalPixels)
+    }
+
+    /// The same world-space clearance on every edge.
+    pub const fn world(distance: f32) -> Self {
+        Self::new(distance, distance, distance, distance).unit(MarginUnit::World)
+    }
+
+    /// Sets the unit the edge values are expressed in.
+    pub const fn unit(mut self, unit: MarginUnit) -> Self {
+        self.unit = unit;
+        self
+    }
+
+    /// Converts [`MarginUnit::LogicalPixels`] margins to screen fractions for a viewport of the
+    /// given logical size. Other units are returned unchanged. Returns `None` for pixel margins
+    /// when no viewport size is available.
+    pub fn to_screen_fractions(&self, viewport_size: Option<Vec2>) -> Option<Self> {
+        match self.unit {
+            MarginUnit::Fraction | MarginUnit::World => Some(*self),
+            MarginUnit::LogicalPixels => {
+                let size = viewport_size.filter(|size| size.x > 0.0 && size.y > 0.0)?;
+                Some(Self::new(
+                    self.left / size.x,
+                    self.right / size.x,
+                    self.top / size.y,
+                    self.bottom / size.y,
+                ))
+            },
+        }
+    }
+}
+
+impl From<f32> for FitMargins {
+    fn from(margin: f32) -> Self { Self::uniform(margin) }
+}
+
+/// Region of the viewport that a fit frames the target in.
+///
+/// [`FitMargins`] apply inside this region: fraction margins are fractions of the region's size
+/// and the target is centered within the region's inset area. Rectangles use viewport
+/// coordinates with the origin at the top-left corner and `y` pointing down, matching `bevy_ui`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Reflect)]
+pub enum FitFrame {
+    /// The whole viewport.
+    #[default]
+    Viewport,
+    /// A rectangle in normalized viewport coordinates, `(0, 0)` to `(1, 1)`.
+    Normalized(Rect),
+    /// A rectangle in logical pixels, converted using the camera's `logical_viewport_size`.
+    LogicalPixels(Rect),
+    /// The largest centered rectangle with this width / height ratio, e.g. `2.39` for a
+    /// cinematic safe frame.
+    AspectRatio(f32),
+    /// The camera's [`UnoccludedViewport`](crate::UnoccludedViewport), or the whole viewport
+    /// when the camera has none.
+    Unoccluded,
+}
+
+impl FitFrame {
+    /// Returns the frame as a rectangle in normalized viewport coordinates.
+    ///
+    /// [`FitFrame::Unoccluded`] is resolved by the fit observers before this is called and
+    /// otherwise covers the whole viewport. Returns `None` when a pixel or aspect-ratio frame
+    /// needs a viewport size that is unavailable.
+    pub fn to_normalized(&self, viewport_size: Option<Vec2>) -> Option<Rect> {
+        let viewport = || viewport_size.filter(|size| size.x > 0.0 && size.y > 0.0);
+        match *self {
+            Self::Viewport | Self::Unoccluded => Some(Rect::new(0.0, 0.0, 1.0, 1.0)),
+            Self::Normalized(rect) => Some(rect),
+            Self::LogicalPixels(rect) => {
+                let size = viewport()?;
+                Some(Rect::from_corners(rect.min / size, rect.max / size))
+            },
+            Self::AspectRatio(ratio) => {
+                let size = viewport()?;
+                let viewport_ratio = size.x / size.y;
+                let (width, height) = if ratio > viewport_ratio {
+                    (1.0, viewport_ratio / ratio)
+                } else {
+                    (ratio / viewport_ratio, 1.0)
+                };
+                Some(Rect::from_center_size(
+                    Vec2::splat(0.5),
+                    Vec2::new(width, height),
+                ))
+            },
+        }
+    }
+
+    /// Replaces [`FitFrame::Unoccluded`] with the camera's unoccluded rectangle, or
+    /// [`FitFrame::Viewport`] when the camera has none. Other frames are returned unchanged.
+    pub fn resolve_unoccluded(self, unoccluded: Option<Rect>) -> Self {
+        match self {
+            Self::Unoccluded => unoccluded.map_or(Self::Viewport, Self::Normalized),
+            frame => frame,
+        }
+    }
+}
+
+/// Where in the [`FitFrame`] a fit places the target.
+///
+/// The fit centers the target's projected bounds on the anchor and scales them so the bounds
+/// reach the requested [`FitMargins`] on the side nearest the anchor; the far side gets extra
+/// space instead. Anchors close to the margins therefore frame the target smaller.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Reflect)]
+pub enum FitAnchor {
+    /// The center of the area left inside the margins.
+    #[default]
+    Center,
+    /// A point in normalized frame coordinates, `(0, 0)` at the frame's top-left corner to
+    /// `(1, 1)` at its bottom-right. Points outside the area left inside the margins are moved
+    /// onto its edge.
+    Normalized(Vec2),
+}
+
+impl FitAnchor {
+    /// The top-left rule-of-thirds intersection.
+    pub const THIRDS_TOP_LEFT: Self = Self::Normalized(Vec2::new(1.0 / 3.0, 1.0 / 3.0));
+    /// The top-right rule-of-thirds intersection.
+    pub const THIRDS_TOP_RIGHT: Self = Self::Normalized(Vec2::new(2.0 / 3.0, 1.0 / 3.0));
+    /// The bottom-left rule-of-thirds intersection.
+    pub const THIRDS_BOTTOM_LEFT: Self = Self::Normalized(Vec2::new(1.0 / 3.0, 2.0 / 3.0));
+    /// The bottom-right rule-of-thirds intersection.
+    pub const THIRDS_BOTTOM_RIGHT: Self = Self::Normalized(Vec2::new(2.0 / 3.0, 2.0 / 3.0));
+
+    /// Returns the anchor in normalized screen-space units (origin at the viewport center, `y`
+    /// up) for a normalized viewport `frame` and visible `half_extents`, or `None` for
+    /// [`FitAnchor::Center`], which depends on the margins.
+    pub fn to_screen(&self, frame: Rect, half_extents: Vec2) -> Option<Vec2> {
+        match *self {
+            Self::Center => None,
+            Self::Normalized(point) => {
+                let viewport = frame.min + point * frame.size();
+                Some(Vec2::new(
+                    half_extents.x * (2.0 * viewport.x - 1.0),
+                    half_extents.y * (1.0 - 2.0 * viewport.y),
+                ))
+            },
+        }
+    }
+}
+
+/// Geometry a fit uses to stand in for the target, trading precision for speed.
+///
+/// The fit projects every point of the chosen volume on each search step, so coarser volumes
+/// fit huge meshes much faster.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, Reflect)]
+pub enum FitVolume {
+    /// Every mesh vertex — the tightest framing.
+    #[default]
+    Vertices,
+    /// The 8 corners of each mesh's local-space bounding box (or of a point target's box).
+    MeshAabb,
+    /// A sphere bounding the whole target. The framing never clips the sphere, and its radius
+    /// varies by under 1.5% while orbiting.
+    BoundingSphere,
+    /// The vertices of each mesh's 3D convex hull — the same framing as `Vertices` with far
+    /// fewer points for dense meshes.
+    ConvexHull,
+}
+
+/// Which pose of animated meshes a fit frames.
+///
+/// Mesh assets store their vertices in bind pose, so by default an animated character is framed
+/// by its rest pose (often a T-pose) rather than what is on screen.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, Reflect)]
+pub enum FitPose {
+    /// Vertex positions as stored in the mesh asset.
+    #[default]
+    BindPose,
+    /// Vertex positions after applying `MeshMorphWeights` and `SkinnedMesh` joint transforms on
+    /// the CPU, matching the current animation frame. Meshes without skinning or morph weights
+    /// are unaffected. Posed vertices are recomputed on every fit instead of read from the
+    /// [`MeshFitCache`](crate::MeshFitCache).
+    Animated,
+}
+
+/// How [`AutoFit`] chooses the viewing angle.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, Reflect)]
+pub enum BestView {
+    /// The angle whose silhouette fills the most of the frame once fitted, trying yaws all
+    /// around the target at pitches from level to 60° above it. Among near-equal views the one
+    /// closest to the current angle wins.
+    #[default]
+    MaxSilhouette,
+    /// A 3/4 view along a diagonal of the target's principal axes, from above, so the three
+    /// dominant dimensions of the object are all visible.
+    PrincipalAxes,
+    /// Keeps the current angle unless the target is close to edge-on from it (its silhouette
+    /// fills less than half as much as the best view's), in which case it turns to the nearest
+    /// angle that is not.
+    AvoidEdgeOn,
+}
+
 /// Identifies which event triggered an animation lifecycle.
 ///
 /// Carried by [`AnimationBegin`], [`AnimationEnd`], [`AnimationCancelled`], and
@@ -165,6 +559,8 @@ pub enum AnimationSource {
     LookAt,
     /// Animation was triggered by [`LookAtAndZoomToFit`].
     LookAtAndZoomToFit,
+    /// Animation was triggered by [`AutoFit`].
+    AutoFit,
 }
 
 /// `ZoomToFit` — frames a target entity in the camera view without changing the
@@ -186,10 +582,25 @@ pub enum AnimationSource {
 /// # Fields
 ///
 /// - `camera` — the entity with a `PanOrbitCamera` component.
-/// - `target` — the entity to frame; must have a `Mesh3d` (direct or on descendants).
-/// - `margin` — total fraction of the screen to leave as space between the target's screen-space
-///   bounding box and the screen edge, split equally across both sides of the constraining
-///   dimension (e.g. `0.25` → ~12.5% each side).
+/// - `target` — the [`FitTarget`] to frame: an entity or a group of entities, each with a `Mesh3d`
+///   or [`FitGeometry`](crate::FitGeometry) (direct or on descendants), world-space geometry
+///   (points, box, or sphere), or a [`MeshRegion`] of one mesh.
+/// - `margin` — the [`FitMargins`] to leave as space between the target's screen-space bounding box
+///   and the screen edges. A plain `f32` is the total fraction of the screen, split equally across
+///   both sides of the constraining dimension (e.g. `0.25` → ~12.5% each side). Use
+///   [`FitMargins::new`] for per-edge margins, e.g. to keep the target clear of side panels or a
+///   toolbar.
+/// - `frame` — the [`FitFrame`] the target is fitted into: the whole viewport by default, or a
+///   sub-rectangle of it.
+/// - `anchor` — the [`FitAnchor`] the target is placed at within the frame: centered by default, or
+///   at a point such as a rule-of-thirds intersection.
+/// - `volume` — the [`FitVolume`] standing in for the target's meshes.
+/// - `pose` — the [`FitPose`] of skinned and morphed meshes to frame.
+/// - `load_timeout` — when set, a target whose meshes are still loading (a freshly spawned
+///   `SceneRoot`, say) keeps the request pending until every mesh under it and its
+///   `GlobalTransform` are available, or the timeout passes; see
+///   [`DeferredFit`](crate::DeferredFit). `None` (the default) fits right away with whatever has
+///   loaded.
 /// - `duration` — see module-level docs on **Duration**.
 /// - `easing` — see module-level docs on **Easing**.
 ///
@@ -205,30 +616,60 @@ pub enum AnimationSource {
 ///
 /// Trigger [`SetFitTarget`] to control where the visualization shows before a
 /// `ZoomToFit` has been triggered.
-#[derive(EntityEvent, Reflect)]
+#[derive(EntityEvent, Reflect, Clone)]
 #[reflect(Event, FromReflect)]
 pub struct ZoomToFit {
     #[event_target]
-    pub camera:   Entity,
-    pub target:   Entity,
-    pub margin:   f32,
-    pub duration: Duration,
-    pub easing:   EaseFunction,
+    pub camera:       Entity,
+    pub target:       FitTarget,
+    pub margin:       FitMargins,
+    pub frame:        FitFrame,
+    pub anchor:       FitAnchor,
+    pub volume:       FitVolume,
+    pub pose:         FitPose,
+    pub duration:     Duration,
+    pub easing:       EaseFunction,
+    pub load_timeout: Option<Duration>,
 }
 
 impl ZoomToFit {
-    pub const fn new(camera: Entity, target: Entity) -> Self {
+    pub fn new(camera: Entity, target: impl Into<FitTarget>) -> Self {
         Self {
             camera,
-            target,
-            margin: 0.1,
+            target: target.into(),
+            margin: FitMargins::uniform(0.1),
+            frame: FitFrame::Viewport,
+            anchor: FitAnchor::Center,
+            volume: FitVolume::Vertices,
+            pose: FitPose::BindPose,
             duration: Duration::ZERO,
             easing: EaseFunction::CubicOut,
+            load_timeout: None,
         }
     }
 
-    pub const fn margin(mut self, margin: f32) -> Self {
-        self.margin = margin;
+    pub fn margin(mut self, margin: impl Into<FitMargins>) -> Self {
+        self.margin = margin.into();
+        self
+    }
+
+    pub const fn frame(mut self, frame: FitFrame) -> Self {
+        self.frame = frame;
+        self
+    }
+
+    pub const fn anchor(mut self, anchor: FitAnchor) -> Self {
+        self.anchor = anchor;
+        self
+    }
+
+    pub const fn volume(mut self, volume: FitVolume) -> Self {
+        self.volume = volume;
+        self
+    }
+
+    pub const fn pose(mut self, pose: FitPose) -> Self {
+        self.pose = pose;
         self
     }
 
@@ -241,12 +682,17 @@ impl ZoomToFit {
         self.easing = easing;
         self
     }
+
+    pub const fn wait_for_load(mut self, timeout: Duration) -> Self {
+        self.load_timeout = Some(timeout);
+        self
+    }
 }
 
 /// `ZoomBegin` — emitted when a [`ZoomToFit`] operation begins.
 ///
 /// - `camera` — the camera that is zooming.
-/// - `target` — the entity being framed.
+/// - `target` — the [`FitTarget`] being framed.
 /// - `margin` — the margin value from the triggering [`ZoomToFit`].
 /// - `duration` — the duration from the triggering [`ZoomToFit`].
 /// - `easing` — the easing curve from the triggering [`ZoomToFit`].
@@ -255,8 +701,8 @@ impl ZoomToFit {
 pub struct ZoomBegin {
     #[event_target]
     pub camera:   Entity,
-    pub target:   Entity,
-    pub margin:   f32,
+    pub target:   FitTarget,
+    pub margin:   FitMargins,
     pub duration: Duration,
     pub easing:   EaseFunction,
 }
@@ -264,7 +710,7 @@ pub struct ZoomBegin {
 /// `ZoomEnd` — emitted when a [`ZoomToFit`] operation completes (both animated and instant).
 ///
 /// - `camera` — the camera that finished zooming.
-/// - `target` — the entity that was framed.
+/// - `target` — the [`FitTarget`] that was framed.
 /// - `margin` — the margin value from the triggering [`ZoomToFit`].
 /// - `duration` — the duration from the triggering [`ZoomToFit`].
 /// - `easing` — the easing curve from the triggering [`ZoomToFit`].
@@ -273,8 +719,8 @@ pub struct ZoomBegin {
 pub struct ZoomEnd {
     #[event_target]
     pub camera:   Entity,
-    pub target:   Entity,
-    pub margin:   f32,
+    pub target:   FitTarget,
+    pub margin:   FitMargins,
     pub duration: Duration,
     pub easing:   EaseFunction,
 }
@@ -294,7 +740,7 @@ pub struct ZoomEnd {
 /// `ZoomCancelled`.
 ///
 /// - `camera` — the camera whose zoom was cancelled.
-/// - `target` — the entity that was being framed.
+/// - `target` — the [`FitTarget`] that was being framed.
 /// - `margin` — the margin value from the triggering [`ZoomToFit`].
 /// - `duration` — the duration from the triggering [`ZoomToFit`].
 /// - `easing` — the easing curve from the triggering [`ZoomToFit`].
@@ -303,12 +749,57 @@ pub struct ZoomEnd {
 pub struct ZoomCancelled {
     #[event_target]
     pub camera:   Entity,
-    pub target:   Entity,
-    pub margin:   f32,
+    pub target:   FitTarget,
+    pub margin:   FitMargins,
     pub duration: Duration,
     pub easing:   EaseFunction,
 }
 
+/// `FitFailed` — emitted when a [`ZoomToFit`], [`AnimateToFit`], [`AutoFit`] or
+/// [`LookAtAndZoomToFit`] cannot compute a fit, instead of moving the camera.
+///
+/// No other lifecycle events fire for the failed request, and the camera's
+/// [`CurrentFitTarget`](crate::CurrentFitTarget) is left unchanged.
+///
+/// - `camera` — the camera the fit was requested for.
+/// - `target` — the [`FitTarget`] that could not be framed.
+/// - `source` — which event requested the fit.
+/// - `reason` — the [`FitError`] explaining why, e.g. [`FitError::NoMesh`] when there is nothing to
+///   frame or [`FitError::MeshNotLoaded`] when the target is still loading.
+#[derive(EntityEvent, Reflect)]
+#[reflect(Event, FromReflect)]
+pub struct FitFailed {
+    #[event_target]
+    pub camera: Entity,
+    pub target: FitTarget,
+    pub source: AnimationSource,
+    pub reason: FitError,
+}
+
+/// `FitLimited` — emitted when a [`ZoomToFit`], [`AnimateToFit`], [`AutoFit`] or
+/// [`LookAtAndZoomToFit`] had to respect a `PanOrbitCamera` yaw, pitch or zoom limit, back the
+/// camera out past the projection's near plane, or frame a [`FitFallback`](crate::FitFallback)
+/// `min_radius` sphere in place of a smaller target, so the target is not framed exactly as
+/// requested.
+///
+/// The camera still moves to the limited fit: this fires just before `ZoomBegin` or
+/// `AnimationBegin` (or `AnimationRejected`, if the animation is rejected), and the animation
+/// stays within the limits throughout.
+///
+/// - `camera` — the camera the fit was requested for.
+/// - `target` — the [`FitTarget`] being framed.
+/// - `source` — which event requested the fit.
+/// - `limits` — the [`FitLimits`] that applied.
+#[derive(EntityEvent, Reflect)]
+#[reflect(Event, FromReflect)]
+pub struct FitLimited {
+    #[event_target]
+    pub camera: Entity,
+    pub target: FitTarget,
+    pub source: AnimationSource,
+    pub limits: FitLimits,
+}
+
 /// `PlayAnimation` — plays a queued sequence of [`CameraMove`] steps.
 ///
 /// - `camera` — the entity with a `PanOrbitCamera` component.
@@ -474,10 +965,15 @@ pub struct CameraMoveEnd {
 /// # Fields
 ///
 /// - `camera` — the entity with a `PanOrbitCamera` component.
-/// - `target` — the entity to frame; must have a `Mesh3d` (direct or on descendants).
+/// - `target` — the [`FitTarget`] to frame; see [`ZoomToFit`].
 /// - `yaw` — final yaw in radians; updates `PanOrbitCamera::target_yaw`.
 /// - `pitch` — final pitch in radians; updates `PanOrbitCamera::target_pitch`.
 /// - `margin` — see [`ZoomToFit`] for details on how margin is applied.
+/// - `frame` — see [`ZoomToFit`].
+/// - `anchor` — see [`ZoomToFit`].
+/// - `volume` — see [`ZoomToFit`].
+/// - `pose` — see [`ZoomToFit`].
+/// - `load_timeout` — see [`ZoomToFit`].
 /// - `duration` — see module-level docs on **Duration**.
 /// - `easing` — see module-level docs on **Easing**.
 ///
@@ -486,29 +982,39 @@ pub struct CameraMoveEnd {
 /// standard animation events with `source: AnimationSource::AnimateToFit`.
 /// See the [module-level event ordering](self#event-ordering) docs for the full
 /// sequence and interruption/conflict scenarios.
-#[derive(EntityEvent, Reflect)]
+#[derive(EntityEvent, Reflect, Clone)]
 #[reflect(Event, FromReflect)]
 pub struct AnimateToFit {
     #[event_target]
-    pub camera:   Entity,
-    pub target:   Entity,
-    pub yaw:      f32,
-    pub pitch:    f32,
-    pub margin:   f32,
-    pub duration: Duration,
-    pub easing:   EaseFunction,
+    pub camera:       Entity,
+    pub target:       FitTarget,
+    pub yaw:          f32,
+    pub pitch:        f32,
+    pub margin:       FitMargins,
+    pub frame:        FitFrame,
+    pub anchor:       FitAnchor,
+    pub volume:       FitVolume,
+    pub pose:         FitPose,
+    pub duration:     Duration,
+    pub easing:       EaseFunction,
+    pub load_timeout: Option<Duration>,
 }
 
 impl AnimateToFit {
-    pub const fn new(camera: Entity, target: Entity) -> Self {
+    pub fn new(camera: Entity, target: impl Into<FitTarget>) -> Self {
         Self {
             camera,
-            target,
+            target: target.into(),
             yaw: 0.0,
             pitch: 0.0,
-            margin: 0.1,
+            margin: FitMargins::uniform(0.1),
+            frame: FitFrame::Viewport,
+            anchor: FitAnchor::Center,
+            volume: FitVolume::Vertices,
+            pose: FitPose::BindPose,
             duration: Duration::ZERO,
             easing: EaseFunction::CubicOut,
+            load_timeout: None,
         }
     }
 
@@ -522,8 +1028,136 @@ impl AnimateToFit {
         self
     }
 
-    pub const fn margin(mut self, margin: f32) -> Self {
-        self.margin = margin;
+    pub fn margin(mut self, margin: impl Into<FitMargins>) -> Self {
+        self.margin = margin.into();
+        self
+    }
+
+    pub const fn frame(mut self, frame: FitFrame) -> Self {
+        self.frame = frame;
+        self
+    }
+
+    pub const fn anchor(mut self, anchor: FitAnchor) -> Self {
+        self.anchor = anchor;
+        self
+    }
+
+    pub const fn volume(mut self, volume: FitVolume) -> Self {
+        self.volume = volume;
+        self
+    }
+
+    pub const fn pose(mut self, pose: FitPose) -> Self {
+        self.pose = pose;
+        self
+    }
+
+    pub const fn duration(mut self, duration: Duration) -> Self {
+        self.duration = duration;
+        self
+    }
+
+    pub const fn easing(mut self, easing: EaseFunction) -> Self {
+        self.easing = easing;
+        self
+    }
+
+    pub const fn wait_for_load(mut self, timeout: Duration) -> Self {
+        self.load_timeout = Some(timeout);
+        self
+    }
+}
+
+/// `AutoFit` — animates the camera to a viewing angle it chooses itself while framing a
+/// target in view.
+///
+/// Like [`AnimateToFit`], but instead of a caller-specified yaw and pitch, the [`BestView`]
+/// strategy picks the angle from the target's geometry — for example the view showing the
+/// largest silhouette, or a 3/4 view along the object's principal axes. Each candidate angle is
+/// fitted with the same margins and frame, respecting the camera's limits.
+///
+/// # See also
+///
+/// - [`AnimateToFit`] — frames the target from a caller-specified viewing angle.
+/// - [`ZoomToFit`] — keeps the current viewing angle.
+///
+/// # Fields
+///
+/// - `camera` — the entity with a `PanOrbitCamera` component.
+/// - `target` — the [`FitTarget`] to frame; see [`ZoomToFit`].
+/// - `view` — the [`BestView`] strategy choosing the final yaw and pitch.
+/// - `margin` — see [`ZoomToFit`] for details on how margin is applied.
+/// - `frame` — see [`ZoomToFit`].
+/// - `anchor` — see [`ZoomToFit`].
+/// - `volume` — see [`ZoomToFit`].
+/// - `pose` — see [`ZoomToFit`].
+/// - `load_timeout` — see [`ZoomToFit`].
+/// - `duration` — see module-level docs on **Duration**.
+/// - `easing` — see module-level docs on **Easing**.
+///
+/// Fires the same events as [`AnimateToFit`], with `source: AnimationSource::AutoFit`.
+#[derive(EntityEvent, Reflect, Clone)]
+#[reflect(Event, FromReflect)]
+pub struct AutoFit {
+    #[event_target]
+    pub camera:       Entity,
+    pub target:       FitTarget,
+    pub view:         BestView,
+    pub margin:       FitMargins,
+    pub frame:        FitFrame,
+    pub anchor:       FitAnchor,
+    pub volume:       FitVolume,
+    pub pose:         FitPose,
+    pub duration:     Duration,
+    pub easing:       EaseFunction,
+    pub load_timeout: Option<Duration>,
+}
+
+impl AutoFit {
+    pub fn new(camera: Entity, target: impl Into<FitTarget>) -> Self {
+        Self {
+            camera,
+            target: target.into(),
+            view: BestView::MaxSilhouette,
+            margin: FitMargins::uniform(0.1),
+            frame: FitFrame::Viewport,
+            anchor: FitAnchor::Center,
+            volume: FitVolume::Vertices,
+            pose: FitPose::BindPose,
+            duration: Duration::ZERO,
+            easing: EaseFunction::CubicOut,
+            load_timeout: None,
+        }
+    }
+
+    pub const fn view(mut self, view: BestView) -> Self {
+        self.view = view;
+        self
+    }
+
+    pub fn margin(mut self, margin: impl Into<FitMargins>) -> Self {
+        self.margin = margin.into();
+        self
+    }
+
+    pub const fn frame(mut self, frame: FitFrame) -> Self {
+        self.frame = frame;
+        self
+    }
+
+    pub const fn anchor(mut self, anchor: FitAnchor) -> Self {
+        self.anchor = anchor;
+        self
+    }
+
+    pub const fn volume(mut self, volume: FitVolume) -> Self {
+        self.volume = volume;
+        self
+    }
+
+    pub const fn pose(mut self, pose: FitPose) -> Self {
+        self.pose = pose;
         self
     }
 
@@ -536,6 +1170,11 @@ impl AnimateToFit {
         self.easing = easing;
         self
     }
+
+    pub const fn wait_for_load(mut self, timeout: Duration) -> Self {
+        self.load_timeout = Some(timeout);
+        self
+    }
 }
 
 /// `LookAt` — rotates the camera in place to face a target entity.
@@ -632,38 +1271,73 @@ impl LookAt {
 /// # Fields
 ///
 /// - `camera` — the entity with a `PanOrbitCamera` component.
-/// - `target` — the entity to frame; must have a `Mesh3d` (direct or on descendants).
+/// - `target` — the [`FitTarget`] to frame; see [`ZoomToFit`].
 /// - `margin` — see [`ZoomToFit`] for details on how margin is applied.
+/// - `frame` — see [`ZoomToFit`].
+/// - `anchor` — see [`ZoomToFit`].
+/// - `volume` — see [`ZoomToFit`].
+/// - `pose` — see [`ZoomToFit`].
+/// - `load_timeout` — see [`ZoomToFit`].
 /// - `duration` — see module-level docs on **Duration**.
 /// - `easing` — see module-level docs on **Easing**.
 ///
 /// Animated paths route through [`PlayAnimation`] using [`CameraMove::ToOrbit`],
 /// so the full event sequence is `AnimationBegin` → `CameraMoveBegin` →
 /// `CameraMoveEnd` → `AnimationEnd` with `source: AnimationSource::LookAtAndZoomToFit`.
-#[derive(EntityEvent, Reflect)]
+#[derive(EntityEvent, Reflect, Clone)]
 #[reflect(Event, FromReflect)]
 pub struct LookAtAndZoomToFit {
     #[event_target]
-    pub camera:   Entity,
-    pub target:   Entity,
-    pub margin:   f32,
-    pub duration: Duration,
-    pub easing:   EaseFunction,
+    pub camera:       Entity,
+    pub target:       FitTarget,
+    pub margin:       FitMargins,
+    pub frame:        FitFrame,
+    pub anchor:       FitAnchor,
+    pub volume:       FitVolume,
+    pub pose:         FitPose,
+    pub duration:     Duration,
+    pub easing:       EaseFunction,
+    pub load_timeout: Option<Duration>,
 }
 
 impl LookAtAndZoomToFit {
-    pub const fn new(camera: Entity, target: Entity) -> Self {
+    pub fn new(camera: Entity, target: impl Into<FitTarget>) -> Self {
         Self {
             camera,
-            target,
-            margin: 0.1,
+            target: target.into(),
+            margin: FitMargins::uniform(0.1),
+            frame: FitFrame::Viewport,
+            anchor: FitAnchor::Center,
+            volume: FitVolume::Vertices,
+            pose: FitPose::BindPose,
             duration: Duration::ZERO,
             easing: EaseFunction::CubicOut,
+            load_timeout: None,
         }
     }
 
-    pub const fn margin(mut self, margin: f32) -> Self {
-        self.margin = margin;
+    pub fn margin(mut self, margin: impl Into<FitMargins>) -> Self {
+        self.margin = margin.into();
+        self
+    }
+
+    pub const fn frame(mut self, frame: FitFrame) -> Self {
+        self.frame = frame;
+        self
+    }
+
+    pub const fn anchor(mut self, anchor: FitAnchor) -> Self {
+        self.anchor = anchor;
+        self
+    }
+
+    pub const fn volume(mut self, volume: FitVolume) -> Self {
+        self.volume = volume;
+        self
+    }
+
+    pub const fn pose(mut self, pose: FitPose) -> Self {
+        self.pose = pose;
         self
     }
 
@@ -676,22 +1350,77 @@ impl LookAtAndZoomToFit {
         self.easing = easing;
         self
     }
+
+    pub const fn wait_for_load(mut self, timeout: Duration) -> Self {
+        self.load_timeout = Some(timeout);
+        self
+    }
 }
 
 /// `SetFitTarget` — sets the visualization target without triggering a zoom. Allows you
 /// to inspect bounds before triggering [`ZoomToFit`].
 ///
 /// - `camera` — the entity with a `PanOrbitCamera` component.
-/// - `target` — the entity whose bounds to visualize; must have a `Mesh3d` (direct or on
-///   descendants).
+/// - `target` — the [`FitTarget`] whose bounds to visualize; entity targets must have a `Mesh3d`
+///   (direct or on descendants).
+/// - `margin` — the [`FitMargins`] the visualization draws as the requested per-edge targets. Fit
+///   events set this to their own margin.
+/// - `frame` — the [`FitFrame`] the visualization outlines. Fit events set this to their own frame;
+///   [`FitFrame::Unoccluded`] follows the camera's current unoccluded rectangle.
+/// - `anchor` — the [`FitAnchor`] the visualization marks. Fit events set this to their own anchor.
+/// - `volume` — the [`FitVolume`] whose silhouette the visualization draws.
+/// - `pose` — the [`FitPose`] of animated meshes the visualization draws.
+///
+/// The camera keeps the target in [`CurrentFitTarget`](crate::CurrentFitTarget) and the rest in
+/// [`CurrentFitOptions`](crate::CurrentFitOptions).
 #[derive(EntityEvent, Reflect)]
 #[reflect(Event, FromReflect)]
 pub struct SetFitTarget {
     #[event_target]
     pub camera: Entity,
-    pub target: Entity,
+    pub target: FitTarget,
+    pub margin: FitMargins,
+    pub frame:  FitFrame,
+    pub anchor: FitAnchor,
+    pub volume: FitVolume,
+    pub pose:   FitPose,
 }
 
 impl SetFitTarget {
-    pub const fn new(camera: Entity, target: Entity) -> Self { Self { camera, target } }
+    pub fn new(camera: Entity, target: impl Into<FitTarget>) -> Self {
+        Self {
+            camera,
+            target: target.into(),
+            margin: FitMargins::uniform(0.1),
+            frame: FitFrame::Viewport,
+            anchor: FitAnchor::Center,
+            volume: FitVolume::Vertices,
+            pose: FitPose::BindPose,
+        }
+    }
+
+    pub fn margin(mut self, margin: impl Into<FitMargins>) -> Self {
+        self.margin = margin.into();
+        self
+    }
+
+    pub const fn frame(mut self, frame: FitFrame) -> Self {
+        self.frame = frame;
+        self
+    }
+
+    pub const fn anchor(mut self, anchor: FitAnchor) -> Self {
+        self.anchor = anchor;
+        self
+    }
+
+    pub const fn volume(mut self, volume: FitVolume) -> Self {
+        self.volume = volume;
+        self
+    }
+
+    pub const fn pose(mut self, pose: FitPose) -> Self {
+        self.pose = pose;
+        self
+    }
 }
@@ -1,15 +1,25 @@
 //! Fit algorithm for framing objects in the camera view.
 //!
-//! Provides screen-space projection, margin calculation, and a binary search convergence
-//! loop that finds the optimal camera radius and focus to frame a set of mesh vertices
-//! with a specified margin.
+//! Provides screen-space projection, margin calculation, and the solvers that find the optimal
+//! camera radius and focus to frame a set of mesh vertices with the specified per-edge margins:
+//! a binary search convergence loop, and an analytic solver for very large point sets.
 
 use core::fmt;
 
 use bevy::prelude::*;
 
+use crate::components::FitAlgorithm;
+use crate::components::FitFallback;
+use crate::components::FitStrategy;
+use crate::events::FitAnchor;
+use crate::events::FitFrame;
+use crate::events::FitMargins;
+use crate::events::MarginUnit;
+use crate::projection::FitProjection;
+use crate::support::MIN_VISIBLE_DEPTH;
+use crate::support::OrbitLimits;
+use crate::support::PointDepths;
 use crate::support::ScreenSpaceBounds;
-use crate::support::projection_aspect_ratio;
 
 // ============================================================================
 // Constants
@@ -21,18 +31,30 @@ pub const CENTERING_MAX_ITERATIONS: usize = 10;
 pub const CENTERING_TOLERANCE: f32 = 0.0001; // normalized screen-space center offset
 pub const MIN_MARGIN: f32 = 0.0;
 pub const MAX_MARGIN: f32 = 0.9999;
+/// Minimum normalized width and height of a fit frame; smaller frames fall back to the viewport.
+pub const MIN_FRAME_EXTENT: f32 = 0.01;
 /// Minimum search radius as a fraction of the object radius (0.1x).
 pub const MIN_RADIUS_MULTIPLIER: f32 = 0.1;
 /// Maximum search radius as a multiple of the object radius (100x).
 pub const MAX_RADIUS_MULTIPLIER: f32 = 100.0;
 /// Initial best-guess radius as a multiple of the object radius (2x).
 pub const INITIAL_RADIUS_MULTIPLIER: f32 = 2.0;
+/// Narrowest field of view (radians) a field-of-view fit may choose.
+pub const MIN_FIT_FOV: f32 = 0.01;
+/// Widest field of view (radians) a field-of-view fit may choose, short of the 180° where the
+/// visible extent becomes infinite.
+pub const MAX_FIT_FOV: f32 = 3.0;
+/// Field-of-view convergence tolerance in radians.
+pub const FOV_TOLERANCE: f32 = 1e-5;
+/// Extra depth beyond the near plane, as a fraction of its distance, that fits keep between the
+/// camera and the target's nearest point (1%).
+pub const NEAR_PLANE_CLEARANCE: f32 = 0.01;
+/// Size of a target's bounding box, relative to its distance from the origin, below which its
+/// points are treated as coinciding.
+pub const ZERO_EXTENT_EPSILON: f32 = 1e-6;
 /// Minimum screen-space extent before treating a dimension as degenerate (edge-on).
 /// Below this threshold the dimension is ignored for fit purposes.
 pub const DEGENERATE_EXTENT_THRESHOLD: f32 = 1e-6;
-/// Returns the zoom margin multiplier (1.0 / (1.0 - margin))
-/// For example, a margin of 0.08 returns 1.087 (8% margin)
-pub const fn zoom_margin_multiplier(margin: f32) -> f32 { 1.0 / (1.0 - margin) }
 
 // ============================================================================
 // Types
@@ -47,16 +69,178 @@ pub enum Edge {
     Bottom,
 }
 
-/// Successful fit output: camera orbit radius and centered focus point.
+/// Per-edge margins in normalized screen-space units (the same units as
+/// [`ScreenSpaceBounds`] margins).
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct EdgeMargins {
+    pub left:   f32,
+    pub right:  f32,
+    pub top:    f32,
+    pub bottom: f32,
+}
+
+impl EdgeMargins {
+    /// Converts [`FitMargins`] inside a normalized viewport `frame` to normalized screen-space
+    /// units for the given projected bounds. The result includes the frame's own insets from
+    /// the viewport edges.
+    ///
+    /// Fraction margins scale with the frame's visible extent, pixel margins are first converted
+    /// to fractions of the frame's size in `viewport_size`, and world margins are divided by the
+    /// depth of the extreme point on each edge (used as-is for orthographic, whose normalized
+    /// units are world units). Returns `None` for pixel margins when no viewport size is
+    /// available.
+    pub fn from_fit_margins(
+        margins: &FitMargins,
+        frame: Rect,
+        bounds: &ScreenSpaceBounds,
+        depths: &PointDepths,
+        is_ortho: bool,
+        viewport_size: Option<Vec2>,
+    ) -> Option<Self> {
+        let margins = margins.to_screen_fractions(viewport_size.map(|size| size * frame.size()))?;
+        let width = 2.0 * bounds.half_extent_x;
+        let height = 2.0 * bounds.half_extent_y;
+        let inner = match margins.unit {
+            MarginUnit::World if is_ortho => margins,
+            MarginUnit::World => FitMargins::new(
+                margins.left / depths.min_x_depth,
+                margins.right / depths.max_x_depth,
+                margins.top / depths.max_y_depth,
+                margins.bottom / depths.min_y_depth,
+            ),
+            MarginUnit::Fraction | MarginUnit::LogicalPixels => FitMargins::new(
+                width * frame.width() * margins.left,
+                width * frame.width() * margins.right,
+                height * frame.height() * margins.top,
+                height * frame.height() * margins.bottom,
+            ),
+        };
+        Some(Self {
+            left:   width * frame.min.x + inner.left,
+            right:  width * (1.0 - frame.max.x) + inner.right,
+            top:    height * frame.min.y + inner.top,
+            bottom: height * (1.0 - frame.max.y) + inner.bottom,
+        })
+    }
+
+    /// Returns the normalized screen-space center of the area left inside these margins.
+    /// This is where the fit centers the target's silhouette.
+    pub fn center(&self) -> (f32, f32) {
+        (
+            (self.left - self.right) * 0.5,
+            (self.bottom - self.top) * 0.5,
+        )
+    }
+
+    /// Narrows these margins so the area left inside them is centered on `anchor` within the
+    /// normalized viewport `frame`, for a view with visible `half_extents`. The side nearest the
+    /// anchor keeps its margin and the far side grows to match. [`FitAnchor::Center`] returns
+    /// the margins unchanged.
+    pub fn anchored(&self, anchor: FitAnchor, frame: Rect, half_extents: Vec2) -> Self {
+        let Some(point) = anchor.to_screen(frame, half_extents) else {
+            return *self;
+        };
+        // Half the width of the widest interval inside `low..high` centered on `at`.
+        let narrow = |low: f32, high: f32, at: f32| {
+            let at = at.clamp(low, high.max(low));
+            (at, (at - low).min(high - at).max(0.0))
+        };
+        let (x, half_width) = narrow(
+            -half_extents.x + self.left,
+            half_extents.x - self.right,
+            point.x,
+        );
+        let (y, half_height) = narrow(
+            -half_extents.y + self.bottom,
+            half_extents.y - self.top,
+            point.y,
+        );
+        Self {
+            left:   half_extents.x + x - half_width,
+            right:  half_extents.x - x - half_width,
+            top:    half_extents.y - y - half_height,
+            bottom: half_extents.y + y - half_height,
+        }
+    }
+}
+
+/// Successful fit output: camera orbit radius and centered focus point, the orientation the fit
+/// was made for, and which camera limits it ran into. Field-of-view fits also carry the
+/// perspective `fov` that frames the target. [`FitSolver`](crate::FitSolver) returns one in its
+/// [`FitPreview`](crate::FitPreview).
 #[derive(Debug, Clone, Copy)]
 pub struct FitSolution {
     pub radius: f32,
     pub focus:  Vec3,
+    pub yaw:    f32,
+    pub pitch:  f32,
+    pub fov:    Option<f32>,
+    pub limits: FitLimits,
 }
 
-/// Explicit fit calculation failures.
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+/// What a fit frames its points with: the orientation, the requested framing and the camera's
+/// fit settings. [`calculate_fit`] reads `algorithm`; [`calculate_fov_fit`] ignores it, and the
+/// `strategy` and `fallback` are applied by [`fit_points`](crate::observers::fit_points).
+#[derive(Debug, Clone, Copy, Default)]
+pub struct FitParams {
+    pub yaw:       f32,
+    pub pitch:     f32,
+    pub margins:   FitMargins,
+    /// The frame to fit inside, with [`FitFrame::Unoccluded`] already resolved.
+    pub frame:     FitFrame,
+    pub anchor:    FitAnchor,
+    pub algorithm: FitAlgorithm,
+    pub strategy:  FitStrategy,
+    pub fallback:  FitFallback,
+}
+
+/// Which camera limits or fallbacks kept a fit from framing its target exactly as requested.
+/// Carried by [`FitLimited`](crate::FitLimited).
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Reflect)]
+pub struct FitLimits {
+    /// The requested yaw was outside `yaw_lower_limit`/`yaw_upper_limit` and was clamped; the
+    /// fit frames the target from the clamped yaw.
+    pub yaw:        bool,
+    /// The requested pitch was outside `pitch_lower_limit`/`pitch_upper_limit` and was clamped;
+    /// the fit frames the target from the clamped pitch.
+    pub pitch:      bool,
+    /// The radius that frames the target was outside `zoom_lower_limit`/`zoom_upper_limit`, so
+    /// the target does not fill the requested margins exactly.
+    pub zoom:       bool,
+    /// The camera was backed out along its view direction so the target clears the
+    /// projection's near plane, e.g. when fitting from inside a room. The target is framed
+    /// smaller than the margins ask for.
+    pub near_plane: bool,
+    /// The target was smaller than the camera's [`FitFallback::min_radius`](crate::FitFallback),
+    /// such as a single point or an empty marker entity, so a sphere of that radius around it was
+    /// framed instead.
+    pub min_radius: bool,
+}
+
+impl FitLimits {
+    /// Returns `true` if any limit was hit.
+    pub const fn any(&self) -> bool {
+        self.yaw || self.pitch || self.zoom || self.near_plane || self.min_radius
+    }
+}
+
+/// Why a fit could not be computed. Carried by [`FitFailed`](crate::FitFailed).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Reflect)]
 pub enum FitError {
+    /// The camera entity is missing or lacks a `PanOrbitCamera`, `Camera` or `Projection`.
+    MissingCamera,
+    /// The target has no `Mesh3d` on it or its descendants, or its geometry is empty.
+    NoMesh,
+    /// A target mesh asset has not finished loading.
+    MeshNotLoaded,
+    /// The target's points all coincide, such as a single vertex or a zero-scale mesh, and the
+    /// camera has no [`FitFallback::min_radius`](crate::FitFallback) to frame them at.
+    ZeroExtent,
+    /// The target's meshes have no `Float32x3` `Mesh::ATTRIBUTE_POSITION`.
+    NoPositionAttribute,
+    /// The camera uses a `Projection::Custom` whose type was not registered with
+    /// [`register_fit_projection`](crate::FitProjectionAppExt::register_fit_projection).
+    UnsupportedProjection,
     /// Camera viewport size/aspect ratio is unavailable.
     NoViewport,
     /// All candidate fits projected points behind the camera.
@@ -66,6 +250,12 @@ pub enum FitError {
 impl fmt::Display for FitError {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
+            Self::MissingCamera => write!(f, "camera entity has no PanOrbitCamera"),
+            Self::NoMesh => write!(f, "target has no mesh or geometry to frame"),
+            Self::MeshNotLoaded => write!(f, "target mesh is not loaded yet"),
+            Self::ZeroExtent => write!(f, "target has no extent to frame"),
+            Self::NoPositionAttribute => write!(f, "target mesh has no position attribute"),
+            Self::UnsupportedProjection => write!(f, "camera projection is not supported"),
             Self::NoViewport => write!(f, "camera viewport size is unavailable"),
             Self::PointsBehindCamera => {
                 write!(f, "all candidate fits project points behind camera")
@@ -78,43 +268,96 @@ impl fmt::Display for FitError {
 // Target margin calculation
 // ============================================================================
 
-/// Computes the target margins for the constraining dimension based on aspect ratios.
-/// Returns `(target_margin_x, target_margin_y)`.
-fn calculate_target_margins(bounds: &ScreenSpaceBounds, zoom_multiplier: f32) -> (f32, f32) {
+/// Clamps each edge to `[MIN_MARGIN, MAX_MARGIN]` (NaN becomes `MIN_MARGIN`), then scales
+/// opposite edges down proportionally if together they exceed `MAX_MARGIN`, so some screen
+/// space always remains for the target.
+///
+/// World-unit margins have no screen-space upper bound, so they are only floored at zero.
+/// Pixel margins are expected to have been converted to fractions already.
+fn clamp_fit_margins(margins: &FitMargins) -> FitMargins {
+    let floor_edge = |edge: f32| {
+        if edge.is_nan() {
+            MIN_MARGIN
+        } else {
+            edge.max(MIN_MARGIN)
+        }
+    };
+    if margins.unit == MarginUnit::World {
+        return FitMargins::new(
+            floor_edge(margins.left),
+            floor_edge(margins.right),
+            floor_edge(margins.top),
+            floor_edge(margins.bottom),
+        )
+        .unit(MarginUnit::World);
+    }
+
+    let clamp_pair = |a: f32, b: f32| {
+        let (a, b) = (floor_edge(a).min(MAX_MARGIN), floor_edge(b).min(MAX_MARGIN));
+        let total = a + b;
+        if total > MAX_MARGIN {
+            let scale = MAX_MARGIN / total;
+            (a * scale, b * scale)
+        } else {
+            (a, b)
+        }
+    };
+    let (left, right) = clamp_pair(margins.left, margins.right);
+    let (top, bottom) = clamp_pair(margins.top, margins.bottom);
+    FitMargins::new(left, right, top, bottom).unit(margins.unit)
+}
+
+/// Clips a normalized fit frame to the viewport. Frames narrower or shorter than
+/// `MIN_FRAME_EXTENT` (including NaN) fall back to the whole viewport.
+fn clamp_fit_frame(frame: Rect) -> Rect {
+    let viewport = Rect::new(0.0, 0.0, 1.0, 1.0);
+    let clipped = frame.intersect(viewport);
+    if clipped.width() >= MIN_FRAME_EXTENT && clipped.height() >= MIN_FRAME_EXTENT {
+        clipped
+    } else {
+        viewport
+    }
+}
+
+/// Computes the target margin for every edge based on aspect ratios.
+///
+/// The constraining dimension's targets equal the requested `margins`. The other dimension's
+/// targets add equal extra space on both sides, so the silhouette sits centered within the
+/// area left inside `margins`.
+pub fn calculate_target_margins(bounds: &ScreenSpaceBounds, margins: &EdgeMargins) -> EdgeMargins {
     let horizontal_extent = bounds.max_norm_x - bounds.min_norm_x;
     let vertical_extent = bounds.max_norm_y - bounds.min_norm_y;
+    let available_x = 2.0 * bounds.half_extent_x - margins.left - margins.right;
+    let available_y = 2.0 * bounds.half_extent_y - margins.top - margins.bottom;
 
     // Guard against degenerate screen-space extents (edge-on flat objects).
     // When one dimension is near-zero, fit based on the non-degenerate dimension only.
-    if vertical_extent < DEGENERATE_EXTENT_THRESHOLD {
-        let target_x = bounds.half_extent_x / zoom_multiplier;
-        return (bounds.half_extent_x - target_x, bounds.half_extent_y);
-    }
-    if horizontal_extent < DEGENERATE_EXTENT_THRESHOLD {
-        let target_y = bounds.half_extent_y / zoom_multiplier;
-        return (bounds.half_extent_x, bounds.half_extent_y - target_y);
-    }
-
-    let boundary_aspect = horizontal_extent / vertical_extent;
-    let screen_aspect = bounds.half_extent_x / bounds.half_extent_y;
-
-    // If boundary is wider (relative to height) than screen, width constrains
-    let width_constrains = boundary_aspect > screen_aspect;
-
-    let (target_edge_x, target_edge_y) = if width_constrains {
-        let target_x = bounds.half_extent_x / zoom_multiplier;
-        let target_y = target_x / boundary_aspect;
-        (target_x, target_y)
+    let width_constrains = if vertical_extent < DEGENERATE_EXTENT_THRESHOLD {
+        true
+    } else if horizontal_extent < DEGENERATE_EXTENT_THRESHOLD {
+        false
     } else {
-        let target_y = bounds.half_extent_y / zoom_multiplier;
-        let target_x = target_y * boundary_aspect;
-        (target_x, target_y)
+        // If boundary is wider (relative to height) than the available area, width constrains
+        horizontal_extent / vertical_extent > available_x / available_y
     };
 
-    (
-        bounds.half_extent_x - target_edge_x,
-        bounds.half_extent_y - target_edge_y,
-    )
+    if width_constrains {
+        let fitted_height = vertical_extent * available_x / horizontal_extent.max(f32::EPSILON);
+        let extra_y = (available_y - fitted_height) * 0.5;
+        EdgeMargins {
+            top: margins.top + extra_y,
+            bottom: margins.bottom + extra_y,
+            ..*margins
+        }
+    } else {
+        let fitted_width = horizontal_extent * available_y / vertical_extent.max(f32::EPSILON);
+        let extra_x = (available_x - fitted_width) * 0.5;
+        EdgeMargins {
+            left: margins.left + extra_x,
+            right: margins.right + extra_x,
+            ..*margins
+        }
+    }
 }
 
 // ============================================================================
@@ -122,56 +365,355 @@ fn calculate_target_margins(bounds: &ScreenSpaceBounds, zoom_multiplier: f32) ->
 // ============================================================================
 
 /// Calculates the optimal radius and centered focus to fit pre-extracted vertices in the camera
-/// view. The focus is adjusted so the projected mesh silhouette is centered in the viewport.
+/// view. The focus is adjusted so the projected mesh silhouette is centered in the area left
+/// inside the requested `margins` within `frame` — the viewport center when the frame is the
+/// whole viewport and the margins are symmetric.
+///
+/// [`FitAlgorithm::BinarySearch`] computes, for each candidate radius, the focus that centers the
+/// projected silhouette in that area (since the geometric center doesn't project to screen
+/// center from off-axis angles), then evaluates margins at that centered position. Returns the
+/// fit solution where the constraining margins equal their targets and the silhouette is
+/// centered. [`FitAlgorithm::Analytic`] solves for the same solution directly and falls back to
+/// the binary search when it cannot.
+///
+/// A [`FitAnchor::Normalized`] `anchor` centers the silhouette on that point of the frame
+/// instead: the margins are narrowed around the anchor (see [`EdgeMargins::anchored`]), so the
+/// side nearest the anchor meets its margin and the silhouette still stays inside all of them.
 ///
-/// For each candidate radius, computes the focus that centers the projected silhouette in the
-/// viewport (since the geometric center doesn't project to screen center from off-axis angles),
-/// then evaluates margins at that centered position. Returns the fit solution where
-/// the constraining margin equals the target and the silhouette is centered.
+/// Pixel margins are converted using the camera's `logical_viewport_size`; world-unit margins
+/// are converted at each candidate radius from the depths of the extreme points.
+///
+/// The camera's `limits` are respected: `yaw` and `pitch` are clamped before fitting, and a
+/// radius outside the zoom limits is clamped with the focus re-centered at the clamped radius.
+/// Before that, a radius that would put the target's nearest point in front of the
+/// projection's [`near plane`](FitProjection::near_plane) is increased until it clears it.
+/// [`FitSolution::limits`] records which limits applied.
+///
+/// Points that all coincide have no size to frame and fail with [`FitError::ZeroExtent`];
+/// [`fit_points`](crate::observers::fit_points) frames them with the camera's
+/// [`FitFallback`] first.
 ///
 /// Note: A lateral camera shift doesn't change point depths, so the centering is geometrically
 /// exact for the constraining margin check.
 pub fn calculate_fit(
     points: &[Vec3],
     geometric_center: Vec3,
-    yaw: f32,
-    pitch: f32,
-    margin: f32,
-    projection: &Projection,
+    params: &FitParams,
+    projection: &dyn FitProjection,
     camera: &Camera,
+    limits: &OrbitLimits,
 ) -> Result<FitSolution, FitError> {
-    let clamped_margin = if margin.is_nan() {
-        MIN_MARGIN
+    let setup = FitSetup::new(points, geometric_center, params, projection, camera, limits)?;
+
+    let analytic = if params.algorithm == FitAlgorithm::Analytic {
+        let fit = solve_analytic(&setup);
+        if fit.is_none() {
+            debug!("calculate_fit: analytic solver has no solution, falling back to binary search");
+        }
+        fit
     } else {
-        margin.clamp(MIN_MARGIN, MAX_MARGIN)
+        None
+    };
+    let fit = match analytic {
+        Some(fit) => fit,
+        None => binary_search_fit(&setup)?,
+    };
+
+    let (cleared_radius, near_plane) = clear_near_plane(&setup, fit.radius);
+    let radius = limits.clamp_radius(cleared_radius);
+    if radius == fit.radius && !near_plane {
+        return Ok(fit);
+    }
+    if radius != cleared_radius {
+        debug!(
+            "calculate_fit: clamped radius {cleared_radius:.3} to zoom limits ({:.3}, {:?})",
+            limits.zoom_lower, limits.zoom_upper
+        );
+    }
+    Ok(FitSolution {
+        radius,
+        focus: refine_focus_centering(&setup, radius),
+        limits: FitLimits {
+            zoom: radius != cleared_radius,
+            near_plane,
+            ..fit.limits
+        },
+        ..fit
+    })
+}
+
+/// Calculates the perspective field of view and centered focus that fit pre-extracted vertices
+/// with the camera held at `radius`, as for a lens zoom or a dolly zoom.
+///
+/// Normalized perspective coordinates are independent of the field of view, which only scales
+/// the visible half extents by `tan(fov / 2)`. This binary searches the field of view between
+/// [`MIN_FIT_FOV`] and [`MAX_FIT_FOV`], re-centering the focus at each candidate as
+/// [`calculate_fit`] does for the radius. A target needing a field of view outside that range
+/// gets the nearest bound.
+///
+/// A `radius` that would leave part of the target behind the camera or in front of the near
+/// plane, such as one inside a room being framed, is first backed out until the target clears
+/// the near plane. It is then clamped to the camera's zoom limits and `yaw` and `pitch` to its
+/// orientation limits, as in [`calculate_fit`].
+pub fn calculate_fov_fit(
+    points: &[Vec3],
+    geometric_center: Vec3,
+    radius: f32,
+    params: &FitParams,
+    perspective: &PerspectiveProjection,
+    camera: &Camera,
+    limits: &OrbitLimits,
+) -> Result<FitSolution, FitError> {
+    let setup = FitSetup::new(
+        points,
+        geometric_center,
+        params,
+        perspective,
+        camera,
+        limits,
+    )?;
+    let (cleared_radius, near_plane) = clear_near_plane(&setup, radius);
+    let clamped_radius = limits.clamp_radius(cleared_radius);
+    let limits = FitLimits {
+        zoom: clamped_radius != cleared_radius,
+        near_plane,
+        ..setup.limits
     };
-    if clamped_margin != margin {
-        warn!(
-            "calculate_fit: clamped margin from {margin} to {clamped_margin} (expected [{MIN_MARGIN}, {MAX_MARGIN}])"
+    let radius = clamped_radius;
+    let tan_half_fov = (perspective.fov * 0.5).tan();
+
+    let mut min_fov = MIN_FIT_FOV;
+    let mut max_fov = MAX_FIT_FOV;
+    let mut best = None;
+    let mut best_error = f32::INFINITY;
+
+    for iteration in 0..MAX_ITERATIONS {
+        let test_fov = (min_fov + max_fov) * 0.5;
+        let candidate = FitSetup {
+            half_extents: setup.half_extents * ((test_fov * 0.5).tan() / tan_half_fov),
+            ..setup
+        };
+        let centered_focus = refine_focus_centering(&candidate, radius);
+        // The field of view does not change which points are in front of the camera.
+        let Some(check) = check_margins(&candidate, radius, centered_focus)? else {
+            return Err(FitError::PointsBehindCamera);
+        };
+
+        debug!(
+            "Iteration {iteration}: fov={test_fov:.4} | {} slack={:.3} | range=[{min_fov:.4}, {max_fov:.4}]",
+            check.dimension, check.slack
         );
+
+        if check.slack.abs() < best_error {
+            best_error = check.slack.abs();
+            best = Some((test_fov, centered_focus));
+        }
+
+        if check.slack > 0.0 {
+            max_fov = test_fov;
+        } else {
+            min_fov = test_fov;
+        }
+
+        if (max_fov - min_fov) < FOV_TOLERANCE {
+            break;
+        }
+    }
+
+    let (fov, focus) = best.ok_or(FitError::PointsBehindCamera)?;
+    Ok(FitSolution {
+        radius,
+        focus,
+        yaw: setup.yaw,
+        pitch: setup.pitch,
+        fov: Some(fov),
+        limits,
+    })
+}
+
+/// Fit inputs shared by the solvers, resolved once per [`calculate_fit`] or [`calculate_fov_fit`]
+/// call.
+struct FitSetup<'a> {
+    points:           &'a [Vec3],
+    geometric_center: Vec3,
+    /// Yaw and pitch after clamping to the camera's limits.
+    yaw:              f32,
+    pitch:            f32,
+    rot:              Quat,
+    projection:       &'a dyn FitProjection,
+    /// Visible half extents at the projection's current zoom.
+    half_extents:     Vec2,
+    /// Clamped margins, in fractions or world units.
+    margins:          FitMargins,
+    /// Clamped normalized fit frame.
+    frame:            Rect,
+    anchor:           FitAnchor,
+    /// Orientation limits hit while clamping `yaw` and `pitch`.
+    limits:           FitLimits,
+}
+
+impl<'a> FitSetup<'a> {
+    /// Clamps the orientation to the camera's `limits`, and the frame and margins to usable
+    /// values, and resolves the visible half extents for the camera's viewport.
+    fn new(
+        points: &'a [Vec3],
+        geometric_center: Vec3,
+        params: &FitParams,
+        projection: &'a dyn FitProjection,
+        camera: &Camera,
+        limits: &OrbitLimits,
+    ) -> Result<Self, FitError> {
+        if is_zero_extent(points) {
+            return Err(FitError::ZeroExtent);
+        }
+
+        let clamped_yaw = limits.clamp_yaw(params.yaw);
+        let clamped_pitch = limits.clamp_pitch(params.pitch);
+        let orientation_limits = FitLimits {
+            yaw: clamped_yaw != params.yaw,
+            pitch: clamped_pitch != params.pitch,
+            ..default()
+        };
+        let (yaw, pitch) = (clamped_yaw, clamped_pitch);
+
+        let viewport_size = camera.logical_viewport_size();
+
+        let requested_frame = params
+            .frame
+            .to_normalized(viewport_size)
+            .ok_or(FitError::NoViewport)?;
+        let frame = clamp_fit_frame(requested_frame);
+        if frame != requested_frame {
+            warn!(
+                "calculate_fit: clipped fit frame from {requested_frame:?} to {frame:?} (expected a non-empty rectangle inside the viewport)"
+            );
+        }
+
+        // Pixel margins become fractions of the frame up front so they are clamped like fractions.
+        let margins = params
+            .margins
+            .to_screen_fractions(viewport_size.map(|size| size * frame.size()))
+            .ok_or(FitError::NoViewport)?;
+        let clamped_margins = clamp_fit_margins(&margins);
+        if clamped_margins != margins {
+            warn!(
+                "calculate_fit: clamped margins from {margins:?} to {clamped_margins:?} (expected each edge in [{MIN_MARGIN}, {MAX_MARGIN}])"
+            );
+        }
+
+        let half_extents = projection
+            .half_extents(viewport_size)
+            .ok_or(FitError::NoViewport)?;
+
+        Ok(Self {
+            points,
+            geometric_center,
+            yaw,
+            pitch,
+            rot: Quat::from_euler(EulerRot::YXZ, yaw, -pitch, 0.0),
+            projection,
+            half_extents,
+            margins: clamped_margins,
+            frame,
+            anchor: params.anchor,
+            limits: orientation_limits,
+        })
     }
 
-    let aspect_ratio = projection_aspect_ratio(projection, camera.logical_viewport_size())
-        .ok_or(FitError::NoViewport)?;
+    fn is_ortho(&self) -> bool { self.projection.is_orthographic() }
+
+    /// A solution at `radius` and `focus` for this setup's orientation.
+    const fn solution(&self, radius: f32, focus: Vec3) -> FitSolution {
+        FitSolution {
+            radius,
+            focus,
+            yaw: self.yaw,
+            pitch: self.pitch,
+            fov: None,
+            limits: self.limits,
+        }
+    }
+
+    /// Visible half extents with the camera at `radius`.
+    fn half_extents_at(&self, radius: f32) -> Vec2 {
+        self.half_extents * self.projection.radius_extent_scale(radius)
+    }
 
-    // For ortho, the camera is always at a fixed distance from focus.
-    // PanOrbitCamera sets this to `(near + far) / 2.0`.
-    let ortho_fixed_distance = match projection {
-        Projection::Orthographic(o) => Some((o.near + o.far) * 0.5),
-        _ => None,
+    /// The requested margins for projected `bounds`, in normalized screen-space units and
+    /// centered on the anchor. Margins are already fractions or world units, so no viewport size
+    /// is needed here.
+    fn requested_margins(
+        &self,
+        bounds: &ScreenSpaceBounds,
+        depths: &PointDepths,
+    ) -> Option<EdgeMargins> {
+        let requested = EdgeMargins::from_fit_margins(
+            &self.margins,
+            self.frame,
+            bounds,
+            depths,
+            self.is_ortho(),
+            None,
+        )?;
+        Some(requested.anchored(
+            self.anchor,
+            self.frame,
+            Vec2::new(bounds.half_extent_x, bounds.half_extent_y),
+        ))
+    }
+}
+
+/// Returns `true` if `points` is non-empty and all its points coincide, leaving no size to frame
+/// at any radius.
+fn is_zero_extent(points: &[Vec3]) -> bool {
+    let Some(first) = points.first() else {
+        return false;
     };
+    let (min, max) = points
+        .iter()
+        .fold((*first, *first), |(min, max), p| (min.min(*p), max.max(*p)));
+    let scale = min.abs().max(max.abs()).max_element().max(1.0);
+    (max - min).max_element() <= ZERO_EXTENT_EPSILON * scale
+}
 
-    let is_ortho = ortho_fixed_distance.is_some();
-    let zoom_multiplier = zoom_margin_multiplier(clamped_margin);
+/// Backs `radius` out along the view direction until every point is at least the projection's
+/// near-plane depth (plus [`NEAR_PLANE_CLEARANCE`]) in front of the camera. Returns the radius
+/// and whether it had to grow.
+///
+/// Focus centering only moves the camera sideways, so each point's depth is the camera distance
+/// minus its offset from the geometric center toward the camera.
+fn clear_near_plane(setup: &FitSetup, radius: f32) -> (f32, bool) {
+    let Some(near) = setup.projection.near_plane() else {
+        return (radius, false);
+    };
+    let backward = setup.rot * Vec3::Z;
+    let nearest_offset = setup
+        .points
+        .iter()
+        .map(|point| (*point - setup.geometric_center).dot(backward))
+        .fold(f32::NEG_INFINITY, f32::max);
+    let min_depth = near.max(MIN_VISIBLE_DEPTH) * (1.0 + NEAR_PLANE_CLEARANCE);
+    let deficit = min_depth - (setup.projection.camera_distance(radius) - nearest_offset);
+    if deficit > 0.0 {
+        debug!(
+            "calculate_fit: backed radius {radius:.3} out by {deficit:.3} to clear the near plane"
+        );
+        (radius + deficit, true)
+    } else {
+        (radius, false)
+    }
+}
 
-    let rot = Quat::from_euler(EulerRot::YXZ, yaw, -pitch, 0.0);
+/// Binary search over the radius, re-centering the focus at every candidate.
+fn binary_search_fit(setup: &FitSetup) -> Result<FitSolution, FitError> {
+    let points = setup.points;
 
     // Compute the object's bounding sphere radius from points for sensible search bounds.
     // The search range is based purely on object size to ensure deterministic results
     // regardless of the camera's current radius.
     let object_radius = points
         .iter()
-        .map(|c| (*c - geometric_center).length())
+        .map(|c| (*c - setup.geometric_center).length())
         .fold(0.0_f32, f32::max);
 
     // Binary search for the correct radius.
@@ -181,7 +723,7 @@ pub fn calculate_fit(
     let mut min_radius = object_radius * MIN_RADIUS_MULTIPLIER;
     let mut max_radius = object_radius * MAX_RADIUS_MULTIPLIER;
     let mut best_radius = object_radius * INITIAL_RADIUS_MULTIPLIER;
-    let mut best_focus = geometric_center;
+    let mut best_focus = setup.geometric_center;
     let mut best_error = f32::INFINITY;
     let mut found_projectable_bounds = false;
 
@@ -190,33 +732,17 @@ pub fn calculate_fit(
     for iteration in 0..MAX_ITERATIONS {
         let test_radius = (min_radius + max_radius) * 0.5;
 
-        // Build the projection to use for this iteration.
-        // For ortho, we need to compute what `area` would be at this test scale.
-        let test_projection = build_test_projection(projection, test_radius);
-
         // Step 1: find the centered focus using accurate depth-based centering
-        let centered_focus = refine_focus_centering(
-            points,
-            geometric_center,
-            test_radius,
-            rot,
-            &test_projection,
-            aspect_ratio,
-            ortho_fixed_distance,
-            is_ortho,
-        );
+        let centered_focus = refine_focus_centering(setup, test_radius);
 
         // Step 2: evaluate margins at the centered focus position.
-        // For ortho, the camera distance is fixed regardless of test_radius.
-        let cam_distance = ortho_fixed_distance.unwrap_or(test_radius);
-        let cam_pos = centered_focus + rot * Vec3::new(0.0, 0.0, cam_distance);
-        let cam_global =
-            GlobalTransform::from(Transform::from_translation(cam_pos).with_rotation(rot));
-
-        let Some((bounds, _)) =
-            ScreenSpaceBounds::from_points(points, &cam_global, &test_projection, aspect_ratio)
+        let Some(MarginCheck {
+            slack,
+            dimension,
+            bounds,
+        }) = check_margins(setup, test_radius, centered_focus)?
         else {
-            warn!(
+            debug!(
                 "Iteration {iteration}: Points behind camera at radius {test_radius:.1}, searching higher"
             );
             min_radius = test_radius;
@@ -224,43 +750,21 @@ pub fn calculate_fit(
         };
         found_projectable_bounds = true;
 
-        let (target_margin_x, target_margin_y) = calculate_target_margins(&bounds, zoom_multiplier);
-
-        // Find constraining dimension (minimum margin).
-        // When a dimension has degenerate (near-zero) screen extent, force the
-        // other dimension to constrain — the degenerate dimension has no
-        // meaningful projection to fit against.
-        let h_min = bounds.left_margin.min(bounds.right_margin);
-        let v_min = bounds.top_margin.min(bounds.bottom_margin);
-        let vertical_extent = bounds.max_norm_y - bounds.min_norm_y;
-        let horizontal_extent = bounds.max_norm_x - bounds.min_norm_x;
-
-        let (current_margin, target_margin, dimension) =
-            if vertical_extent < DEGENERATE_EXTENT_THRESHOLD {
-                (h_min, target_margin_x, "H")
-            } else if horizontal_extent < DEGENERATE_EXTENT_THRESHOLD {
-                (v_min, target_margin_y, "V")
-            } else if h_min < v_min {
-                (h_min, target_margin_x, "H")
-            } else {
-                (v_min, target_margin_y, "V")
-            };
-
         debug!(
-            "Iteration {iteration}: radius={test_radius:.1} | {dimension} margin={current_margin:.3} \
-             target={target_margin:.3} | L={:.3} R={:.3} T={:.3} B={:.3} | range=[{min_radius:.1}, {max_radius:.1}]",
+            "Iteration {iteration}: radius={test_radius:.1} | {dimension} slack={slack:.3} \
+             | L={:.3} R={:.3} T={:.3} B={:.3} | range=[{min_radius:.1}, {max_radius:.1}]",
             bounds.left_margin, bounds.right_margin, bounds.top_margin, bounds.bottom_margin
         );
 
-        // Track the closest match to target margin
-        let margin_error = (current_margin - target_margin).abs();
+        // Track the closest match to the target margins
+        let margin_error = slack.abs();
         if margin_error < best_error {
             best_error = margin_error;
             best_radius = test_radius;
             best_focus = centered_focus;
         }
 
-        if current_margin > target_margin {
+        if slack > 0.0 {
             max_radius = test_radius;
         } else {
             min_radius = test_radius;
@@ -270,10 +774,7 @@ pub fn calculate_fit(
             debug!(
                 "Iteration {iteration}: Converged to best radius {best_radius:.3} error={best_error:.5}"
             );
-            return Ok(FitSolution {
-                radius: best_radius,
-                focus:  best_focus,
-            });
+            return Ok(setup.solution(best_radius, best_focus));
         }
     }
 
@@ -285,47 +786,75 @@ pub fn calculate_fit(
         "Binary search did not converge in {MAX_ITERATIONS} iterations. Using best radius {best_radius:.1}"
     );
 
-    Ok(FitSolution {
-        radius: best_radius,
-        focus:  best_focus,
-    })
+    Ok(setup.solution(best_radius, best_focus))
 }
 
-/// Builds a test projection with the given radius/scale for binary search iterations.
-///
-/// For perspective, returns the original projection unchanged.
-/// For orthographic, creates a modified projection with `area` recomputed for the test scale,
-/// since `PanOrbitCamera` maps `radius` → `OrthographicProjection::scale`.
-fn build_test_projection(projection: &Projection, test_radius: f32) -> Projection {
-    match projection {
-        Projection::Perspective(_) => projection.clone(),
-        Projection::Orthographic(ortho) => {
-            // Compute what the area would be at this scale.
-            // The current area is `base_size * current_scale`, so base_size = area / scale.
-            // At test scale: new_area = base_size * test_radius.
-            let current_scale = ortho.scale;
-            let scale_ratio = if current_scale.abs() > f32::EPSILON {
-                test_radius / current_scale
-            } else {
-                1.0
-            };
-            let new_area = Rect::new(
-                ortho.area.min.x * scale_ratio,
-                ortho.area.min.y * scale_ratio,
-                ortho.area.max.x * scale_ratio,
-                ortho.area.max.y * scale_ratio,
-            );
-            Projection::Orthographic(OrthographicProjection {
-                scale: test_radius,
-                area: new_area,
-                ..*ortho
-            })
-        },
-        _ => projection.clone(),
-    }
+/// How the silhouette at a candidate radius and focus compares to the requested margins.
+struct MarginCheck {
+    /// Room left over the target margins along the constraining dimension; negative when the
+    /// silhouette overflows them.
+    slack:     f32,
+    /// The constraining dimension, `"H"` or `"V"`, for logging.
+    dimension: &'static str,
+    bounds:    ScreenSpaceBounds,
+}
+
+/// Measures the margins with the camera at `radius` looking at `focus`. Returns `Ok(None)` when
+/// points end up behind the camera.
+fn check_margins(
+    setup: &FitSetup,
+    radius: f32,
+    focus: Vec3,
+) -> Result<Option<MarginCheck>, FitError> {
+    // For ortho, the camera distance is fixed regardless of radius.
+    let cam_distance = setup.projection.camera_distance(radius);
+    let cam_pos = focus + setup.rot * Vec3::new(0.0, 0.0, cam_distance);
+    let cam_global =
+        GlobalTransform::from(Transform::from_translation(cam_pos).with_rotation(setup.rot));
+
+    let Some((bounds, depths)) = ScreenSpaceBounds::from_points(
+        setup.points,
+        &cam_global,
+        setup.projection,
+        setup.half_extents_at(radius),
+    ) else {
+        return Ok(None);
+    };
+
+    let Some(requested) = setup.requested_margins(&bounds, &depths) else {
+        return Err(FitError::NoViewport);
+    };
+    let targets = calculate_target_margins(&bounds, &requested);
+
+    // Find constraining dimension (least slack over its target margins).
+    // When a dimension has degenerate (near-zero) screen extent, force the
+    // other dimension to constrain — the degenerate dimension has no
+    // meaningful projection to fit against.
+    let h_slack = (bounds.left_margin - targets.left).min(bounds.right_margin - targets.right);
+    let v_slack = (bounds.top_margin - targets.top).min(bounds.bottom_margin - targets.bottom);
+    let vertical_extent = bounds.max_norm_y - bounds.min_norm_y;
+    let horizontal_extent = bounds.max_norm_x - bounds.min_norm_x;
+
+    let (slack, dimension) = if vertical_extent < DEGENERATE_EXTENT_THRESHOLD {
+        (h_slack, "H")
+    } else if horizontal_extent < DEGENERATE_EXTENT_THRESHOLD {
+        (v_slack, "V")
+    } else if h_slack < v_slack {
+        (h_slack, "H")
+    } else {
+        (v_slack, "V")
+    };
+
+    Ok(Some(MarginCheck {
+        slack,
+        dimension,
+        bounds,
+    }))
 }
 
-/// Shifts the camera focus so the projected bounding box is centered on screen.
+/// Shifts the camera focus so the projected bounding box is centered in the area left inside
+/// the margins within the frame (the screen center when the frame is the whole viewport and the
+/// margins are symmetric), or on the anchor when the fit has one.
 ///
 /// For perspective, each correction step uses the harmonic mean of the depths of the two
 /// extreme points per dimension. This is the exact inverse of perspective projection: when
@@ -336,33 +865,34 @@ fn build_test_projection(projection: &Projection, test_radius: f32) -> Projectio
 ///
 /// For orthographic, centering is depth-independent (centering_depth = 1.0), so the shift
 /// is a direct 1:1 world-unit correction.
-#[allow(clippy::too_many_arguments)]
-fn refine_focus_centering(
-    points: &[Vec3],
-    initial_focus: Vec3,
-    radius: f32,
-    rot: Quat,
-    projection: &Projection,
-    aspect_ratio: f32,
-    ortho_fixed_distance: Option<f32>,
-    is_ortho: bool,
-) -> Vec3 {
+fn refine_focus_centering(setup: &FitSetup, radius: f32) -> Vec3 {
+    let rot = setup.rot;
     let cam_right = rot * Vec3::X;
     let cam_up = rot * Vec3::Y;
+    let is_ortho = setup.is_ortho();
 
-    let cam_distance = ortho_fixed_distance.unwrap_or(radius);
+    let cam_distance = setup.projection.camera_distance(radius);
+    let half_extents = setup.half_extents_at(radius);
 
-    let mut focus = initial_focus;
+    let mut focus = setup.geometric_center;
     for _ in 0..CENTERING_MAX_ITERATIONS {
         let cam_pos = focus + rot * Vec3::new(0.0, 0.0, cam_distance);
         let cam_global =
             GlobalTransform::from(Transform::from_translation(cam_pos).with_rotation(rot));
-        let Some((bounds, depths)) =
-            ScreenSpaceBounds::from_points(points, &cam_global, projection, aspect_ratio)
-        else {
+        let Some((bounds, depths)) = ScreenSpaceBounds::from_points(
+            setup.points,
+            &cam_global,
+            setup.projection,
+            half_extents,
+        ) else {
+            break;
+        };
+        let Some(requested) = setup.requested_margins(&bounds, &depths) else {
             break;
         };
-        let (cx, cy) = bounds.center();
+        let (target_x, target_y) = requested.center();
+        let (center_x, center_y) = bounds.center();
+        let (cx, cy) = (center_x - target_x, center_y - target_y);
         if cx.abs() < CENTERING_TOLERANCE && cy.abs() < CENTERING_TOLERANCE {
             break;
         }
@@ -373,10 +903,8 @@ fn refine_focus_centering(
             (1.0, 1.0)
         } else {
             (
-                2.0 * depths.min_x_depth * depths.max_x_depth
-                    / (depths.min_x_depth + depths.max_x_depth),
-                2.0 * depths.min_y_depth * depths.max_y_depth
-                    / (depths.min_y_depth + depths.max_y_depth),
+                harmonic_mean(depths.min_x_depth, depths.max_x_depth),
+                harmonic_mean(depths.min_y_depth, depths.max_y_depth),
             )
         };
 
@@ -385,44 +913,289 @@ fn refine_focus_centering(
     focus
 }
 
+fn harmonic_mean(a: f32, b: f32) -> f32 { 2.0 * a * b / (a + b) }
+
+// ============================================================================
+// Analytic solver
+// ============================================================================
+
+/// The interval one screen axis leaves for the silhouette, for the analytic solver.
+///
+/// A point at normalized coordinate `n` fits when `low + low_world <= n <= high - high_world`.
+/// Fraction margins are folded into the lines and scale with the view; world margins only
+/// apply to orthographic fits, whose normalized units are world units.
+struct AxisLines {
+    low:        f32,
+    high:       f32,
+    low_world:  f32,
+    high_world: f32,
+}
+
+impl AxisLines {
+    /// Builds the lines for an axis with the given half extent, the frame's span along the axis
+    /// (as viewport fractions from the low side), and the margins at its low and high ends.
+    fn new(half_extent: f32, frame: (f32, f32), margins: (f32, f32), unit: MarginUnit) -> Self {
+        let extent = 2.0 * half_extent;
+        let span = extent * (frame.1 - frame.0);
+        let (fraction, world) = match unit {
+            MarginUnit::World => ((0.0, 0.0), margins),
+            MarginUnit::Fraction | MarginUnit::LogicalPixels => (margins, (0.0, 0.0)),
+        };
+        Self {
+            low:        -half_extent + extent * frame.0 + span * fraction.0,
+            high:       -half_extent + extent * frame.1 - span * fraction.1,
+            low_world:  world.0,
+            high_world: world.1,
+        }
+    }
+
+    /// Narrows fraction-margin lines to the widest interval centered on the normalized
+    /// coordinate `at`, as [`EdgeMargins::anchored`] does.
+    fn anchored(&self, at: f32) -> Self {
+        let at = at.clamp(self.low, self.high.max(self.low));
+        let half = (at - self.low).min(self.high - at);
+        Self {
+            low: at - half,
+            high: at + half,
+            ..*self
+        }
+    }
+
+    /// Scales the lines to an orthographic radius; world margins are unaffected.
+    fn scaled(&self, radius: f32) -> Self {
+        Self {
+            low: self.low * radius,
+            high: self.high * radius,
+            ..*self
+        }
+    }
+
+    /// The normalized coordinate the silhouette is centered on (matching
+    /// [`EdgeMargins::center`]).
+    fn center(&self) -> f32 { (self.low + self.low_world + self.high - self.high_world) * 0.5 }
+}
+
+/// Solves the fit directly from the points' camera-space coordinates in a handful of passes
+/// over them, instead of re-projecting every point for each binary search candidate.
+///
+/// With the orientation fixed, a point `(x, z)` relative to a camera at lateral offset `a` and
+/// depth offset `t` projects to `(x - a) / (z - t)` in perspective, so each edge constraint is
+/// linear in `a` and `t`. The tightest `t` per axis is where the bounds of both edges meet;
+/// the farther of the two axes constrains. Orthographic projection is linear in the radius
+/// directly. The focus is then centered per axis as in [`refine_focus_centering`], which
+/// converges in one or two steps.
+///
+/// Returns `None` when there is no valid solution, such as points ending up behind the camera
+/// or a zero orthographic scale, so the caller can fall back to the binary search. Perspective
+/// fits with world margins also return `None`: the binary search measures those at the depth of
+/// whichever point is outermost on screen, which has no linear form. So do projections that
+/// don't [`support the closed form`](FitProjection::supports_analytic_fit), and anchored fits
+/// with world margins, whose narrowed interval depends on the silhouette's size.
+fn solve_analytic(setup: &FitSetup) -> Option<FitSolution> {
+    let is_ortho = setup.is_ortho();
+    if !setup.projection.supports_analytic_fit()
+        || (!is_ortho && setup.margins.unit == MarginUnit::World)
+    {
+        return None;
+    }
+
+    // Orthographic extents scale with the radius, so solve in extents per unit of radius.
+    let half_extents = setup.half_extents_at(1.0);
+
+    let right = setup.rot * Vec3::X;
+    let up = setup.rot * Vec3::Y;
+    let forward = setup.rot * Vec3::NEG_Z;
+    let local: Vec<Vec3> = setup
+        .points
+        .iter()
+        .map(|point| {
+            let offset = *point - setup.geometric_center;
+            Vec3::new(offset.dot(right), offset.dot(up), offset.dot(forward))
+        })
+        .collect();
+    if local.is_empty() {
+        return None;
+    }
+
+    let margins = &setup.margins;
+    let frame = setup.frame;
+    let x_lines = AxisLines::new(
+        half_extents.x,
+        (frame.min.x, frame.max.x),
+        (margins.left, margins.right),
+        margins.unit,
+    );
+    let y_lines = AxisLines::new(
+        half_extents.y,
+        (1.0 - frame.max.y, 1.0 - frame.min.y),
+        (margins.bottom, margins.top),
+        margins.unit,
+    );
+    let (x_lines, y_lines) = match setup.anchor.to_screen(frame, half_extents) {
+        None => (x_lines, y_lines),
+        Some(_) if margins.unit == MarginUnit::World => return None,
+        Some(anchor) => (x_lines.anchored(anchor.x), y_lines.anchored(anchor.y)),
+    };
+    if x_lines.high <= x_lines.low || y_lines.high <= y_lines.low {
+        return None;
+    }
+
+    let (radius, offset) = if is_ortho {
+        solve_orthographic(&local, &x_lines, &y_lines)
+    } else {
+        solve_perspective(&local, &x_lines, &y_lines)?
+    };
+
+    (radius.is_finite() && radius > 0.0 && offset.is_finite()).then(|| {
+        setup.solution(
+            radius,
+            setup.geometric_center + right * offset.x + up * offset.y,
+        )
+    })
+}
+
+/// Solves a perspective fit, returning the radius and the focus offset along the camera's
+/// right and up axes.
+fn solve_perspective(
+    local: &[Vec3],
+    x_lines: &AxisLines,
+    y_lines: &AxisLines,
+) -> Option<(f32, Vec2)> {
+    // Largest depth offset that still fits an axis: the camera offset `a` must satisfy
+    // `a <= min(c - low * z) + low * t` and `a >= max(c - high * z) + high * t`.
+    let tightest_depth_offset = |lines: &AxisLines, coordinate: fn(&Vec3) -> f32| {
+        let (lower, upper) = local.iter().fold(
+            (f32::INFINITY, f32::NEG_INFINITY),
+            |(lower, upper), point| {
+                (
+                    lower.min(coordinate(point) - lines.low * point.z),
+                    upper.max(coordinate(point) - lines.high * point.z),
+                )
+            },
+        );
+        (lower - upper) / (lines.high - lines.low)
+    };
+    let depth_offset =
+        tightest_depth_offset(x_lines, |p| p.x).min(tightest_depth_offset(y_lines, |p| p.y));
+
+    if local
+        .iter()
+        .any(|point| point.z - depth_offset <= MIN_VISIBLE_DEPTH)
+    {
+        return None;
+    }
+
+    let offset = Vec2::new(
+        center_perspective_axis(local, x_lines, depth_offset, |p| p.x),
+        center_perspective_axis(local, y_lines, depth_offset, |p| p.y),
+    );
+    Some((-depth_offset, offset))
+}
+
+/// Finds the camera offset along one axis that centers the silhouette within `lines`, using
+/// the harmonic-mean correction from [`refine_focus_centering`].
+fn center_perspective_axis(
+    local: &[Vec3],
+    lines: &AxisLines,
+    depth_offset: f32,
+    coordinate: fn(&Vec3) -> f32,
+) -> f32 {
+    let mut offset = 0.0;
+    for _ in 0..CENTERING_MAX_ITERATIONS {
+        let (mut min, mut min_depth) = (f32::INFINITY, 1.0);
+        let (mut max, mut max_depth) = (f32::NEG_INFINITY, 1.0);
+        for point in local {
+            let depth = point.z - depth_offset;
+            let normalized = (coordinate(point) - offset) / depth;
+            if normalized < min {
+                (min, min_depth) = (normalized, depth);
+            }
+            if normalized > max {
+                (max, max_depth) = (normalized, depth);
+            }
+        }
+        let error = (min + max) * 0.5 - lines.center();
+        if error.abs() < CENTERING_TOLERANCE {
+            break;
+        }
+        offset += error * harmonic_mean(min_depth, max_depth);
+    }
+    offset
+}
+
+/// Solves an orthographic fit, returning the radius (scale) and the focus offset along the
+/// camera's right and up axes. Both are exact since projection does not depend on depth.
+fn solve_orthographic(local: &[Vec3], x_lines: &AxisLines, y_lines: &AxisLines) -> (f32, Vec2) {
+    let (min, max) = local
+        .iter()
+        .fold((Vec2::INFINITY, Vec2::NEG_INFINITY), |(min, max), point| {
+            (min.min(point.xy()), max.max(point.xy()))
+        });
+    let radius_for = |lines: &AxisLines, min: f32, max: f32| {
+        (max - min + lines.low_world + lines.high_world) / (lines.high - lines.low)
+    };
+    let radius = radius_for(x_lines, min.x, max.x).max(radius_for(y_lines, min.y, max.y));
+
+    let center =
+        |lines: &AxisLines, min: f32, max: f32| (min + max) * 0.5 - lines.scaled(radius).center();
+    let offset = Vec2::new(center(x_lines, min.x, max.x), center(y_lines, min.y, max.y));
+    (radius, offset)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
-    fn default_perspective() -> Projection {
-        Projection::Perspective(PerspectiveProjection::default())
+    fn default_perspective() -> PerspectiveProjection { PerspectiveProjection::default() }
+
+    fn unit_aspect_half_extents(projection: &PerspectiveProjection) -> Vec2 {
+        projection
+            .half_extents(Some(Vec2::ONE))
+            .expect("perspective projections always have half extents")
     }
 
     #[test]
     fn calculate_fit_returns_no_viewport_for_invalid_ortho_area() {
-        let projection = Projection::Orthographic(OrthographicProjection {
+        let projection = OrthographicProjection {
             area: Rect::new(-1.0, 0.0, 1.0, 0.0),
             ..OrthographicProjection::default_3d()
-        });
+        };
         let camera = Camera::default();
 
         let result = calculate_fit(
             &[Vec3::new(0.0, 0.0, 0.0), Vec3::new(1.0, 0.0, 0.0)],
             Vec3::ZERO,
-            0.0,
-            0.0,
-            0.1,
+            &FitParams {
+                margins: FitMargins::uniform(0.1),
+                ..default()
+            },
             &projection,
             &camera,
+            &OrbitLimits::default(),
         );
 
         assert!(matches!(result, Err(FitError::NoViewport)));
     }
 
     #[test]
-    fn calculate_fit_returns_points_behind_camera_for_degenerate_point_cloud() {
+    fn calculate_fit_returns_zero_extent_for_degenerate_point_cloud() {
         let projection = default_perspective();
         let camera = Camera::default();
         let points = [Vec3::ZERO, Vec3::ZERO, Vec3::ZERO];
 
-        let result = calculate_fit(&points, Vec3::ZERO, 0.0, 0.0, 0.1, &projection, &camera);
+        let result = calculate_fit(
+            &points,
+            Vec3::ZERO,
+            &FitParams {
+                margins: FitMargins::uniform(0.1),
+                ..default()
+            },
+            &projection,
+            &camera,
+            &OrbitLimits::default(),
+        );
 
-        assert!(matches!(result, Err(FitError::PointsBehindCamera)));
+        assert!(matches!(result, Err(FitError::ZeroExtent)));
     }
 
     #[test]
@@ -436,7 +1209,17 @@ mod tests {
             Vec3::new(1.0, 1.0, 0.0),
         ];
 
-        let result = calculate_fit(&points, Vec3::ZERO, 0.0, 0.0, 5.0, &projection, &camera);
+        let result = calculate_fit(
+            &points,
+            Vec3::ZERO,
+            &FitParams {
+                margins: FitMargins::uniform(5.0),
+                ..default()
+            },
+            &projection,
+            &camera,
+            &OrbitLimits::default(),
+        );
 
         let fit = result.expect("fit should succeed with clamped margin");
         assert!(fit.radius.is_finite());
@@ -457,11 +1240,13 @@ mod tests {
         let result = calculate_fit(
             &points,
             Vec3::ZERO,
-            0.0,
-            0.0,
-            f32::NAN,
+            &FitParams {
+                margins: FitMargins::uniform(f32::NAN),
+                ..default()
+            },
             &projection,
             &camera,
+            &OrbitLimits::default(),
         );
 
         assert!(result.is_ok());
@@ -482,8 +1267,18 @@ mod tests {
         ];
         let object_radius = points.iter().map(|p| p.length()).fold(0.0_f32, f32::max);
 
-        let fit = calculate_fit(&points, Vec3::ZERO, 0.0, 0.0, 0.1, &projection, &camera)
-            .expect("edge-on flat plane should produce a valid fit");
+        let fit = calculate_fit(
+            &points,
+            Vec3::ZERO,
+            &FitParams {
+                margins: FitMargins::uniform(0.1),
+                ..default()
+            },
+            &projection,
+            &camera,
+            &OrbitLimits::default(),
+        )
+        .expect("edge-on flat plane should produce a valid fit");
 
         assert!(
             fit.radius < object_radius * 10.0,
@@ -507,8 +1302,19 @@ mod tests {
         ];
         let object_radius = points.iter().map(|p| p.length()).fold(0.0_f32, f32::max);
 
-        let fit = calculate_fit(&points, Vec3::ZERO, 0.0, 0.001, 0.1, &projection, &camera)
-            .expect("near-edge-on flat plane should produce a valid fit");
+        let fit = calculate_fit(
+            &points,
+            Vec3::ZERO,
+            &FitParams {
+                pitch: 0.001,
+                margins: FitMargins::uniform(0.1),
+                ..default()
+            },
+            &projection,
+            &camera,
+            &OrbitLimits::default(),
+        )
+        .expect("near-edge-on flat plane should produce a valid fit");
 
         assert!(
             fit.radius < object_radius * 10.0,
@@ -527,8 +1333,18 @@ mod tests {
         let points = [Vec3::new(0.0, -1.0, 0.0), Vec3::new(0.0, 1.0, 0.0)];
         let object_radius = 1.0;
 
-        let fit = calculate_fit(&points, Vec3::ZERO, 0.0, 0.0, 0.1, &projection, &camera)
-            .expect("vertical line should produce a valid fit");
+        let fit = calculate_fit(
+            &points,
+            Vec3::ZERO,
+            &FitParams {
+                margins: FitMargins::uniform(0.1),
+                ..default()
+            },
+            &projection,
+            &camera,
+            &OrbitLimits::default(),
+        )
+        .expect("vertical line should produce a valid fit");
 
         assert!(
             fit.radius < object_radius * 10.0,
@@ -537,4 +1353,639 @@ mod tests {
             object_radius,
         );
     }
+
+    /// Wide quad with a large left margin: width constrains, so the left and right margins
+    /// land exactly on their requested targets and the silhouette shifts right of center.
+    #[test]
+    fn asymmetric_margins_offset_the_framed_silhouette() {
+        let projection = default_perspective();
+        let camera = Camera::default();
+        let points = [
+            Vec3::new(-2.0, -0.5, 0.0),
+            Vec3::new(2.0, -0.5, 0.0),
+            Vec3::new(-2.0, 0.5, 0.0),
+            Vec3::new(2.0, 0.5, 0.0),
+        ];
+        let margins = FitMargins::new(0.3, 0.05, 0.05, 0.05);
+
+        let fit = calculate_fit(
+            &points,
+            Vec3::ZERO,
+            &FitParams {
+                margins,
+                ..default()
+            },
+            &projection,
+            &camera,
+            &OrbitLimits::default(),
+        )
+        .expect("asymmetric margins should produce a valid fit");
+
+        let rot = Quat::from_euler(EulerRot::YXZ, 0.0, 0.0, 0.0);
+        let cam_pos = fit.focus + rot * Vec3::new(0.0, 0.0, fit.radius);
+        let cam_global =
+            GlobalTransform::from(Transform::from_translation(cam_pos).with_rotation(rot));
+        let (bounds, depths) = ScreenSpaceBounds::from_points(
+            &points,
+            &cam_global,
+            &projection,
+            unit_aspect_half_extents(&projection),
+        )
+        .expect("points should be in front of the camera");
+        let requested = EdgeMargins::from_fit_margins(
+            &margins,
+            Rect::new(0.0, 0.0, 1.0, 1.0),
+            &bounds,
+            &depths,
+            false,
+            None,
+        )
+        .expect("fraction margins need no viewport");
+
+        assert!((bounds.left_margin - requested.left).abs() < 0.01);
+        assert!((bounds.right_margin - requested.right).abs() < 0.01);
+        assert!((bounds.top_margin - bounds.bottom_margin).abs() < 0.01);
+        assert!(
+            fit.focus.x < 0.0,
+            "focus {:?} should shift left so the silhouette moves right",
+            fit.focus
+        );
+    }
+
+    #[test]
+    fn calculate_fit_returns_no_viewport_for_pixel_margins_without_viewport() {
+        let projection = default_perspective();
+        let camera = Camera::default();
+        let points = [Vec3::new(-1.0, -1.0, 0.0), Vec3::new(1.0, 1.0, 0.0)];
+
+        let result = calculate_fit(
+            &points,
+            Vec3::ZERO,
+            &FitParams {
+                margins: FitMargins::logical_pixels(24.0),
+                ..default()
+            },
+            &projection,
+            &camera,
+            &OrbitLimits::default(),
+        );
+
+        assert!(matches!(result, Err(FitError::NoViewport)));
+    }
+
+    /// A flat quad facing the camera has every point at the same depth, so one world unit of
+    /// clearance frames it exactly like a quad one unit larger on every side with no margin.
+    #[test]
+    fn world_margins_match_fitting_an_expanded_target() {
+        let projection = default_perspective();
+        let camera = Camera::default();
+        let quad = |half: f32| {
+            [
+                Vec3::new(-half, -half, 0.0),
+                Vec3::new(half, -half, 0.0),
+                Vec3::new(-half, half, 0.0),
+                Vec3::new(half, half, 0.0),
+            ]
+        };
+
+        let padded = calculate_fit(
+            &quad(1.0),
+            Vec3::ZERO,
+            &FitParams {
+                margins: FitMargins::world(1.0),
+                ..default()
+            },
+            &projection,
+            &camera,
+            &OrbitLimits::default(),
+        )
+        .expect("world margins should produce a valid fit");
+        let expanded = calculate_fit(
+            &quad(2.0),
+            Vec3::ZERO,
+            &FitParams {
+                margins: FitMargins::uniform(0.0),
+                ..default()
+            },
+            &projection,
+            &camera,
+            &OrbitLimits::default(),
+        )
+        .expect("zero margins should produce a valid fit");
+
+        assert!(
+            (padded.radius - expanded.radius).abs() < expanded.radius * 0.01,
+            "padded radius {:.3} should match expanded radius {:.3}",
+            padded.radius,
+            expanded.radius,
+        );
+    }
+
+    /// Framing into the right half of the viewport puts the silhouette's center in the middle
+    /// of that half rather than the screen center.
+    #[test]
+    fn fit_frame_centers_silhouette_in_sub_rectangle() {
+        let projection = default_perspective();
+        let camera = Camera::default();
+        let points = [
+            Vec3::new(-1.0, -1.0, 0.0),
+            Vec3::new(1.0, -1.0, 0.0),
+            Vec3::new(-1.0, 1.0, 0.0),
+            Vec3::new(1.0, 1.0, 0.0),
+        ];
+        let frame = Rect::new(0.5, 0.0, 1.0, 1.0);
+
+        let fit = calculate_fit(
+            &points,
+            Vec3::ZERO,
+            &FitParams {
+                margins: FitMargins::uniform(0.0),
+                frame: FitFrame::Normalized(frame),
+                ..default()
+            },
+            &projection,
+            &camera,
+            &OrbitLimits::default(),
+        )
+        .expect("sub-rectangle frame should produce a valid fit");
+
+        let rot = Quat::from_euler(EulerRot::YXZ, 0.0, 0.0, 0.0);
+        let cam_pos = fit.focus + rot * Vec3::new(0.0, 0.0, fit.radius);
+        let cam_global =
+            GlobalTransform::from(Transform::from_translation(cam_pos).with_rotation(rot));
+        let (bounds, _) = ScreenSpaceBounds::from_points(
+            &points,
+            &cam_global,
+            &projection,
+            unit_aspect_half_extents(&projection),
+        )
+        .expect("points should be in front of the camera");
+        let (center_x, center_y) = bounds.center();
+
+        // The right half spans normalized x in [0, half_extent_x].
+        assert!((center_x - bounds.half_extent_x * 0.5).abs() < 0.01);
+        assert!(center_y.abs() < 0.01);
+        assert!(
+            bounds.min_norm_x >= -0.01,
+            "silhouette should stay in the right half"
+        );
+    }
+
+    #[test]
+    fn anchor_places_silhouette_at_thirds_point_within_margins() {
+        let projection = default_perspective();
+        let points = [
+            Vec3::new(-1.0, -1.0, 0.0),
+            Vec3::new(1.0, -1.0, 0.0),
+            Vec3::new(-1.0, 1.0, 0.0),
+            Vec3::new(1.0, 1.0, 0.0),
+        ];
+
+        let fit = calculate_fit(
+            &points,
+            Vec3::ZERO,
+            &FitParams {
+                margins: FitMargins::uniform(0.2),
+                anchor: FitAnchor::THIRDS_TOP_LEFT,
+                ..default()
+            },
+            &projection,
+            &Camera::default(),
+            &OrbitLimits::default(),
+        )
+        .expect("anchored fit should produce a valid solution");
+
+        let cam_global = GlobalTransform::from(Transform::from_translation(
+            fit.focus + Vec3::new(0.0, 0.0, fit.radius),
+        ));
+        let half_extents = unit_aspect_half_extents(&projection);
+        let (bounds, _) =
+            ScreenSpaceBounds::from_points(&points, &cam_global, &projection, half_extents)
+                .expect("points should be in front of the camera");
+        let (center_x, center_y) = bounds.center();
+
+        // The top-left thirds point is a third of the way left and up from the screen center.
+        assert!((center_x + half_extents.x / 3.0).abs() < 0.01);
+        assert!((center_y - half_extents.y / 3.0).abs() < 0.01);
+        // The edges nearest the anchor meet their 10% margins; the far edges get more room.
+        let margin = 0.2 * half_extents.x;
+        assert!((bounds.left_margin - margin).abs() < 0.01);
+        assert!((bounds.top_margin - margin).abs() < 0.01);
+        assert!(bounds.right_margin > bounds.left_margin + margin);
+        assert!(bounds.bottom_margin > bounds.top_margin + margin);
+    }
+
+    #[test]
+    fn aspect_ratio_frame_is_centered_and_letterboxed() {
+        let frame = FitFrame::AspectRatio(2.0)
+            .to_normalized(Some(Vec2::new(1000.0, 1000.0)))
+            .expect("viewport size is available");
+
+        assert_eq!(frame, Rect::new(0.0, 0.25, 1.0, 0.75));
+    }
+
+    /// An irregular, deterministic point cloud with depth variation on every axis.
+    fn irregular_points() -> Vec<Vec3> {
+        (0..64)
+            .map(|i| {
+                let i = i as f32;
+                Vec3::new(
+                    (i * 1.3).sin() * 2.0 + 0.4,
+                    (i * 0.7).cos() * 1.2 - 0.3,
+                    (i * 2.1).sin() * 0.8,
+                )
+            })
+            .collect()
+    }
+
+    fn fit_with(
+        algorithm: FitAlgorithm,
+        yaw: f32,
+        pitch: f32,
+        margins: &FitMargins,
+        frame: &FitFrame,
+        anchor: FitAnchor,
+        projection: &dyn FitProjection,
+    ) -> FitSolution {
+        let points = irregular_points();
+        calculate_fit(
+            &points,
+            Vec3::new(0.1, 0.2, -0.1),
+            &FitParams {
+                yaw,
+                pitch,
+                margins: *margins,
+                frame: *frame,
+                anchor,
+                algorithm,
+                ..default()
+            },
+            projection,
+            &Camera::default(),
+            &OrbitLimits::default(),
+        )
+        .expect("irregular point cloud should produce a valid fit")
+    }
+
+    #[test]
+    fn analytic_solver_matches_binary_search() {
+        let perspective = default_perspective();
+        let orthographic = OrthographicProjection {
+            area: Rect::new(-1.5, -1.0, 1.5, 1.0),
+            ..OrthographicProjection::default_3d()
+        };
+        let projections: [&dyn FitProjection; 2] = [&perspective, &orthographic];
+        let sub_frame = FitFrame::Normalized(Rect::new(0.3, 0.1, 0.9, 0.8));
+        let center = FitAnchor::Center;
+        let cases = [
+            (
+                0.0,
+                0.0,
+                FitMargins::uniform(0.1),
+                FitFrame::Viewport,
+                center,
+            ),
+            (
+                0.7,
+                0.4,
+                FitMargins::uniform(0.1),
+                FitFrame::Viewport,
+                center,
+            ),
+            (
+                -1.2,
+                -0.6,
+                FitMargins::new(0.3, 0.05, 0.1, 0.2),
+                FitFrame::Viewport,
+                center,
+            ),
+            (2.5, 0.9, FitMargins::uniform(0.2), sub_frame, center),
+            (0.3, 0.2, FitMargins::world(0.5), FitFrame::Viewport, center),
+            (
+                0.7,
+                0.4,
+                FitMargins::uniform(0.1),
+                FitFrame::Viewport,
+                FitAnchor::THIRDS_TOP_RIGHT,
+            ),
+            (
+                -0.5,
+                0.3,
+                FitMargins::new(0.1, 0.2, 0.05, 0.1),
+                sub_frame,
+                FitAnchor::Normalized(Vec2::new(0.25, 0.6)),
+            ),
+        ];
+
+        for projection in projections {
+            for (yaw, pitch, margins, frame, anchor) in &cases {
+                // Perspective fits with world margins have no analytic form and would only
+                // compare the binary search with itself.
+                if !projection.is_orthographic() && margins.unit == MarginUnit::World {
+                    continue;
+                }
+                let expected = fit_with(
+                    FitAlgorithm::BinarySearch,
+                    *yaw,
+                    *pitch,
+                    margins,
+                    frame,
+                    *anchor,
+                    projection,
+                );
+                let analytic = fit_with(
+                    FitAlgorithm::Analytic,
+                    *yaw,
+                    *pitch,
+                    margins,
+                    frame,
+                    *anchor,
+                    projection,
+                );
+
+                assert!(
+                    (analytic.radius - expected.radius).abs() < TOLERANCE,
+                    "radius {:.5} should match binary search {:.5} (yaw {yaw}, pitch {pitch}, {margins:?}, {frame:?}, {anchor:?})",
+                    analytic.radius,
+                    expected.radius,
+                );
+                assert!(
+                    analytic.focus.distance(expected.focus) < TOLERANCE,
+                    "focus {:?} should match binary search {:?} (yaw {yaw}, pitch {pitch}, {margins:?}, {frame:?}, {anchor:?})",
+                    analytic.focus,
+                    expected.focus,
+                );
+            }
+        }
+    }
+
+    /// Perspective frustum whose visible window is shifted off-axis, as on a CAVE wall.
+    struct OffAxisProjection {
+        half_tan_fov:  f32,
+        window_center: Vec2,
+    }
+
+    impl FitProjection for OffAxisProjection {
+        fn project_normalized(&self, view_point: Vec3) -> Option<Vec2> {
+            (view_point.z > MIN_VISIBLE_DEPTH)
+                .then(|| view_point.xy() / view_point.z - self.window_center)
+        }
+
+        fn unproject_normalized(&self, normalized: Vec2, depth: f32) -> Vec3 {
+            ((normalized + self.window_center) * depth).extend(depth)
+        }
+
+        fn half_extents(&self, _viewport_size: Option<Vec2>) -> Option<Vec2> {
+            Some(Vec2::splat(self.half_tan_fov))
+        }
+    }
+
+    #[test]
+    fn custom_projection_centers_silhouette_in_its_off_axis_window() {
+        let projection = OffAxisProjection {
+            half_tan_fov:  0.5,
+            window_center: Vec2::new(0.3, -0.2),
+        };
+        let margins = FitMargins::uniform(0.1);
+        let points = irregular_points();
+
+        // Off-axis windows have no analytic solve, so `FitAlgorithm::Analytic` would fall back to
+        // the binary search tested here.
+        let fit = fit_with(
+            FitAlgorithm::BinarySearch,
+            0.4,
+            0.3,
+            &margins,
+            &FitFrame::Viewport,
+            FitAnchor::Center,
+            &projection,
+        );
+
+        let rot = Quat::from_euler(EulerRot::YXZ, 0.4, -0.3, 0.0);
+        let cam_global = GlobalTransform::from(
+            Transform::from_translation(fit.focus + rot * Vec3::new(0.0, 0.0, fit.radius))
+                .with_rotation(rot),
+        );
+        let (bounds, _) = ScreenSpaceBounds::from_points(
+            &points,
+            &cam_global,
+            &projection,
+            Vec2::splat(projection.half_tan_fov),
+        )
+        .expect("fitted points should be in front of the camera");
+
+        let (center_x, center_y) = bounds.center();
+        assert!(
+            center_x.abs() < 0.01 && center_y.abs() < 0.01,
+            "silhouette center ({center_x:.4}, {center_y:.4}) should be the window center"
+        );
+        let margin = bounds.left_margin.min(bounds.top_margin) / (2.0 * projection.half_tan_fov);
+        assert!(
+            (margin - 0.05).abs() < 0.005,
+            "constraining margin {margin:.4} should be half the requested 0.1 total"
+        );
+    }
+
+    #[test]
+    fn camera_limits_clamp_orientation_and_radius_and_keep_focus_centered() {
+        let points = irregular_points();
+        let projection = default_perspective();
+        let margins = FitMargins::uniform(0.1);
+        let fit = |limits: &OrbitLimits| {
+            calculate_fit(
+                &points,
+                Vec3::new(0.1, 0.2, -0.1),
+                &FitParams {
+                    yaw: 0.4,
+                    pitch: 0.9,
+                    margins,
+                    ..default()
+                },
+                &projection,
+                &Camera::default(),
+                limits,
+            )
+            .expect("irregular point cloud should produce a valid fit")
+        };
+
+        let unlimited = fit(&OrbitLimits {
+            pitch_upper: Some(0.6),
+            ..default()
+        });
+        assert_eq!(unlimited.pitch, 0.6);
+        assert_eq!(
+            unlimited.limits,
+            FitLimits {
+                pitch: true,
+                ..default()
+            }
+        );
+
+        let limits = OrbitLimits {
+            pitch_upper: Some(0.6),
+            zoom_upper: Some(unlimited.radius * 0.5),
+            ..default()
+        };
+        let limited = fit(&limits);
+        assert_eq!(limited.radius, unlimited.radius * 0.5);
+        assert!(limited.limits.pitch && limited.limits.zoom && !limited.limits.yaw);
+
+        let rot = Quat::from_euler(EulerRot::YXZ, limited.yaw, -limited.pitch, 0.0);
+        let cam_global = GlobalTransform::from(
+            Transform::from_translation(limited.focus + rot * Vec3::new(0.0, 0.0, limited.radius))
+                .with_rotation(rot),
+        );
+        let (bounds, _) = ScreenSpaceBounds::from_points(
+            &points,
+            &cam_global,
+            &projection,
+            unit_aspect_half_extents(&projection),
+        )
+        .expect("points should be in front of the camera");
+        let (center_x, center_y) = bounds.center();
+        assert!(
+            center_x.abs() < 0.01 && center_y.abs() < 0.01,
+            "clamped fit should stay centered, got ({center_x:.4}, {center_y:.4})"
+        );
+    }
+
+    #[test]
+    fn fov_fit_at_fixed_radius_matches_radius_fit_with_that_fov() {
+        let points = irregular_points();
+        let center = Vec3::new(0.1, 0.2, -0.1);
+        let margins = FitMargins::uniform(0.1);
+        let radius = 12.0;
+
+        let fov_fit = calculate_fov_fit(
+            &points,
+            center,
+            radius,
+            &FitParams {
+                yaw: 0.4,
+                pitch: 0.3,
+                margins,
+                ..default()
+            },
+            &default_perspective(),
+            &Camera::default(),
+            &OrbitLimits::default(),
+        )
+        .expect("irregular point cloud should produce a valid fov fit");
+        let fov = fov_fit.fov.expect("fov fit should carry a field of view");
+        assert_eq!(fov_fit.radius, radius);
+        assert!(
+            fov < default_perspective().fov,
+            "a distant camera should narrow the lens, got {fov}"
+        );
+
+        let lens = PerspectiveProjection {
+            fov,
+            ..default_perspective()
+        };
+        let radius_fit = calculate_fit(
+            &points,
+            center,
+            &FitParams {
+                yaw: 0.4,
+                pitch: 0.3,
+                margins,
+                ..default()
+            },
+            &lens,
+            &Camera::default(),
+            &OrbitLimits::default(),
+        )
+        .expect("irregular point cloud should produce a valid fit");
+        assert!(
+            (radius_fit.radius - radius).abs() < radius * 0.001,
+            "radius fit with the solved fov should land at {radius}, got {}",
+            radius_fit.radius
+        );
+        assert!(
+            radius_fit.focus.distance(fov_fit.focus) < 0.01,
+            "both fits should center the same focus"
+        );
+    }
+
+    /// Depth of the point nearest a camera at `radius` from `focus`, looking down `-Z`.
+    fn nearest_depth(points: &[Vec3], focus: Vec3, radius: f32) -> f32 {
+        points
+            .iter()
+            .map(|point| focus.z + radius - point.z)
+            .fold(f32::INFINITY, f32::min)
+    }
+
+    fn cube_corners(half_size: f32) -> Vec<Vec3> {
+        (0..8)
+            .map(|i| {
+                Vec3::new(
+                    if i & 1 == 0 { -half_size } else { half_size },
+                    if i & 2 == 0 { -half_size } else { half_size },
+                    if i & 4 == 0 { -half_size } else { half_size },
+                )
+            })
+            .collect()
+    }
+
+    #[test]
+    fn fit_backs_out_past_a_near_plane_that_would_clip_the_target() {
+        let points = cube_corners(0.5);
+        let projection = PerspectiveProjection {
+            near: 5.0,
+            ..default_perspective()
+        };
+
+        for algorithm in [FitAlgorithm::BinarySearch, FitAlgorithm::Analytic] {
+            let fit = calculate_fit(
+                &points,
+                Vec3::ZERO,
+                &FitParams {
+                    margins: FitMargins::uniform(0.1),
+                    algorithm,
+                    ..default()
+                },
+                &projection,
+                &Camera::default(),
+                &OrbitLimits::default(),
+            )
+            .expect("cube should produce a valid fit");
+
+            assert!(
+                fit.limits.near_plane,
+                "{algorithm:?} should report the near plane"
+            );
+            let depth = nearest_depth(&points, fit.focus, fit.radius);
+            assert!(
+                depth >= projection.near,
+                "{algorithm:?}: nearest point at depth {depth:.3} is clipped by the near plane"
+            );
+        }
+    }
+
+    #[test]
+    fn fov_fit_from_inside_the_target_backs_out_instead_of_failing() {
+        let room = cube_corners(5.0);
+
+        let fit = calculate_fov_fit(
+            &room,
+            Vec3::ZERO,
+            1.0,
+            &FitParams {
+                margins: FitMargins::uniform(0.1),
+                ..default()
+            },
+            &default_perspective(),
+            &Camera::default(),
+            &OrbitLimits::default(),
+        )
+        .expect("a camera inside the room should back out and fit");
+
+        assert!(fit.limits.near_plane);
+        assert!(fit.fov.is_some());
+        let depth = nearest_depth(&room, fit.focus, fit.radius);
+        assert!(
+            depth >= default_perspective().near,
+            "nearest wall at depth {depth:.3} should clear the near plane"
+        );
+    }
 }
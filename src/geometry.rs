@@ -0,0 +1,150 @@
+//! Fit geometry from app components other than `Mesh3d`, via [`FitGeometry`].
+//!
+//! Each component type registered with
+//! [`register_fit_geometry`](FitGeometryAppExt::register_fit_geometry) has its points copied
+//! into a [`FitGeometryPoints`] on the entity whenever it changes, where fit targets and the
+//! visualization pick them up alongside the entity's meshes. Their reduced points are cached in
+//! the [`MeshFitCache`](crate::MeshFitCache) until the geometry changes again.
+
+use std::any::TypeId;
+use std::collections::HashMap;
+
+use bevy::math::bounding::Aabb3d;
+use bevy::math::bounding::BoundingSphere;
+use bevy::prelude::*;
+
+use crate::support::aabb_corners;
+use crate::support::sphere_surface_points;
+
+/// A component that contributes fit geometry to its entity, for content without a `Mesh3d` —
+/// point clouds, instanced foliage, voxel chunks or text.
+///
+/// Geometry is in the entity's local space: fits apply its `GlobalTransform` exactly as they do
+/// for a mesh, and [`FitVolume`](crate::FitVolume) reduces the points the same way. For data
+/// already in world space, put the component on an entity at the origin. Entity targets then
+/// include the geometry of every registered component at or under them, subject to
+/// [`FitIgnore`](crate::FitIgnore) and [`FitFilter`](crate::FitFilter):
+///
+/// ```ignore
+/// impl FitGeometry for PointCloud {
+///     fn fit_shape(&self) -> Option<FitShape> { Some(FitShape::Points(self.points.clone())) }
+/// }
+///
+/// app.register_fit_geometry::<PointCloud>();
+/// ```
+///
+/// Points are read when the component is added or changed, and become visible to fits in
+/// `PostUpdate` of that frame; a fit triggered before then can wait for them with
+/// [`wait_for_load`](crate::ZoomToFit::wait_for_load).
+pub trait FitGeometry: Component {
+    /// Returns the geometry this component contributes, or `None` while it has none yet (for
+    /// example text that has not been laid out).
+    fn fit_shape(&self) -> Option<FitShape>;
+}
+
+/// Local-space geometry a [`FitGeometry`] component contributes to fits.
+#[derive(Clone, Debug, PartialEq)]
+pub enum FitShape {
+    /// Individual points.
+    Points(Vec<Vec3>),
+    /// A box, contributing its 8 corners.
+    Aabb(Aabb3d),
+    /// A sphere, sampled just outside its surface so the framing never clips it.
+    Sphere(BoundingSphere),
+}
+
+impl FitShape {
+    fn into_points(self) -> Vec<Vec3> {
+        match self {
+            Self::Points(points) => points,
+            Self::Aabb(aabb) => aabb_corners(&aabb).to_vec(),
+            Self::Sphere(sphere) => sphere_surface_points(&sphere),
+        }
+    }
+}
+
+impl From<Vec<Vec3>> for FitShape {
+    fn from(points: Vec<Vec3>) -> Self { Self::Points(points) }
+}
+
+impl From<Aabb3d> for FitShape {
+    fn from(aabb: Aabb3d) -> Self { Self::Aabb(aabb) }
+}
+
+impl From<BoundingSphere> for FitShape {
+    fn from(sphere: BoundingSphere) -> Self { Self::Sphere(sphere) }
+}
+
+/// Local-space points from each registered [`FitGeometry`] component on this entity. Present
+/// only while at least one of them has points to contribute.
+#[derive(Component)]
+pub struct FitGeometryPoints {
+    points: HashMap<TypeId, Vec<Vec3>>,
+}
+
+impl FitGeometryPoints {
+    /// Returns the points of every contributing component.
+    pub fn points(&self) -> Vec<Vec3> { self.points.values().flatten().copied().collect() }
+}
+
+/// Sets (or with `None`, clears) the points `source` contributes to `entity`, removing its
+/// [`FitGeometryPoints`] once no source has any.
+fn set_fit_geometry_points(mut entity: EntityWorldMut, source: TypeId, points: Option<Vec<Vec3>>) {
+    match (points, entity.get_mut::<FitGeometryPoints>()) {
+        (Some(points), Some(mut provided)) => {
+            provided.points.insert(source, points);
+        },
+        (Some(points), None) => {
+            entity.insert(FitGeometryPoints {
+                points: HashMap::from([(source, points)]),
+            });
+        },
+        (None, Some(mut provided)) => {
+            provided.points.remove(&source);
+            if provided.points.is_empty() {
+                entity.remove::<FitGeometryPoints>();
+            }
+        },
+        (None, None) => {},
+    }
+}
+
+/// System that copies the geometry of added or changed `C` components into
+/// [`FitGeometryPoints`], and drops it when `C` is removed.
+fn sync_fit_geometry<C: FitGeometry>(
+    mut commands: Commands,
+    changed_query: Query<(Entity, &C), Changed<C>>,
+    mut removed: RemovedComponents<C>,
+) {
+    let source = TypeId::of::<C>();
+    for (entity, geometry) in &changed_query {
+        let points = geometry
+            .fit_shape()
+            .map(FitShape::into_points)
+            .filter(|points| !points.is_empty());
+        commands
+            .entity(entity)
+            .queue_silenced(move |entity: EntityWorldMut| {
+                set_fit_geometry_points(entity, source, points);
+            });
+    }
+    for entity in removed.read() {
+        if let Ok(mut entity_commands) = commands.get_entity(entity) {
+            entity_commands.queue_silenced(move |entity: EntityWorldMut| {
+                set_fit_geometry_points(entity, source, None);
+            });
+        }
+    }
+}
+
+/// Registers [`FitGeometry`] components as sources of fit points.
+pub trait FitGeometryAppExt {
+    /// Lets fits and the visualization frame the geometry of `C` components.
+    fn register_fit_geometry<C: FitGeometry>(&mut self) -> &mut Self;
+}
+
+impl FitGeometryAppExt for App {
+    fn register_fit_geometry<C: FitGeometry>(&mut self) -> &mut Self {
+        self.add_systems(PostUpdate, sync_fit_geometry::<C>)
+    }
+}
@@ -12,11 +12,23 @@
 use bevy::prelude::*;
 
 mod animation;
+mod async_fit;
+mod best_view;
 mod components;
+mod convex_hull;
+mod deferred_fit;
 mod events;
 mod fit;
+mod geometry;
+mod maintain;
+mod mesh_cache;
 mod observers;
+#[cfg(feature = "ui_occluders")]
+mod occluders;
+mod projection;
+mod solver;
 mod support;
+mod tracking;
 #[cfg(feature = "visualization")]
 mod visualization;
 
@@ -24,12 +36,28 @@ mod visualization;
 pub use animation::CameraMove;
 pub use animation::CameraMoveList;
 use animation::process_camera_move_list;
+// Async fits
+pub use async_fit::PendingFit;
+use async_fit::poll_pending_fits;
 // Components
 pub use components::AnimationConflictPolicy;
 pub use components::CameraInputInterruptBehavior;
+pub use components::CurrentFitOptions;
 pub use components::CurrentFitTarget;
+pub use components::FitAlgorithm;
+pub use components::FitExecution;
+pub use components::FitFallback;
+pub use components::FitFilter;
+pub use components::FitIgnore;
+#[cfg(feature = "ui_occluders")]
+pub use components::FitOccluder;
+pub use components::FitStrategy;
 #[cfg(feature = "visualization")]
 pub use components::FitVisualization;
+pub use components::UnoccludedViewport;
+// Deferred fits
+pub use deferred_fit::DeferredFit;
+use deferred_fit::resolve_deferred_fits;
 // Events
 pub use events::AnimateToFit;
 pub use events::AnimationBegin;
@@ -37,10 +65,22 @@ pub use events::AnimationCancelled;
 pub use events::AnimationEnd;
 pub use events::AnimationRejected;
 pub use events::AnimationSource;
+pub use events::AutoFit;
+pub use events::BestView;
 pub use events::CameraMoveBegin;
 pub use events::CameraMoveEnd;
+pub use events::FitAnchor;
+pub use events::FitFailed;
+pub use events::FitFrame;
+pub use events::FitLimited;
+pub use events::FitMargins;
+pub use events::FitPose;
+pub use events::FitTarget;
+pub use events::FitVolume;
 pub use events::LookAt;
 pub use events::LookAtAndZoomToFit;
+pub use events::MarginUnit;
+pub use events::MeshRegion;
 pub use events::PlayAnimation;
 pub use events::SetFitTarget;
 pub use events::ZoomBegin;
@@ -48,7 +88,23 @@ pub use events::ZoomCancelled;
 pub use events::ZoomContext;
 pub use events::ZoomEnd;
 pub use events::ZoomToFit;
+// Fit
+pub use fit::FitError;
+pub use fit::FitLimits;
+pub use fit::FitSolution;
+// Geometry
+pub use geometry::FitGeometry;
+pub use geometry::FitGeometryAppExt;
+pub use geometry::FitShape;
+// Maintain fit
+pub use maintain::MaintainFit;
+use maintain::maintain_fits;
+// Mesh cache
+pub use mesh_cache::MeshFitCache;
+use mesh_cache::invalidate_mesh_fit_cache;
+use mesh_cache::prune_geometry_fit_cache;
 use observers::on_animate_to_fit;
+use observers::on_auto_fit;
 use observers::on_camera_move_list_added;
 use observers::on_look_at;
 use observers::on_look_at_and_zoom_to_fit;
@@ -56,6 +112,19 @@ use observers::on_play_animation;
 use observers::on_set_fit_target;
 use observers::on_zoom_to_fit;
 use observers::restore_camera_state;
+// Projection
+pub use projection::FitProjection;
+pub use projection::FitProjectionAppExt;
+pub use projection::FitProjectionRegistry;
+// Solver
+pub use solver::FitOptions;
+pub use solver::FitPreview;
+pub use solver::FitSolver;
+pub use support::ScreenSpaceBounds;
+// Tracking
+pub use tracking::TrackMode;
+pub use tracking::TrackTarget;
+use tracking::track_targets;
 // Visualization
 #[cfg(feature = "visualization")]
 pub use visualization::FitTargetVisualizationConfig;
@@ -74,10 +143,46 @@ impl Plugin for PanOrbitCameraExtPlugin {
             .add_observer(on_play_animation)
             .add_observer(on_set_fit_target)
             .add_observer(on_animate_to_fit)
+            .add_observer(on_auto_fit)
             .add_observer(on_look_at)
             .add_observer(on_look_at_and_zoom_to_fit)
+            // Custom projections opt in via `register_fit_projection`
+            .init_resource::<FitProjectionRegistry>()
+            // Cache of per-mesh fit points, invalidated by mesh asset changes
+            .init_resource::<MeshFitCache>()
+            .add_message::<AssetEvent<Mesh>>()
+            .add_systems(
+                PostUpdate,
+                (
+                    invalidate_mesh_fit_cache.after(bevy::asset::AssetEventSystems),
+                    prune_geometry_fit_cache,
+                ),
+            )
             // Add systems
-            .add_systems(Update, process_camera_move_list);
+            .add_systems(
+                Update,
+                (
+                    maintain_fits
+                        .before(process_camera_move_list)
+                        .run_if(any_with_component::<MaintainFit>),
+                    resolve_deferred_fits
+                        .before(process_camera_move_list)
+                        .run_if(any_with_component::<DeferredFit>),
+                    poll_pending_fits
+                        .before(process_camera_move_list)
+                        .run_if(any_with_component::<PendingFit>),
+                    process_camera_move_list,
+                    track_targets
+                        .after(process_camera_move_list)
+                        .run_if(any_with_component::<TrackTarget>),
+                ),
+            );
+
+        #[cfg(feature = "ui_occluders")]
+        app.add_systems(
+            PostUpdate,
+            occluders::update_unoccluded_viewports.after(bevy::ui::UiSystems::Layout),
+        );
 
         #[cfg(feature = "visualization")]
         app.add_plugins(visualization::VisualizationPlugin);
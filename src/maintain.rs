@@ -0,0 +1,259 @@
+//! Re-fitting the current fit target when the viewport or projection changes, via
+//! [`MaintainFit`].
+
+use bevy::prelude::*;
+use bevy_panorbit_camera::PanOrbitCamera;
+
+use crate::animation::CameraMove;
+use crate::animation::CameraMoveList;
+use crate::animation::set_perspective_fov;
+use crate::components::AnimationSourceMarker;
+use crate::components::CurrentFitOptions;
+use crate::components::CurrentFitTarget;
+use crate::components::FitAlgorithm;
+use crate::components::FitStrategy;
+use crate::components::UnoccludedViewport;
+use crate::events::AnimationSource;
+use crate::fit::FitError;
+use crate::fit::FitParams;
+use crate::fit::FitSolution;
+use crate::observers::fit_points;
+use crate::projection::FitProjection;
+use crate::projection::FitProjectionRegistry;
+use crate::support::FitTargetQueries;
+use crate::support::extract_fit_target_vertices;
+
+/// Relative change in the viewport size or visible extents that counts as a new view.
+const VIEW_CHANGE_TOLERANCE: f32 = 1e-4;
+
+/// Keeps the camera's [`CurrentFitTarget`] framed when the view a fit was solved for changes.
+///
+/// A fit is only exact for the viewport size, [`UnoccludedViewport`] and projection it saw.
+/// With this component on the camera, resizing the window, moving docked panels or changing the
+/// `Projection` re-runs the fit with its [`CurrentFitOptions`]:
+///
+/// - at rest, the camera moves to the new focus and radius (through its own smoothing);
+/// - during a fit animation (`ZoomToFit`, `AnimateToFit`, `AutoFit`, `LookAtAndZoomToFit`), the
+///   animation's endpoint is retargeted so it lands on the corrected framing;
+/// - during other animations, the re-fit waits until they finish.
+///
+/// A field-of-view fit's own lens animation is not a view change, and a re-fit that fails (for
+/// example while the target's mesh is reloading) is retried every frame until it succeeds.
+///
+/// ```ignore
+/// commands.entity(camera).insert(MaintainFit::default());
+/// ```
+#[derive(Component, Reflect, Clone, Copy, Debug, Default)]
+#[reflect(Component, Default)]
+pub struct MaintainFit {
+    /// The view the current framing was solved for; `None` until first seen.
+    view: Option<FitView>,
+}
+
+/// What a fit depends on besides the target and the orbit.
+#[derive(Clone, Copy, Debug, PartialEq, Reflect)]
+struct FitView {
+    viewport_size: Option<Vec2>,
+    unoccluded:    Option<Rect>,
+    /// Visible half extents at radius `1.0`, which change with the field of view, aspect ratio
+    /// or orthographic scaling mode but not with zooming.
+    unit_extents:  Option<Vec2>,
+}
+
+impl FitView {
+    fn approx_eq(&self, other: &Self) -> bool {
+        fn close(a: Vec2, b: Vec2) -> bool {
+            (a - b).abs().max_element() <= VIEW_CHANGE_TOLERANCE * a.abs().max_element().max(1.0)
+        }
+        fn both<T: Copy>(a: Option<T>, b: Option<T>, eq: impl Fn(T, T) -> bool) -> bool {
+            match (a, b) {
+                (Some(a), Some(b)) => eq(a, b),
+                (None, None) => true,
+                _ => false,
+            }
+        }
+        both(self.viewport_size, other.viewport_size, close)
+            && both(self.unoccluded, other.unoccluded, |a, b| {
+                close(a.min, b.min) && close(a.max, b.max)
+            })
+            && both(self.unit_extents, other.unit_extents, close)
+    }
+}
+
+/// Moves a fit animation's endpoint to `fit`.
+const fn retarget_move(camera_move: &mut CameraMove, fit: &FitSolution) {
+    match camera_move {
+        CameraMove::ToOrbit { focus, radius, .. } => {
+            *focus = fit.focus;
+            *radius = fit.radius;
+        },
+        CameraMove::ToOrbitWithFov {
+            focus, radius, fov, ..
+        } => {
+            *focus = fit.focus;
+            *radius = fit.radius;
+            if let Some(fit_fov) = fit.fov {
+                *fov = fit_fov;
+            }
+        },
+        CameraMove::ToPosition { .. } => {},
+    }
+}
+
+/// System that re-fits cameras with [`MaintainFit`] whose viewport or projection changed.
+#[allow(clippy::type_complexity)]
+pub fn maintain_fits(
+    mut camera_query: Query<(
+        Entity,
+        &mut MaintainFit,
+        &mut PanOrbitCamera,
+        &mut Projection,
+        &Camera,
+        &CurrentFitTarget,
+        Option<&CurrentFitOptions>,
+        Option<&UnoccludedViewport>,
+        Option<&FitAlgorithm>,
+        Option<&FitStrategy>,
+        Option<&mut CameraMoveList>,
+        Option<&AnimationSourceMarker>,
+    )>,
+    projections: Res<FitProjectionRegistry>,
+    mut queries: FitTargetQueries,
+) {
+    for (
+        camera,
+        mut maintain,
+        mut panorbit,
+        mut projection,
+        cam,
+        target,
+        options,
+        unoccluded,
+        algorithm,
+        strategy,
+        move_list,
+        source,
+    ) in &mut camera_query
+    {
+        // A field-of-view animation changes the projection every frame; measure the view at the
+        // field of view it ends on, so the fit doesn't keep retargeting its own animation.
+        let end_fov = move_list
+            .as_ref()
+            .and_then(|list| list.camera_moves.back())
+            .and_then(|camera_move| match camera_move {
+                CameraMove::ToOrbitWithFov { fov, .. } => Some(*fov),
+                CameraMove::ToOrbit { .. } | CameraMove::ToPosition { .. } => None,
+            });
+        let end_lens;
+        let fit_projection = match (end_fov, &*projection) {
+            (Some(fov), Projection::Perspective(perspective)) => {
+                end_lens = PerspectiveProjection {
+                    fov,
+                    ..perspective.clone()
+                };
+                &end_lens as &dyn FitProjection
+            },
+            _ => match projections.resolve(&projection) {
+                Some(fit_projection) => fit_projection,
+                None => continue,
+            },
+        };
+        let viewport_size = cam.logical_viewport_size();
+        let view = FitView {
+            viewport_size,
+            unoccluded: unoccluded.map(|u| u.0),
+            unit_extents: fit_projection
+                .half_extents(viewport_size)
+                .map(|half_extents| half_extents * fit_projection.radius_extent_scale(1.0)),
+        };
+        let Some(previous) = maintain.view else {
+            maintain.view = Some(view);
+            continue;
+        };
+        if previous.approx_eq(&view) {
+            continue;
+        }
+
+        // Fit animations are retargeted; other animations are left alone and the re-fit waits
+        // for them, since the view stays unrecorded until then. So does a failed re-fit, which
+        // is retried on the next frame.
+        let fit_animation = source.is_some_and(|source| {
+            matches!(
+                source.0,
+                AnimationSource::ZoomToFit
+                    | AnimationSource::AnimateToFit
+                    | AnimationSource::AutoFit
+                    | AnimationSource::LookAtAndZoomToFit
+            )
+        });
+        let mut move_list = match move_list {
+            Some(move_list) if fit_animation => Some(move_list),
+            Some(_) => continue,
+            None => None,
+        };
+        let (yaw, pitch) = match move_list.as_ref().and_then(|list| list.camera_moves.back()) {
+            Some(
+                CameraMove::ToOrbit { yaw, pitch, .. }
+                | CameraMove::ToOrbitWithFov { yaw, pitch, .. },
+            ) => (*yaw, *pitch),
+            Some(CameraMove::ToPosition { .. }) => continue,
+            None => (panorbit.target_yaw, panorbit.target_pitch),
+        };
+        let options = options.copied().unwrap_or_default();
+        let params = FitParams {
+            yaw,
+            pitch,
+            margins: options.margin,
+            frame: options.frame.resolve_unoccluded(unoccluded.map(|u| u.0)),
+            anchor: options.anchor,
+            algorithm: algorithm.copied().unwrap_or_default(),
+            strategy: strategy.copied().unwrap_or_default(),
+            fallback: queries
+                .fallback_query
+                .get(camera)
+                .copied()
+                .unwrap_or_default(),
+        };
+        let fit = extract_fit_target_vertices(
+            &target.0,
+            camera,
+            options.volume,
+            options.pose,
+            &mut queries,
+        )
+        .and_then(|(points, geometric_center)| {
+            let fit_projection = projections
+                .resolve(&projection)
+                .ok_or(FitError::UnsupportedProjection)?;
+            fit_points(
+                &points,
+                geometric_center,
+                &params,
+                &projection,
+                fit_projection,
+                cam,
+                &panorbit,
+            )
+        });
+        let fit = match fit {
+            Ok(fit) => fit,
+            Err(reason) => {
+                debug!("MaintainFit: re-fit of {:?} failed: {reason}", target.0);
+                continue;
+            },
+        };
+        maintain.view = Some(view);
+
+        if let Some(move_list) = move_list.as_mut()
+            && let Some(last_move) = move_list.camera_moves.back_mut()
+        {
+            retarget_move(last_move, &fit);
+        } else {
+            panorbit.target_focus = fit.focus;
+            panorbit.target_radius = fit.radius;
+            if let Some(fov) = fit.fov {
+                set_perspective_fov(Some(&mut projection), fov);
+            }
+        }
+    }
+}
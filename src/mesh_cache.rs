@@ -0,0 +1,105 @@
+//! Cache of per-mesh local-space fit points, shared by the fit observers and the visualization.
+
+use std::collections::HashMap;
+use std::collections::hash_map::Entry;
+
+use bevy::ecs::change_detection::Tick;
+use bevy::prelude::*;
+
+use crate::events::FitVolume;
+use crate::geometry::FitGeometryPoints;
+use crate::support::mesh_local_points;
+use crate::support::reduce_points;
+
+/// Local-space fit points per mesh asset and [`FitVolume`], and per entity with
+/// [`FitGeometry`](crate::FitGeometry) points.
+///
+/// Repeated fits and the per-frame visualization look up each mesh's points here, so only the
+/// `GlobalTransform` multiply happens per call instead of re-reading (and, for hulls,
+/// re-reducing) the mesh. Entries are dropped when their mesh asset is modified or removed;
+/// geometry entries are recomputed when the entity's geometry changes and dropped when it is
+/// removed.
+#[derive(Resource, Default)]
+pub struct MeshFitCache {
+    points:   HashMap<(AssetId<Mesh>, FitVolume), Vec<Vec3>>,
+    geometry: HashMap<(Entity, FitVolume), (Tick, Vec<Vec3>)>,
+}
+
+/// Bounding spheres are built from the per-mesh box corners, so both share an entry.
+const fn cached_volume(volume: FitVolume) -> FitVolume {
+    match volume {
+        FitVolume::BoundingSphere => FitVolume::MeshAabb,
+        volume => volume,
+    }
+}
+
+impl MeshFitCache {
+    /// Returns the local-space points standing in for a mesh under `volume`, reading and
+    /// reducing the mesh on a miss. Returns `None`, caching nothing, if the mesh has no float3
+    /// position attribute.
+    pub(crate) fn local_points(
+        &mut self,
+        id: AssetId<Mesh>,
+        mesh: &Mesh,
+        volume: FitVolume,
+    ) -> Option<&[Vec3]> {
+        let volume = cached_volume(volume);
+        match self.points.entry((id, volume)) {
+            Entry::Occupied(entry) => Some(entry.into_mut().as_slice()),
+            Entry::Vacant(entry) => Some(entry.insert(mesh_local_points(mesh, volume)?).as_slice()),
+        }
+    }
+
+    /// Returns the local-space points standing in for an entity's [`FitGeometryPoints`] under
+    /// `volume`, reducing them again if they changed since they were cached.
+    pub(crate) fn geometry_points(
+        &mut self,
+        entity: Entity,
+        provided: &Ref<FitGeometryPoints>,
+        volume: FitVolume,
+    ) -> &[Vec3] {
+        let volume = cached_volume(volume);
+        let changed = provided.last_changed();
+        let entry = self
+            .geometry
+            .entry((entity, volume))
+            .or_insert_with(|| (changed, reduce_points(provided.points(), volume)));
+        if entry.0 != changed {
+            *entry = (changed, reduce_points(provided.points(), volume));
+        }
+        &entry.1
+    }
+
+    /// Drops every cached entry for a mesh asset.
+    pub fn invalidate(&mut self, id: AssetId<Mesh>) {
+        self.points.retain(|(cached, _), _| *cached != id);
+    }
+
+    /// Drops all cached entries.
+    pub fn clear(&mut self) {
+        self.points.clear();
+        self.geometry.clear();
+    }
+}
+
+/// Invalidates cached points for meshes that were modified or removed this frame.
+pub fn invalidate_mesh_fit_cache(
+    mut asset_events: MessageReader<AssetEvent<Mesh>>,
+    mut cache: ResMut<MeshFitCache>,
+) {
+    for event in asset_events.read() {
+        if let AssetEvent::Modified { id } | AssetEvent::Removed { id } = event {
+            cache.invalidate(*id);
+        }
+    }
+}
+
+/// Drops cached points for entities whose [`FitGeometryPoints`] were removed this frame.
+pub fn prune_geometry_fit_cache(
+    mut removed: RemovedComponents<FitGeometryPoints>,
+    mut cache: ResMut<MeshFitCache>,
+) {
+    for entity in removed.read() {
+        cache.geometry.retain(|(cached, _), _| *cached != entity);
+    }
+}
@@ -1,6 +1,5 @@
 //! Observers that wire events to camera behavior.
 
-use std::collections::VecDeque;
 use std::time::Duration;
 
 use bevy::math::curve::easing::EaseFunction;
@@ -10,18 +9,39 @@ use bevy_panorbit_camera::PanOrbitCamera;
 use crate::animation::CameraMove;
 use crate::animation::CameraMoveList;
 use crate::animation::orbital_params_from_offset;
+use crate::animation::set_perspective_fov;
+use crate::async_fit::AsyncFits;
+use crate::async_fit::PendingFit;
+use crate::best_view::choose_best_view;
 use crate::components::AnimationConflictPolicy;
 use crate::components::AnimationSourceMarker;
 use crate::components::CameraInputInterruptBehavior;
+use crate::components::CurrentFitOptions;
 use crate::components::CurrentFitTarget;
+use crate::components::FitAlgorithm;
+use crate::components::FitStrategy;
 use crate::components::PanOrbitCameraStash;
+use crate::components::UnoccludedViewport;
 use crate::components::ZoomAnimationMarker;
+use crate::convex_hull::convex_hull_3d;
+use crate::deferred_fit::DeferredFit;
+use crate::deferred_fit::DeferredFits;
 use crate::events::AnimateToFit;
 use crate::events::AnimationBegin;
 use crate::events::AnimationCancelled;
 use crate::events::AnimationEnd;
 use crate::events::AnimationRejected;
 use crate::events::AnimationSource;
+use crate::events::AutoFit;
+use crate::events::BestView;
+use crate::events::FitAnchor;
+use crate::events::FitFailed;
+use crate::events::FitFrame;
+use crate::events::FitLimited;
+use crate::events::FitMargins;
+use crate::events::FitPose;
+use crate::events::FitTarget;
+use crate::events::FitVolume;
 use crate::events::LookAt;
 use crate::events::LookAtAndZoomToFit;
 use crate::events::PlayAnimation;
@@ -31,9 +51,19 @@ use crate::events::ZoomCancelled;
 use crate::events::ZoomContext;
 use crate::events::ZoomEnd;
 use crate::events::ZoomToFit;
+use crate::fit::FitError;
+use crate::fit::FitLimits;
+use crate::fit::FitParams;
 use crate::fit::FitSolution;
 use crate::fit::calculate_fit;
-use crate::support::extract_mesh_vertices;
+use crate::fit::calculate_fov_fit;
+use crate::projection::FitProjection;
+use crate::projection::FitProjectionRegistry;
+use crate::support::FitTargetQueries;
+use crate::support::OrbitLimits;
+use crate::support::extract_fit_target_vertices;
+use crate::support::fit_target_translation;
+use crate::support::min_radius_stand_in;
 
 /// Parameters for an instant orbital snap.
 struct SnapOrbit {
@@ -41,16 +71,26 @@ struct SnapOrbit {
     yaw:    Option<f32>,
     pitch:  Option<f32>,
     radius: f32,
+    /// Perspective field of view, for field-of-view fits.
+    fov:    Option<f32>,
 }
 
-/// Snaps the camera to an orbital position instantly (no animation) and fires
-/// caller-provided lifecycle events via `emit_events`.
+/// Snaps the camera to an orbital position instantly (no animation), clamped to the camera's
+/// limits, and fires caller-provided lifecycle events via `emit_events`.
 fn snap_to_orbit(
     commands: &mut Commands,
     panorbit: &mut PanOrbitCamera,
+    projection: Option<&mut Mut<Projection>>,
     snap: SnapOrbit,
     emit_events: impl FnOnce(&mut Commands),
 ) {
+    let limits = OrbitLimits::from_camera(panorbit);
+    let snap = SnapOrbit {
+        radius: limits.clamp_radius(snap.radius),
+        yaw: snap.yaw.map(|yaw| limits.clamp_yaw(yaw)),
+        pitch: snap.pitch.map(|pitch| limits.clamp_pitch(pitch)),
+        ..snap
+    };
     panorbit.focus = snap.focus;
     panorbit.radius = Some(snap.radius);
     panorbit.target_focus = snap.focus;
@@ -64,6 +104,9 @@ fn snap_to_orbit(
         panorbit.target_pitch = pitch;
     }
     panorbit.force_update = true;
+    if let Some(fov) = snap.fov {
+        set_perspective_fov(projection, fov);
+    }
 
     emit_events(commands);
 }
@@ -95,77 +138,451 @@ fn stash_camera_state(
     }
 }
 
-/// Shared fit preparation used by both ZoomToFit and AnimateToFit observers.
-/// Extracts target mesh vertices and computes the fit solution for the requested
-/// camera orientation.
-#[allow(clippy::too_many_arguments)]
-fn prepare_fit_for_target(
-    context: &str,
-    target: Entity,
-    yaw: f32,
-    pitch: f32,
-    margin: f32,
+/// Fits pre-extracted points with the [`FitStrategy`] in `params`. `FieldOfView` holds the
+/// camera's current radius and solves the field of view on perspective cameras; other
+/// projections fit by radius. Targets smaller than the `params`'
+/// [`FitFallback::min_radius`](crate::FitFallback::min_radius) are fitted as a sphere of that
+/// radius.
+pub fn fit_points(
+    points: &[Vec3],
+    geometric_center: Vec3,
+    params: &FitParams,
     projection: &Projection,
+    fit_projection: &dyn FitProjection,
     camera: &Camera,
-    mesh_query: &Query<&Mesh3d>,
-    children_query: &Query<&Children>,
-    global_transform_query: &Query<&GlobalTransform>,
-    meshes: &Assets<Mesh>,
-) -> Option<FitSolution> {
-    let Some((vertices, geometric_center)) = extract_mesh_vertices(
+    panorbit: &PanOrbitCamera,
+) -> Result<FitSolution, FitError> {
+    let stand_in = min_radius_stand_in(points, params.fallback.min_radius)?;
+    let (points, geometric_center) = stand_in
+        .as_ref()
+        .map_or((points, geometric_center), |(sphere, center)| {
+            (sphere.as_slice(), *center)
+        });
+    let limits = OrbitLimits::from_camera(panorbit);
+    let fit = match (params.strategy, projection) {
+        (FitStrategy::FieldOfView, Projection::Perspective(perspective)) => calculate_fov_fit(
+            points,
+            geometric_center,
+            panorbit.target_radius,
+            params,
+            perspective,
+            camera,
+            &limits,
+        ),
+        _ => calculate_fit(
+            points,
+            geometric_center,
+            params,
+            fit_projection,
+            camera,
+            &limits,
+        ),
+    }?;
+    Ok(FitSolution {
+        limits: FitLimits {
+            min_radius: stand_in.is_some(),
+            ..fit.limits
+        },
+        ..fit
+    })
+}
+
+/// The animated move to a fit from `yaw` and `pitch`, changing the field of view as well for
+/// field-of-view fits.
+const fn fit_move(
+    fit: &FitSolution,
+    yaw: f32,
+    pitch: f32,
+    duration: Duration,
+    easing: EaseFunction,
+) -> CameraMove {
+    match fit.fov {
+        Some(fov) => CameraMove::ToOrbitWithFov {
+            focus: fit.focus,
+            yaw,
+            pitch,
+            radius: fit.radius,
+            fov,
+            duration,
+            easing,
+        },
+        None => CameraMove::ToOrbit {
+            focus: fit.focus,
+            yaw,
+            pitch,
+            radius: fit.radius,
+            duration,
+            easing,
+        },
+    }
+}
+
+/// Triggers [`FitLimited`] if the camera's limits kept `fit` from framing the target as
+/// requested.
+fn report_fit_limits(
+    commands: &mut Commands,
+    camera: Entity,
+    target: &FitTarget,
+    source: AnimationSource,
+    fit: &FitSolution,
+) {
+    if !fit.limits.any() {
+        return;
+    }
+    debug!("{source:?}: fit of {target:?} limited by {:?}", fit.limits);
+    commands.trigger(FitLimited {
+        camera,
+        target: target.clone(),
+        source,
+        limits: fit.limits,
+    });
+}
+
+/// Reports a fit request that could not be computed by triggering [`FitFailed`].
+fn fail_fit(
+    commands: &mut Commands,
+    camera: Entity,
+    target: FitTarget,
+    source: AnimationSource,
+    reason: FitError,
+) {
+    debug!("{source:?}: cannot fit {target:?}: {reason}");
+    commands.trigger(FitFailed {
+        camera,
         target,
-        children_query,
-        mesh_query,
-        global_transform_query,
-        meshes,
-    ) else {
-        warn!("{context}: Failed to extract mesh vertices for entity {target:?}");
-        return None;
+        source,
+        reason,
+    });
+}
+
+/// A fit event's options and how its solved fit is applied, kept together so a fit solved on a
+/// background task finishes exactly like one solved inside its observer.
+#[derive(Clone, Debug)]
+pub struct FitRequest {
+    pub camera:      Entity,
+    pub target:      FitTarget,
+    pub source:      AnimationSource,
+    pub margin:      FitMargins,
+    /// The requested frame, with [`FitFrame::Unoccluded`] still unresolved.
+    pub frame:       FitFrame,
+    pub anchor:      FitAnchor,
+    pub volume:      FitVolume,
+    pub pose:        FitPose,
+    pub duration:    Duration,
+    pub easing:      EaseFunction,
+    pub orientation: FitOrientation,
+}
+
+impl FitRequest {
+    /// Reports that this request could not be fitted.
+    pub fn fail(self, commands: &mut Commands, reason: FitError) {
+        fail_fit(commands, self.camera, self.target, self.source, reason);
+    }
+}
+
+/// The orientation a solved fit is applied with.
+#[derive(Clone, Copy, Debug)]
+pub enum FitOrientation {
+    /// Keep the camera's yaw and pitch, as `ZoomToFit` does.
+    Keep,
+    /// Orbit to the yaw and pitch the fit was solved for.
+    Fitted,
+    /// Look at the fit's focus from this world position, as `LookAtAndZoomToFit` does.
+    LookFrom(Vec3),
+}
+
+/// What a fit solves for.
+#[derive(Clone, Copy, Debug)]
+pub enum FitJob {
+    /// Fit from this yaw and pitch.
+    Orbit { yaw: f32, pitch: f32 },
+    /// Fit from the angle a [`BestView`] strategy picks, starting from the `current` yaw and
+    /// pitch. Candidate angles are fitted against the 3D convex hull of the target's points,
+    /// which frames the same silhouette with far fewer points.
+    BestView {
+        view:    BestView,
+        current: (f32, f32),
+    },
+}
+
+/// Everything a fit reads, copied out of the world so it can also be solved off the main
+/// thread.
+pub struct FitInputs {
+    points:           Vec<Vec3>,
+    geometric_center: Vec3,
+    /// Fit parameters, with the yaw and pitch left to the [`FitJob`].
+    params:           FitParams,
+    projection:       Projection,
+    projections:      FitProjectionRegistry,
+    camera:           Camera,
+    panorbit:         PanOrbitCamera,
+}
+
+impl FitInputs {
+    /// Extracts the world-space points of the request's target and snapshots the camera.
+    #[allow(clippy::too_many_arguments)]
+    pub fn gather(
+        request: &FitRequest,
+        unoccluded: Option<&UnoccludedViewport>,
+        algorithm: Option<&FitAlgorithm>,
+        strategy: Option<&FitStrategy>,
+        projection: &Projection,
+        projections: &FitProjectionRegistry,
+        camera: &Camera,
+        panorbit: &PanOrbitCamera,
+        queries: &mut FitTargetQueries,
+    ) -> Result<Self, FitError> {
+        projections
+            .resolve(projection)
+            .ok_or(FitError::UnsupportedProjection)?;
+        let (points, geometric_center) = extract_fit_target_vertices(
+            &request.target,
+            request.camera,
+            request.volume,
+            request.pose,
+            queries,
+        )?;
+        Ok(Self {
+            points,
+            geometric_center,
+            params: FitParams {
+                margins: request.margin,
+                frame: request.frame.resolve_unoccluded(unoccluded.map(|u| u.0)),
+                anchor: request.anchor,
+                algorithm: algorithm.copied().unwrap_or_default(),
+                strategy: strategy.copied().unwrap_or_default(),
+                fallback: queries
+                    .fallback_query
+                    .get(request.camera)
+                    .copied()
+                    .unwrap_or_default(),
+                ..default()
+            },
+            projection: projection.clone(),
+            projections: projections.clone(),
+            camera: camera.clone(),
+            panorbit: *panorbit,
+        })
+    }
+
+    /// Solves `job` against the snapshot.
+    pub fn solve(&self, job: FitJob) -> Result<FitSolution, FitError> {
+        let fit_projection = self
+            .projections
+            .resolve(&self.projection)
+            .ok_or(FitError::UnsupportedProjection)?;
+        let fit = |points: &[Vec3], yaw: f32, pitch: f32| {
+            fit_points(
+                points,
+                self.geometric_center,
+                &FitParams {
+                    yaw,
+                    pitch,
+                    ..self.params
+                },
+                &self.projection,
+                fit_projection,
+                &self.camera,
+                &self.panorbit,
+            )
+        };
+        match job {
+            FitJob::Orbit { yaw, pitch } => fit(&self.points, yaw, pitch),
+            FitJob::BestView { view, current } => {
+                let hull = convex_hull_3d(&self.points);
+                choose_best_view(view, &hull, current, fit_projection, |yaw, pitch| {
+                    fit(&hull, yaw, pitch)
+                })
+            },
+        }
+    }
+}
+
+/// Solves `job` inside the observer, or hands it to a background task when the camera uses
+/// [`FitExecution::Async`](crate::FitExecution::Async), then applies or reports the result.
+fn run_fit(
+    commands: &mut Commands,
+    async_fits: &AsyncFits,
+    panorbit: &mut PanOrbitCamera,
+    projection: &mut Mut<Projection>,
+    request: FitRequest,
+    inputs: Result<FitInputs, FitError>,
+    job: FitJob,
+) {
+    let inputs = match inputs {
+        Ok(inputs) => inputs,
+        Err(reason) => {
+            async_fits.cancel_superseded(commands, request.camera);
+            request.fail(commands, reason);
+            return;
+        },
     };
+    if async_fits.is_async(request.camera) {
+        async_fits.spawn(commands, request, inputs, job);
+        return;
+    }
+    match inputs.solve(job) {
+        Ok(fit) => complete_fit(commands, panorbit, projection, request, &fit),
+        Err(reason) => request.fail(commands, reason),
+    }
+}
 
-    let fit = match calculate_fit(
-        &vertices,
-        geometric_center,
-        yaw,
-        pitch,
-        margin,
-        projection,
+/// Applies a solved fit: reports camera limits it ran into, animates (or snaps) to it, and
+/// records the request as the camera's fit target.
+pub fn complete_fit(
+    commands: &mut Commands,
+    panorbit: &mut PanOrbitCamera,
+    projection: &mut Mut<Projection>,
+    request: FitRequest,
+    fit: &FitSolution,
+) {
+    let FitRequest {
         camera,
-    ) {
-        Ok(fit) => fit,
-        Err(error) => {
-            warn!("{context}: Failed to calculate fit for entity {target:?}: {error}");
-            return None;
+        target,
+        source,
+        margin,
+        frame,
+        anchor,
+        volume,
+        pose,
+        duration,
+        easing,
+        orientation,
+    } = request;
+    report_fit_limits(commands, camera, &target, source, fit);
+
+    let (yaw, pitch) = match orientation {
+        FitOrientation::Keep | FitOrientation::Fitted => (fit.yaw, fit.pitch),
+        FitOrientation::LookFrom(cam_pos) => {
+            // Recompute yaw/pitch relative to the fit's focus (bounds center), which may
+            // differ slightly from the raw `GlobalTransform` translation.
+            let (yaw, pitch, _) = orbital_params_from_offset(cam_pos - fit.focus);
+            let limits = OrbitLimits::from_camera(panorbit);
+            (limits.clamp_yaw(yaw), limits.clamp_pitch(pitch))
         },
     };
+    let zoom = source == AnimationSource::ZoomToFit;
+
+    if duration > Duration::ZERO {
+        // Animated path: use `ToOrbit` to pass orbital params directly, avoiding
+        // gimbal lock from atan2 decomposition at extreme pitch angles.
+        let animation = PlayAnimation::new(camera, [fit_move(fit, yaw, pitch, duration, easing)]);
+        if zoom {
+            // `on_play_animation` handles conflict resolution, `ZoomBegin`, and
+            // `ZoomAnimationMarker` insertion — all in one place after acceptance.
+            commands.trigger(animation.zoom_context(ZoomContext {
+                target: target.clone(),
+                margin,
+                duration,
+                easing,
+            }));
+        } else {
+            commands.trigger(animation.source(source));
+        }
+    } else {
+        // Instant path: snap directly to target — no `PlayAnimation` involved.
+        let keep = matches!(orientation, FitOrientation::Keep);
+        snap_to_orbit(
+            commands,
+            panorbit,
+            Some(projection),
+            SnapOrbit {
+                focus:  fit.focus,
+                yaw:    (!keep).then_some(yaw),
+                pitch:  (!keep).then_some(pitch),
+                radius: fit.radius,
+                fov:    fit.fov,
+            },
+            |commands| {
+                if zoom {
+                    commands.trigger(ZoomBegin {
+                        camera,
+                        target: target.clone(),
+                        margin,
+                        duration,
+                        easing,
+                    });
+                    commands.trigger(ZoomEnd {
+                        camera,
+                        target: target.clone(),
+                        margin,
+                        duration: Duration::ZERO,
+                        easing,
+                    });
+                } else {
+                    commands.trigger(AnimationBegin { camera, source });
+                    commands.trigger(AnimationEnd { camera, source });
+                }
+            },
+        );
+    }
 
-    Some(fit)
+    // Route fit target updates through a single lifecycle owner.
+    commands.trigger(
+        SetFitTarget::new(camera, target)
+            .margin(margin)
+            .frame(frame)
+            .anchor(anchor)
+            .volume(volume)
+            .pose(pose),
+    );
 }
 
-/// Observer for `ZoomToFit` event - frames a target entity in the camera view.
+/// Observer for `ZoomToFit` event - frames a [`FitTarget`](crate::FitTarget) in the camera view:
+/// entities with a `Mesh3d` or [`FitGeometry`](crate::FitGeometry) (direct or on descendants),
+/// world-space points, boxes and spheres, or a [`MeshRegion`](crate::MeshRegion).
 /// When duration is `Duration::ZERO`, snaps instantly.
 /// When duration is greater than zero, animates smoothly via [`PlayAnimation`]
 /// with a [`ZoomContext`] so that `on_play_animation` handles all conflict
 /// resolution and zoom lifecycle events in one place.
-/// Requires target entity to have a `Mesh3d` (direct or on descendants).
+/// A target that cannot be fitted leaves the camera untouched and fires
+/// [`FitFailed`](crate::FitFailed) with the reason.
+#[allow(clippy::type_complexity)]
 pub fn on_zoom_to_fit(
     zoom: On<ZoomToFit>,
     mut commands: Commands,
-    mut camera_query: Query<(&mut PanOrbitCamera, &Projection, &Camera)>,
-    mesh_query: Query<&Mesh3d>,
-    children_query: Query<&Children>,
-    global_transform_query: Query<&GlobalTransform>,
-    meshes: Res<Assets<Mesh>>,
+    mut camera_query: Query<(
+        &mut PanOrbitCamera,
+        &mut Projection,
+        &Camera,
+        Option<&UnoccludedViewport>,
+        Option<&FitAlgorithm>,
+        Option<&FitStrategy>,
+    )>,
+    projections: Res<FitProjectionRegistry>,
+    mut queries: FitTargetQueries,
+    async_fits: AsyncFits,
+    deferred_fits: DeferredFits,
 ) {
-    let camera = zoom.camera;
-    let target = zoom.target;
-    let margin = zoom.margin;
-    let duration = zoom.duration;
-    let easing = zoom.easing;
+    let request = FitRequest {
+        camera:      zoom.camera,
+        target:      zoom.target.clone(),
+        source:      AnimationSource::ZoomToFit,
+        margin:      zoom.margin,
+        frame:       zoom.frame,
+        anchor:      zoom.anchor,
+        volume:      zoom.volume,
+        pose:        zoom.pose,
+        duration:    zoom.duration,
+        easing:      zoom.easing,
+        orientation: FitOrientation::Keep,
+    };
 
-    let Ok((mut panorbit, projection, cam)) = camera_query.get_mut(camera) else {
+    let Ok((mut panorbit, mut projection, cam, unoccluded, algorithm, strategy)) =
+        camera_query.get_mut(request.camera)
+    else {
+        request.fail(&mut commands, FitError::MissingCamera);
         return;
     };
+    if deferred_fits.defer_until_loaded(
+        &mut commands,
+        &request,
+        zoom.load_timeout,
+        &queries,
+        zoom.event(),
+    ) {
+        return;
+    }
 
     debug!(
         "ZoomToFit: yaw={:.3} pitch={:.3} current_focus={:.1?} current_radius={:.1} duration_ms={:.0}",
@@ -173,79 +590,33 @@ pub fn on_zoom_to_fit(
         panorbit.target_pitch,
         panorbit.target_focus,
         panorbit.target_radius,
-        duration.as_secs_f32() * 1000.0,
+        request.duration.as_secs_f32() * 1000.0,
     );
 
-    let Some(fit) = prepare_fit_for_target(
-        "ZoomToFit",
-        target,
-        panorbit.target_yaw,
-        panorbit.target_pitch,
-        margin,
-        projection,
+    let inputs = FitInputs::gather(
+        &request,
+        unoccluded,
+        algorithm,
+        strategy,
+        &projection,
+        &projections,
         cam,
-        &mesh_query,
-        &children_query,
-        &global_transform_query,
-        &meshes,
-    ) else {
-        return;
+        &panorbit,
+        &mut queries,
+    );
+    let job = FitJob::Orbit {
+        yaw:   panorbit.target_yaw,
+        pitch: panorbit.target_pitch,
     };
-
-    if duration > Duration::ZERO {
-        // Animated path: use `ToOrbit` to pass orbital params directly, avoiding
-        // gimbal lock from atan2 decomposition at extreme pitch angles.
-        let camera_moves = VecDeque::from([CameraMove::ToOrbit {
-            focus: fit.focus,
-            yaw: panorbit.target_yaw,
-            pitch: panorbit.target_pitch,
-            radius: fit.radius,
-            duration,
-            easing,
-        }]);
-
-        let ctx = ZoomContext {
-            target,
-            margin,
-            duration,
-            easing,
-        };
-
-        // `on_play_animation` handles conflict resolution, `ZoomBegin`, and
-        // `ZoomAnimationMarker` insertion — all in one place after acceptance.
-        commands.trigger(PlayAnimation::new(camera, camera_moves).zoom_context(ctx));
-    } else {
-        // Instant path: snap directly to target — no `PlayAnimation` involved.
-        snap_to_orbit(
-            &mut commands,
-            &mut panorbit,
-            SnapOrbit {
-                focus:  fit.focus,
-                yaw:    None,
-                pitch:  None,
-                radius: fit.radius,
-            },
-            |commands| {
-                commands.trigger(ZoomBegin {
-                    camera,
-                    target,
-                    margin,
-                    duration,
-                    easing,
-                });
-                commands.trigger(ZoomEnd {
-                    camera,
-                    target,
-                    margin,
-                    duration: Duration::ZERO,
-                    easing,
-                });
-            },
-        );
-    }
-
-    // Route fit target updates through a single lifecycle owner.
-    commands.trigger(SetFitTarget::new(camera, target));
+    run_fit(
+        &mut commands,
+        &async_fits,
+        &mut panorbit,
+        &mut projection,
+        request,
+        inputs,
+        job,
+    );
 }
 
 /// Fires `ZoomBegin` and inserts `ZoomAnimationMarker` when the accepted
@@ -258,7 +629,7 @@ fn begin_zoom_if_needed(
     if let Some(ctx) = zoom_context {
         commands.trigger(ZoomBegin {
             camera:   entity,
-            target:   ctx.target,
+            target:   ctx.target.clone(),
             margin:   ctx.margin,
             duration: ctx.duration,
             easing:   ctx.easing,
@@ -286,6 +657,7 @@ pub fn on_play_animation(
     move_list_query: Query<&CameraMoveList>,
     marker_query: Query<&ZoomAnimationMarker>,
     source_marker_query: Query<&AnimationSourceMarker>,
+    waiting_fit_query: Query<(), Or<(With<PendingFit>, With<DeferredFit>)>>,
 ) {
     let entity = start.camera;
     let zoom_context = start.zoom_context.clone();
@@ -303,7 +675,11 @@ pub fn on_play_animation(
 
     let interrupt_behavior = interrupt_behavior.copied().unwrap_or_default();
     let policy = conflict_policy.copied().unwrap_or_default();
-    let has_in_flight = move_list_query.get(entity).is_ok();
+    // A fit still solving in the background or waiting for its target to load counts as in
+    // flight, unless this is its own animation (`poll_pending_fits` and `resolve_deferred_fits`
+    // remove their component first).
+    let has_in_flight =
+        move_list_query.get(entity).is_ok() || waiting_fit_query.get(entity).is_ok();
 
     if has_in_flight {
         match policy {
@@ -315,6 +691,9 @@ pub fn on_play_animation(
                 return;
             },
             AnimationConflictPolicy::LastWins => {
+                commands
+                    .entity(entity)
+                    .remove::<(PendingFit, DeferredFit)>();
                 // Cancel in-flight animation — read source from existing marker
                 let in_flight_source = source_marker_query
                     .get(entity)
@@ -344,7 +723,7 @@ pub fn on_play_animation(
                     commands.entity(entity).remove::<ZoomAnimationMarker>();
                     commands.trigger(ZoomCancelled {
                         camera:   entity,
-                        target:   marker.0.target,
+                        target:   marker.0.target.clone(),
                         margin:   marker.0.margin,
                         duration: marker.0.duration,
                         easing:   marker.0.easing,
@@ -407,81 +786,166 @@ pub fn on_camera_move_list_added(
 
 /// Observer for `SetFitTarget` event - sets the target entity for fit visualization
 pub fn on_set_fit_target(set_target: On<SetFitTarget>, mut commands: Commands) {
-    commands
-        .entity(set_target.camera)
-        .insert(CurrentFitTarget(set_target.target));
+    commands.entity(set_target.camera).insert((
+        CurrentFitTarget(set_target.target.clone()),
+        CurrentFitOptions {
+            margin: set_target.margin,
+            frame:  set_target.frame,
+            anchor: set_target.anchor,
+            volume: set_target.volume,
+            pose:   set_target.pose,
+        },
+    ));
 }
 
 /// Observer for `AnimateToFit` event - animates the camera to a specific orientation
 /// while fitting a target entity in view.
+#[allow(clippy::type_complexity)]
 pub fn on_animate_to_fit(
     event: On<AnimateToFit>,
     mut commands: Commands,
-    mut camera_query: Query<(&mut PanOrbitCamera, &Projection, &Camera)>,
-    mesh_query: Query<&Mesh3d>,
-    children_query: Query<&Children>,
-    global_transform_query: Query<&GlobalTransform>,
-    meshes: Res<Assets<Mesh>>,
+    mut camera_query: Query<(
+        &mut PanOrbitCamera,
+        &mut Projection,
+        &Camera,
+        Option<&UnoccludedViewport>,
+        Option<&FitAlgorithm>,
+        Option<&FitStrategy>,
+    )>,
+    projections: Res<FitProjectionRegistry>,
+    mut queries: FitTargetQueries,
+    async_fits: AsyncFits,
+    deferred_fits: DeferredFits,
 ) {
-    let camera = event.camera;
-    let target = event.target;
-    let yaw = event.yaw;
-    let pitch = event.pitch;
-    let margin = event.margin;
-    let duration = event.duration;
-    let easing = event.easing;
+    let request = FitRequest {
+        camera:      event.camera,
+        target:      event.target.clone(),
+        source:      AnimationSource::AnimateToFit,
+        margin:      event.margin,
+        frame:       event.frame,
+        anchor:      event.anchor,
+        volume:      event.volume,
+        pose:        event.pose,
+        duration:    event.duration,
+        easing:      event.easing,
+        orientation: FitOrientation::Fitted,
+    };
 
-    let Ok((mut panorbit, projection, cam)) = camera_query.get_mut(camera) else {
+    let Ok((mut panorbit, mut projection, cam, unoccluded, algorithm, strategy)) =
+        camera_query.get_mut(request.camera)
+    else {
+        request.fail(&mut commands, FitError::MissingCamera);
         return;
     };
+    if deferred_fits.defer_until_loaded(
+        &mut commands,
+        &request,
+        event.load_timeout,
+        &queries,
+        event.event(),
+    ) {
+        return;
+    }
 
-    let Some(fit) = prepare_fit_for_target(
-        "AnimateToFit",
-        target,
-        yaw,
-        pitch,
-        margin,
-        projection,
+    let inputs = FitInputs::gather(
+        &request,
+        unoccluded,
+        algorithm,
+        strategy,
+        &projection,
+        &projections,
         cam,
-        &mesh_query,
-        &children_query,
-        &global_transform_query,
-        &meshes,
-    ) else {
-        return;
+        &panorbit,
+        &mut queries,
+    );
+    let job = FitJob::Orbit {
+        yaw:   event.yaw,
+        pitch: event.pitch,
     };
+    run_fit(
+        &mut commands,
+        &async_fits,
+        &mut panorbit,
+        &mut projection,
+        request,
+        inputs,
+        job,
+    );
+}
 
-    if duration > Duration::ZERO {
-        let camera_moves = VecDeque::from([CameraMove::ToOrbit {
-            focus: fit.focus,
-            yaw,
-            pitch,
-            radius: fit.radius,
-            duration,
-            easing,
-        }]);
-        commands.trigger(
-            PlayAnimation::new(camera, camera_moves).source(AnimationSource::AnimateToFit),
-        );
-    } else {
-        snap_to_orbit(
-            &mut commands,
-            &mut panorbit,
-            SnapOrbit {
-                focus:  fit.focus,
-                yaw:    Some(yaw),
-                pitch:  Some(pitch),
-                radius: fit.radius,
-            },
-            |commands| {
-                let source = AnimationSource::AnimateToFit;
-                commands.trigger(AnimationBegin { camera, source });
-                commands.trigger(AnimationEnd { camera, source });
-            },
-        );
+/// Observer for `AutoFit` event - animates the camera to the viewing angle chosen by the
+/// event's [`BestView`](crate::BestView) strategy while fitting a target entity in view.
+#[allow(clippy::type_complexity)]
+pub fn on_auto_fit(
+    event: On<AutoFit>,
+    mut commands: Commands,
+    mut camera_query: Query<(
+        &mut PanOrbitCamera,
+        &mut Projection,
+        &Camera,
+        Option<&UnoccludedViewport>,
+        Option<&FitAlgorithm>,
+        Option<&FitStrategy>,
+    )>,
+    projections: Res<FitProjectionRegistry>,
+    mut queries: FitTargetQueries,
+    async_fits: AsyncFits,
+    deferred_fits: DeferredFits,
+) {
+    let request = FitRequest {
+        camera:      event.camera,
+        target:      event.target.clone(),
+        source:      AnimationSource::AutoFit,
+        margin:      event.margin,
+        frame:       event.frame,
+        anchor:      event.anchor,
+        volume:      event.volume,
+        pose:        event.pose,
+        duration:    event.duration,
+        easing:      event.easing,
+        orientation: FitOrientation::Fitted,
+    };
+
+    let Ok((mut panorbit, mut projection, cam, unoccluded, algorithm, strategy)) =
+        camera_query.get_mut(request.camera)
+    else {
+        request.fail(&mut commands, FitError::MissingCamera);
+        return;
+    };
+    if deferred_fits.defer_until_loaded(
+        &mut commands,
+        &request,
+        event.load_timeout,
+        &queries,
+        event.event(),
+    ) {
+        return;
     }
-    // Route fit target updates through a single lifecycle owner.
-    commands.trigger(SetFitTarget::new(camera, target));
+
+    let inputs = FitInputs::gather(
+        &request,
+        unoccluded,
+        algorithm,
+        strategy,
+        &projection,
+        &projections,
+        cam,
+        &panorbit,
+        &mut queries,
+    );
+    let job = FitJob::BestView {
+        view:    event.view,
+        current: (panorbit.target_yaw, panorbit.target_pitch),
+    };
+    run_fit(
+        &mut commands,
+        &async_fits,
+        &mut panorbit,
+        &mut projection,
+        request,
+        inputs,
+        job,
+    );
 }
 
 /// Observer for `LookAt` event — rotates the camera in place to look at a target entity.
@@ -528,11 +992,13 @@ pub fn on_look_at(
         snap_to_orbit(
             &mut commands,
             &mut panorbit,
+            None,
             SnapOrbit {
                 focus: target_pos,
                 yaw: Some(yaw),
                 pitch: Some(pitch),
                 radius,
+                fov: None,
             },
             |commands| {
                 let source = AnimationSource::LookAt;
@@ -546,91 +1012,91 @@ pub fn on_look_at(
 /// Observer for `LookAtAndZoomToFit` event — rotates the camera in place to look at
 /// a target entity and adjusts the radius to frame it, all in one fluid motion.
 /// The yaw and pitch are back-solved from the camera's current world position.
+#[allow(clippy::type_complexity)]
 pub fn on_look_at_and_zoom_to_fit(
     event: On<LookAtAndZoomToFit>,
     mut commands: Commands,
-    mut camera_query: Query<(&mut PanOrbitCamera, &Projection, &Camera, &GlobalTransform)>,
-    mesh_query: Query<&Mesh3d>,
-    children_query: Query<&Children>,
-    global_transform_query: Query<&GlobalTransform>,
-    meshes: Res<Assets<Mesh>>,
+    mut camera_query: Query<(
+        &mut PanOrbitCamera,
+        &mut Projection,
+        &Camera,
+        &GlobalTransform,
+        Option<&UnoccludedViewport>,
+        Option<&FitAlgorithm>,
+        Option<&FitStrategy>,
+    )>,
+    projections: Res<FitProjectionRegistry>,
+    mut queries: FitTargetQueries,
+    async_fits: AsyncFits,
+    deferred_fits: DeferredFits,
 ) {
-    let camera = event.camera;
-    let target = event.target;
-    let margin = event.margin;
-    let duration = event.duration;
-    let easing = event.easing;
+    let mut request = FitRequest {
+        camera:      event.camera,
+        target:      event.target.clone(),
+        source:      AnimationSource::LookAtAndZoomToFit,
+        margin:      event.margin,
+        frame:       event.frame,
+        anchor:      event.anchor,
+        volume:      event.volume,
+        pose:        event.pose,
+        duration:    event.duration,
+        easing:      event.easing,
+        orientation: FitOrientation::Fitted,
+    };
 
-    let Ok((mut panorbit, projection, cam, cam_transform)) = camera_query.get_mut(camera) else {
+    let Ok((mut panorbit, mut projection, cam, cam_transform, unoccluded, algorithm, strategy)) =
+        camera_query.get_mut(request.camera)
+    else {
+        request.fail(&mut commands, FitError::MissingCamera);
         return;
     };
+    if deferred_fits.defer_until_loaded(
+        &mut commands,
+        &request,
+        event.load_timeout,
+        &queries,
+        event.event(),
+    ) {
+        return;
+    }
 
     let cam_pos = cam_transform.translation();
+    request.orientation = FitOrientation::LookFrom(cam_pos);
 
     // Back-solve yaw/pitch from camera's current position relative to the target.
     // We need the target's bounds center for this, so we run the fit calculation
     // with a preliminary yaw/pitch, then refine.
-    let Ok(target_gt) = global_transform_query.get(target) else {
-        warn!("LookAtAndZoomToFit: target {target:?} has no GlobalTransform");
+    let Some(target_pos) = fit_target_translation(&request.target, &queries.global_transform_query)
+    else {
+        request.fail(&mut commands, FitError::NoMesh);
         return;
     };
-    let target_pos = target_gt.translation();
     let (preliminary_yaw, preliminary_pitch, _) = orbital_params_from_offset(cam_pos - target_pos);
 
-    let Some(fit) = prepare_fit_for_target(
-        "LookAtAndZoomToFit",
-        target,
-        preliminary_yaw,
-        preliminary_pitch,
-        margin,
-        projection,
+    let inputs = FitInputs::gather(
+        &request,
+        unoccluded,
+        algorithm,
+        strategy,
+        &projection,
+        &projections,
         cam,
-        &mesh_query,
-        &children_query,
-        &global_transform_query,
-        &meshes,
-    ) else {
-        return;
+        &panorbit,
+        &mut queries,
+    );
+    let job = FitJob::Orbit {
+        yaw:   preliminary_yaw,
+        pitch: preliminary_pitch,
     };
-
-    // Recompute yaw/pitch relative to the fit's focus (bounds center), which may
-    // differ slightly from the raw `GlobalTransform` translation.
-    let (yaw, pitch, _) = orbital_params_from_offset(cam_pos - fit.focus);
-
-    if duration > Duration::ZERO {
-        commands.trigger(
-            PlayAnimation::new(
-                camera,
-                [CameraMove::ToOrbit {
-                    focus: fit.focus,
-                    yaw,
-                    pitch,
-                    radius: fit.radius,
-                    duration,
-                    easing,
-                }],
-            )
-            .source(AnimationSource::LookAtAndZoomToFit),
-        );
-    } else {
-        snap_to_orbit(
-            &mut commands,
-            &mut panorbit,
-            SnapOrbit {
-                focus:  fit.focus,
-                yaw:    Some(yaw),
-                pitch:  Some(pitch),
-                radius: fit.radius,
-            },
-            |commands| {
-                let source = AnimationSource::LookAtAndZoomToFit;
-                commands.trigger(AnimationBegin { camera, source });
-                commands.trigger(AnimationEnd { camera, source });
-            },
-        );
-    }
-
-    commands.trigger(SetFitTarget::new(camera, target));
+    run_fit(
+        &mut commands,
+        &async_fits,
+        &mut panorbit,
+        &mut projection,
+        request,
+        inputs,
+        job,
+    );
 }
 
 /// Observer that restores camera runtime state when `CameraMoveList` is removed.
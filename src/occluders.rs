@@ -0,0 +1,107 @@
+//! Derives each camera's [`UnoccludedViewport`] from `bevy_ui` nodes marked with
+//! [`FitOccluder`].
+
+use bevy::prelude::*;
+use bevy::ui::ComputedUiTargetCamera;
+use bevy::ui::UiGlobalTransform;
+use bevy_panorbit_camera::PanOrbitCamera;
+
+use crate::components::FitOccluder;
+use crate::components::UnoccludedViewport;
+
+/// Recomputes [`UnoccludedViewport`] for every `PanOrbitCamera` from the laid-out rectangles of
+/// the visible [`FitOccluder`] nodes that render to it. Runs after UI layout.
+pub fn update_unoccluded_viewports(
+    mut commands: Commands,
+    mut camera_query: Query<
+        (Entity, &Camera, Option<&mut UnoccludedViewport>),
+        With<PanOrbitCamera>,
+    >,
+    occluder_query: Query<
+        (
+            &ComputedNode,
+            &UiGlobalTransform,
+            &ComputedUiTargetCamera,
+            &InheritedVisibility,
+        ),
+        With<FitOccluder>,
+    >,
+) {
+    for (camera, cam, current) in &mut camera_query {
+        let Some(viewport_size) = cam
+            .physical_viewport_size()
+            .map(|size| size.as_vec2())
+            .filter(|size| size.x > 0.0 && size.y > 0.0)
+        else {
+            continue;
+        };
+
+        // UI layout is in the target camera's physical viewport pixels; node transforms
+        // place the node's center.
+        let occluders = occluder_query
+            .iter()
+            .filter(|(node, _, target, visibility)| {
+                target.get() == Some(camera) && visibility.get() && !node.is_empty()
+            })
+            .map(|(node, transform, _, _)| {
+                Rect::from_center_size(
+                    transform.translation / viewport_size,
+                    node.size() / viewport_size,
+                )
+            });
+        let unoccluded = UnoccludedViewport(unoccluded_rect(occluders));
+
+        match current {
+            Some(mut current) => {
+                current.set_if_neq(unoccluded);
+            },
+            None => {
+                commands.entity(camera).insert(unoccluded);
+            },
+        }
+    }
+}
+
+/// Shrinks the normalized viewport past each occluder, largest first. Each occluder is cut
+/// away from whichever side keeps the most area, so panels docked to an edge inset that edge.
+fn unoccluded_rect(occluders: impl Iterator<Item = Rect>) -> Rect {
+    let area = |rect: &Rect| rect.width() * rect.height();
+    let mut occluders: Vec<Rect> = occluders.collect();
+    occluders.sort_by(|a, b| area(b).total_cmp(&area(a)));
+
+    let mut frame = Rect::new(0.0, 0.0, 1.0, 1.0);
+    for occluder in occluders {
+        let overlap = frame.intersect(occluder);
+        if overlap.is_empty() {
+            continue;
+        }
+        let candidates = [
+            Rect::from_corners(Vec2::new(overlap.max.x, frame.min.y), frame.max),
+            Rect::from_corners(frame.min, Vec2::new(overlap.min.x, frame.max.y)),
+            Rect::from_corners(Vec2::new(frame.min.x, overlap.max.y), frame.max),
+            Rect::from_corners(frame.min, Vec2::new(frame.max.x, overlap.min.y)),
+        ];
+        if let Some(best) = candidates
+            .into_iter()
+            .max_by(|a, b| area(a).total_cmp(&area(b)))
+        {
+            frame = best;
+        }
+    }
+    frame
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn docked_panels_inset_their_edges() {
+        let left_panel = Rect::new(0.0, 0.0, 0.25, 1.0);
+        let toolbar = Rect::new(0.0, 0.9, 1.0, 1.0);
+
+        let frame = unoccluded_rect([left_panel, toolbar].into_iter());
+
+        assert_eq!(frame, Rect::new(0.25, 0.0, 1.0, 0.9));
+    }
+}
@@ -0,0 +1,200 @@
+//! Projection abstraction used by the fit solvers and the visualization.
+//!
+//! [`FitProjection`] describes how a camera projection maps camera-space points to normalized
+//! screen space and how that mapping changes with the `PanOrbitCamera` radius. Perspective and
+//! orthographic projections implement it out of the box; `Projection::Custom` projections opt in
+//! by implementing it and registering their type with
+//! [`register_fit_projection`](FitProjectionAppExt::register_fit_projection).
+
+use core::fmt::Debug;
+
+use bevy::camera::CameraProjection;
+use bevy::camera::CustomProjection;
+use bevy::prelude::*;
+
+use crate::support::MIN_VISIBLE_DEPTH;
+
+/// A camera projection the fit solvers can frame targets with.
+///
+/// Fits work in *normalized* screen coordinates: the visible area spans `±half_extents` around
+/// the origin, and a point's normalized coordinates come from [`project_normalized`]. For a
+/// symmetric perspective frustum these are `(x / depth, y / depth)`; an off-axis frustum shifts
+/// them so its visible window is centered on the origin.
+///
+/// Camera-space points passed to these methods have `x` pointing right, `y` up and `z` the
+/// depth in front of the camera.
+///
+/// ```ignore
+/// impl FitProjection for OffAxisProjection {
+///     fn project_normalized(&self, view_point: Vec3) -> Option<Vec2> {
+///         (view_point.z > self.near).then(|| view_point.xy() / view_point.z - self.window_center())
+///     }
+///
+///     fn unproject_normalized(&self, normalized: Vec2, depth: f32) -> Vec3 {
+///         ((normalized + self.window_center()) * depth).extend(depth)
+///     }
+///
+///     fn half_extents(&self, _viewport_size: Option<Vec2>) -> Option<Vec2> {
+///         Some(self.window_half_size())
+///     }
+/// }
+///
+/// app.register_fit_projection::<OffAxisProjection>();
+/// ```
+///
+/// [`project_normalized`]: FitProjection::project_normalized
+pub trait FitProjection: Send + Sync + 'static {
+    /// Projects a camera-space point to normalized screen coordinates, or returns `None` if the
+    /// projection cannot see it (e.g. a point behind a perspective camera).
+    fn project_normalized(&self, view_point: Vec3) -> Option<Vec2>;
+
+    /// Inverse of [`project_normalized`](FitProjection::project_normalized): the camera-space
+    /// point at `depth` that projects to `normalized`. Used to place visualization gizmos.
+    fn unproject_normalized(&self, normalized: Vec2, depth: f32) -> Vec3;
+
+    /// Half extents of the visible area in normalized units at the projection's current zoom,
+    /// for a viewport of `viewport_size` logical pixels when known. Returns `None` when they
+    /// cannot be determined, e.g. a zero-height area.
+    fn half_extents(&self, viewport_size: Option<Vec2>) -> Option<Vec2>;
+
+    /// Factor the [`half_extents`](FitProjection::half_extents) scale by when `PanOrbitCamera`
+    /// is at `radius`. Defaults to `1.0`, for projections where the radius only moves the
+    /// camera.
+    fn radius_extent_scale(&self, _radius: f32) -> f32 { 1.0 }
+
+    /// Distance from the focus to the camera when `PanOrbitCamera` is at `radius`. Defaults to
+    /// the radius itself.
+    fn camera_distance(&self, radius: f32) -> f32 { radius }
+
+    /// Depth of the projection's near clipping plane, for projections whose camera distance grows
+    /// with the radius. Fits back the camera out until every point of the target is deeper, so
+    /// the target is never clipped. Defaults to `None`, for projections that don't clip or
+    /// whose clipping the radius cannot change.
+    fn near_plane(&self) -> Option<f32> { None }
+
+    /// Whether normalized coordinates are independent of depth, as in orthographic projection.
+    /// Focus centering then shifts the camera 1:1 instead of scaling by depth.
+    fn is_orthographic(&self) -> bool { false }
+
+    /// Whether normalized coordinates are exactly `(x, y) / depth` (or `(x, y)` when
+    /// orthographic), so [`FitAlgorithm::Analytic`](crate::FitAlgorithm::Analytic) can solve
+    /// the fit in closed form. Defaults to `false`, which falls back to the binary search.
+    fn supports_analytic_fit(&self) -> bool { false }
+}
+
+impl FitProjection for PerspectiveProjection {
+    fn project_normalized(&self, view_point: Vec3) -> Option<Vec2> {
+        (view_point.z > MIN_VISIBLE_DEPTH).then(|| view_point.xy() / view_point.z)
+    }
+
+    fn unproject_normalized(&self, normalized: Vec2, depth: f32) -> Vec3 {
+        (normalized * depth).extend(depth)
+    }
+
+    /// Uses the viewport's aspect ratio when available, falling back to
+    /// `PerspectiveProjection::aspect_ratio`.
+    fn half_extents(&self, viewport_size: Option<Vec2>) -> Option<Vec2> {
+        let aspect_ratio = viewport_size.map_or(self.aspect_ratio, |size| size.x / size.y);
+        let half_tan_vfov = (self.fov * 0.5).tan();
+        Some(Vec2::new(half_tan_vfov * aspect_ratio, half_tan_vfov))
+    }
+
+    fn near_plane(&self) -> Option<f32> { Some(self.near) }
+
+    fn supports_analytic_fit(&self) -> bool { true }
+}
+
+impl FitProjection for OrthographicProjection {
+    fn project_normalized(&self, view_point: Vec3) -> Option<Vec2> { Some(view_point.xy()) }
+
+    /// Normalized coordinates are already in world units, so `depth` only sets the forward
+    /// component.
+    fn unproject_normalized(&self, normalized: Vec2, depth: f32) -> Vec3 {
+        normalized.extend(depth)
+    }
+
+    fn half_extents(&self, _viewport_size: Option<Vec2>) -> Option<Vec2> {
+        (self.area.height().abs() >= f32::EPSILON).then(|| self.area.size() * 0.5)
+    }
+
+    /// `PanOrbitCamera` maps `radius` → `OrthographicProjection::scale`, and the area scales
+    /// with it.
+    fn radius_extent_scale(&self, radius: f32) -> f32 {
+        if self.scale.abs() > f32::EPSILON {
+            radius / self.scale
+        } else {
+            1.0
+        }
+    }
+
+    /// The camera stays at a fixed distance from the focus; `PanOrbitCamera` sets this to
+    /// `(near + far) / 2.0`.
+    fn camera_distance(&self, _radius: f32) -> f32 { (self.near + self.far) * 0.5 }
+
+    fn is_orthographic(&self) -> bool { true }
+
+    fn supports_analytic_fit(&self) -> bool { self.scale.abs() > f32::EPSILON }
+}
+
+/// Looks up a registered [`FitProjection`] inside a `CustomProjection`.
+type CustomProjectionResolver = for<'a> fn(&'a CustomProjection) -> Option<&'a dyn FitProjection>;
+
+/// `Projection::Custom` types that implement [`FitProjection`], registered with
+/// [`register_fit_projection`](FitProjectionAppExt::register_fit_projection).
+///
+/// Fits on a camera whose custom projection is not registered fail with
+/// [`FitError::UnsupportedProjection`](crate::FitError::UnsupportedProjection).
+#[derive(Resource, Default, Clone)]
+pub struct FitProjectionRegistry {
+    custom: Vec<CustomProjectionResolver>,
+}
+
+impl FitProjectionRegistry {
+    /// Registers a custom projection type.
+    pub fn register<P>(&mut self)
+    where
+        P: FitProjection + CameraProjection + Debug + Clone,
+    {
+        fn resolve<P>(custom: &CustomProjection) -> Option<&dyn FitProjection>
+        where
+            P: FitProjection + CameraProjection + Debug + Clone,
+        {
+            custom
+                .get::<P>()
+                .map(|projection| projection as &dyn FitProjection)
+        }
+        self.custom.push(resolve::<P>);
+    }
+
+    /// Returns the [`FitProjection`] behind a camera's `Projection`, or `None` for a custom
+    /// projection whose type was not registered.
+    pub fn resolve<'a>(&self, projection: &'a Projection) -> Option<&'a dyn FitProjection> {
+        match projection {
+            Projection::Perspective(perspective) => Some(perspective),
+            Projection::Orthographic(orthographic) => Some(orthographic),
+            Projection::Custom(custom) => self.custom.iter().find_map(|resolve| resolve(custom)),
+        }
+    }
+}
+
+/// Registers `Projection::Custom` types with the [`FitProjectionRegistry`].
+pub trait FitProjectionAppExt {
+    /// Lets fits and the visualization frame targets with cameras using the custom projection
+    /// `P`.
+    fn register_fit_projection<P>(&mut self) -> &mut Self
+    where
+        P: FitProjection + CameraProjection + Debug + Clone;
+}
+
+impl FitProjectionAppExt for App {
+    fn register_fit_projection<P>(&mut self) -> &mut Self
+    where
+        P: FitProjection + CameraProjection + Debug + Clone,
+    {
+        self.init_resource::<FitProjectionRegistry>();
+        self.world_mut()
+            .resource_mut::<FitProjectionRegistry>()
+            .register::<P>();
+        self
+    }
+}
@@ -0,0 +1,222 @@
+//! Dry-run fits with [`FitSolver`]: where a fit would put the camera, without moving it.
+
+use bevy::ecs::system::SystemParam;
+use bevy::prelude::*;
+use bevy_panorbit_camera::PanOrbitCamera;
+
+use crate::components::FitAlgorithm;
+use crate::components::FitStrategy;
+use crate::components::UnoccludedViewport;
+use crate::events::FitAnchor;
+use crate::events::FitFrame;
+use crate::events::FitMargins;
+use crate::events::FitPose;
+use crate::events::FitTarget;
+use crate::events::FitVolume;
+use crate::fit::FitError;
+use crate::fit::FitParams;
+use crate::fit::FitSolution;
+use crate::observers::fit_points;
+use crate::projection::FitProjection;
+use crate::projection::FitProjectionRegistry;
+use crate::support::FitTargetQueries;
+use crate::support::ScreenSpaceBounds;
+use crate::support::extract_fit_target_vertices;
+
+/// Options for a [`FitSolver`] dry run. Defaults match [`ZoomToFit::new`](crate::ZoomToFit::new)
+/// from the camera's current orientation.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct FitOptions {
+    /// Yaw to fit from, or `None` for the camera's current target yaw.
+    pub yaw:    Option<f32>,
+    /// Pitch to fit from, or `None` for the camera's current target pitch.
+    pub pitch:  Option<f32>,
+    pub margin: FitMargins,
+    pub frame:  FitFrame,
+    pub anchor: FitAnchor,
+    pub volume: FitVolume,
+    pub pose:   FitPose,
+}
+
+impl Default for FitOptions {
+    fn default() -> Self {
+        Self {
+            yaw:    None,
+            pitch:  None,
+            margin: FitMargins::uniform(0.1),
+            frame:  FitFrame::Viewport,
+            anchor: FitAnchor::Center,
+            volume: FitVolume::Vertices,
+            pose:   FitPose::BindPose,
+        }
+    }
+}
+
+impl FitOptions {
+    pub const fn yaw(mut self, yaw: f32) -> Self {
+        self.yaw = Some(yaw);
+        self
+    }
+
+    pub const fn pitch(mut self, pitch: f32) -> Self {
+        self.pitch = Some(pitch);
+        self
+    }
+
+    pub fn margin(mut self, margin: impl Into<FitMargins>) -> Self {
+        self.margin = margin.into();
+        self
+    }
+
+    pub const fn frame(mut self, frame: FitFrame) -> Self {
+        self.frame = frame;
+        self
+    }
+
+    pub const fn anchor(mut self, anchor: FitAnchor) -> Self {
+        self.anchor = anchor;
+        self
+    }
+
+    pub const fn volume(mut self, volume: FitVolume) -> Self {
+        self.volume = volume;
+        self
+    }
+
+    pub const fn pose(mut self, pose: FitPose) -> Self {
+        self.pose = pose;
+        self
+    }
+}
+
+/// A dry-run fit: the camera pose a fit would produce and where the target would land on
+/// screen from there.
+#[derive(Debug, Clone)]
+pub struct FitPreview {
+    pub solution: FitSolution,
+    /// The target's projected bounds with the camera at `solution`.
+    pub bounds:   ScreenSpaceBounds,
+}
+
+/// Computes fits without moving the camera, for previews, choosing between targets or
+/// pre-computing poses.
+///
+/// Fits are solved exactly like [`ZoomToFit`](crate::ZoomToFit), honouring the camera's
+/// [`FitAlgorithm`], [`FitStrategy`], [`FitFilter`](crate::FitFilter), [`UnoccludedViewport`]
+/// and `PanOrbitCamera` limits:
+///
+/// ```ignore
+/// fn preview_fit(mut solver: FitSolver, camera: Single<Entity, With<PanOrbitCamera>>, selected: Res<Selected>) {
+///     if let Ok(preview) = solver.solve(*camera, selected.0, FitOptions::default().margin(0.2)) {
+///         info!("fit would orbit {:?} at radius {}", preview.solution.focus, preview.solution.radius);
+///     }
+/// }
+/// ```
+///
+/// `FitSolver` reads `PanOrbitCamera`, so a system using it cannot also query
+/// `&mut PanOrbitCamera`; solve in one system and apply the result in another.
+#[derive(SystemParam)]
+#[allow(clippy::type_complexity)]
+pub struct FitSolver<'w, 's> {
+    cameras: Query<
+        'w,
+        's,
+        (
+            &'static PanOrbitCamera,
+            &'static Camera,
+            &'static Projection,
+            Option<&'static UnoccludedViewport>,
+            Option<&'static FitAlgorithm>,
+            Option<&'static FitStrategy>,
+        ),
+    >,
+    projections: Res<'w, FitProjectionRegistry>,
+    queries:     FitTargetQueries<'w, 's>,
+}
+
+impl FitSolver<'_, '_> {
+    /// Solves a fit of `target` for `camera` with `options`, returning the same errors a
+    /// [`FitFailed`](crate::FitFailed) would carry.
+    pub fn solve(
+        &mut self,
+        camera: Entity,
+        target: impl Into<FitTarget>,
+        options: FitOptions,
+    ) -> Result<FitPreview, FitError> {
+        let (panorbit, cam, projection, unoccluded, algorithm, strategy) = self
+            .cameras
+            .get(camera)
+            .map_err(|_| FitError::MissingCamera)?;
+        let fit_projection = self
+            .projections
+            .resolve(projection)
+            .ok_or(FitError::UnsupportedProjection)?;
+        let (vertices, geometric_center) = extract_fit_target_vertices(
+            &target.into(),
+            camera,
+            options.volume,
+            options.pose,
+            &mut self.queries,
+        )?;
+        let params = FitParams {
+            yaw:       options.yaw.unwrap_or(panorbit.target_yaw),
+            pitch:     options.pitch.unwrap_or(panorbit.target_pitch),
+            margins:   options.margin,
+            frame:     options.frame.resolve_unoccluded(unoccluded.map(|u| u.0)),
+            anchor:    options.anchor,
+            algorithm: algorithm.copied().unwrap_or_default(),
+            strategy:  strategy.copied().unwrap_or_default(),
+            fallback:  self
+                .queries
+                .fallback_query
+                .get(camera)
+                .copied()
+                .unwrap_or_default(),
+        };
+
+        let solution = fit_points(
+            &vertices,
+            geometric_center,
+            &params,
+            projection,
+            fit_projection,
+            cam,
+            panorbit,
+        )?;
+        let bounds = solution_bounds(&vertices, &solution, projection, fit_projection, cam)
+            .ok_or(FitError::PointsBehindCamera)?;
+        Ok(FitPreview { solution, bounds })
+    }
+}
+
+/// Projects `points` from the camera pose in `solution`, with the solved field of view for
+/// field-of-view fits.
+fn solution_bounds(
+    points: &[Vec3],
+    solution: &FitSolution,
+    projection: &Projection,
+    fit_projection: &dyn FitProjection,
+    camera: &Camera,
+) -> Option<ScreenSpaceBounds> {
+    let solved_lens;
+    let fit_projection = match (solution.fov, projection) {
+        (Some(fov), Projection::Perspective(perspective)) => {
+            solved_lens = PerspectiveProjection {
+                fov,
+                ..perspective.clone()
+            };
+            &solved_lens as &dyn FitProjection
+        },
+        _ => fit_projection,
+    };
+
+    let half_extents = fit_projection.half_extents(camera.logical_viewport_size())?
+        * fit_projection.radius_extent_scale(solution.radius);
+    let rot = Quat::from_euler(EulerRot::YXZ, solution.yaw, -solution.pitch, 0.0);
+    let cam_pos =
+        solution.focus + rot * Vec3::new(0.0, 0.0, fit_projection.camera_distance(solution.radius));
+    let cam_global = GlobalTransform::from(Transform::from_translation(cam_pos).with_rotation(rot));
+
+    ScreenSpaceBounds::from_points(points, &cam_global, fit_projection, half_extents)
+        .map(|(bounds, _)| bounds)
+}
@@ -1,6 +1,31 @@
 //! Shared utility functions used across multiple modules.
 
+use bevy::camera::visibility::RenderLayers;
+use bevy::ecs::system::SystemParam;
+use bevy::math::bounding::Aabb3d;
+use bevy::math::bounding::BoundingSphere;
+use bevy::math::bounding::BoundingVolume;
+use bevy::mesh::PrimitiveTopology;
+use bevy::mesh::VertexAttributeValues;
+use bevy::mesh::morph::MeshMorphWeights;
+use bevy::mesh::morph::MorphAttributes;
+use bevy::mesh::skinning::SkinnedMesh;
+use bevy::mesh::skinning::SkinnedMeshInverseBindposes;
 use bevy::prelude::*;
+use bevy_panorbit_camera::PanOrbitCamera;
+
+use crate::components::FitFallback;
+use crate::components::FitFilter;
+use crate::components::FitIgnore;
+use crate::convex_hull::convex_hull_3d;
+use crate::events::FitPose;
+use crate::events::FitTarget;
+use crate::events::FitVolume;
+use crate::events::MeshRegion;
+use crate::fit::FitError;
+use crate::geometry::FitGeometryPoints;
+use crate::mesh_cache::MeshFitCache;
+use crate::projection::FitProjection;
 
 // ============================================================================
 // Camera basis
@@ -27,6 +52,59 @@ impl CameraBasis {
     }
 }
 
+// ============================================================================
+// Camera limits
+// ============================================================================
+
+/// The yaw, pitch and zoom limits configured on a `PanOrbitCamera`, applied the way it applies
+/// them. Zoom limits bound the radius, which is the scale for orthographic cameras.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct OrbitLimits {
+    pub yaw_lower:   Option<f32>,
+    pub yaw_upper:   Option<f32>,
+    pub pitch_lower: Option<f32>,
+    pub pitch_upper: Option<f32>,
+    pub zoom_lower:  f32,
+    pub zoom_upper:  Option<f32>,
+}
+
+impl OrbitLimits {
+    pub const fn from_camera(camera: &PanOrbitCamera) -> Self {
+        Self {
+            yaw_lower:   camera.yaw_lower_limit,
+            yaw_upper:   camera.yaw_upper_limit,
+            pitch_lower: camera.pitch_lower_limit,
+            pitch_upper: camera.pitch_upper_limit,
+            zoom_lower:  camera.zoom_lower_limit,
+            zoom_upper:  camera.zoom_upper_limit,
+        }
+    }
+
+    pub fn clamp_yaw(&self, yaw: f32) -> f32 { clamp_optional(yaw, self.yaw_lower, self.yaw_upper) }
+
+    pub fn clamp_pitch(&self, pitch: f32) -> f32 {
+        clamp_optional(pitch, self.pitch_lower, self.pitch_upper)
+    }
+
+    pub fn clamp_radius(&self, radius: f32) -> f32 {
+        clamp_optional(radius, Some(self.zoom_lower), self.zoom_upper)
+    }
+
+    /// Clamps the camera's target yaw, pitch and radius in place, so animations stop at the
+    /// limits instead of running past them and snapping back.
+    pub fn clamp_targets(&self, camera: &mut PanOrbitCamera) {
+        camera.target_yaw = self.clamp_yaw(camera.target_yaw);
+        camera.target_pitch = self.clamp_pitch(camera.target_pitch);
+        camera.target_radius = self.clamp_radius(camera.target_radius);
+    }
+}
+
+/// Clamps `value` to whichever bounds are set, lower first, like `PanOrbitCamera` does.
+fn clamp_optional(value: f32, lower: Option<f32>, upper: Option<f32>) -> f32 {
+    let value = lower.map_or(value, |lower| value.max(lower));
+    upper.map_or(value, |upper| value.min(upper))
+}
+
 // ============================================================================
 // Projection utilities
 // ============================================================================
@@ -35,78 +113,20 @@ impl CameraBasis {
 /// Points at or below this depth are treated as behind the camera in perspective projection.
 pub const MIN_VISIBLE_DEPTH: f32 = 0.1;
 
-/// Projection-derived parameters for screen-space normalization.
-/// Consolidates the extraction of half extents and projection type from a `Projection`.
-pub struct ProjectionParams {
-    /// Half visible extent in x (perspective: half_tan_hfov, ortho: area.width()/2)
-    pub half_extent_x: f32,
-    /// Half visible extent in y (perspective: half_tan_vfov, ortho: area.height()/2)
-    pub half_extent_y: f32,
-    /// Whether this uses orthographic projection
-    pub is_ortho:      bool,
-}
-
-impl ProjectionParams {
-    /// Extracts projection parameters from a `Projection` and viewport aspect ratio.
-    /// Returns `None` for unsupported projection variants.
-    pub fn from_projection(projection: &Projection, viewport_aspect: f32) -> Option<Self> {
-        let is_ortho = matches!(projection, Projection::Orthographic(_));
-        let (half_extent_x, half_extent_y) = match projection {
-            Projection::Perspective(p) => {
-                let half_tan_vfov = (p.fov * 0.5).tan();
-                (half_tan_vfov * viewport_aspect, half_tan_vfov)
-            },
-            Projection::Orthographic(o) => (o.area.width() * 0.5, o.area.height() * 0.5),
-            _ => return None,
-        };
-        Some(Self {
-            half_extent_x,
-            half_extent_y,
-            is_ortho,
-        })
-    }
-}
-
-/// Projects a world-space point to normalized screen coordinates.
+/// Projects a world-space point to normalized screen coordinates through `projection`.
 ///
-/// Returns `(norm_x, norm_y, depth)` or `None` if the point is behind the camera
-/// (perspective only — orthographic points are always valid).
-pub fn project_point(point: Vec3, cam: &CameraBasis, is_ortho: bool) -> Option<(f32, f32, f32)> {
+/// Returns `(norm_x, norm_y, depth)` or `None` if the projection cannot see the point (for
+/// perspective, a point behind the camera).
+pub fn project_point(
+    point: Vec3,
+    cam: &CameraBasis,
+    projection: &dyn FitProjection,
+) -> Option<(f32, f32, f32)> {
     let relative = point - cam.pos;
     let depth = relative.dot(cam.forward);
-    if !is_ortho && depth <= MIN_VISIBLE_DEPTH {
-        return None;
-    }
-    let x = relative.dot(cam.right);
-    let y = relative.dot(cam.up);
-    let (norm_x, norm_y) = if is_ortho {
-        (x, y)
-    } else {
-        (x / depth, y / depth)
-    };
-    Some((norm_x, norm_y, depth))
-}
-
-/// Extracts the aspect ratio from a `Projection`, using `viewport_size` for
-/// perspective when available, falling back to `PerspectiveProjection::aspect_ratio`.
-///
-/// Returns `None` for orthographic projections with zero-height area or unknown
-/// projection variants.
-pub fn projection_aspect_ratio(
-    projection: &Projection,
-    viewport_size: Option<Vec2>,
-) -> Option<f32> {
-    match projection {
-        Projection::Perspective(p) => Some(viewport_size.map_or(p.aspect_ratio, |s| s.x / s.y)),
-        Projection::Orthographic(o) => {
-            let area = o.area;
-            if area.height().abs() < f32::EPSILON {
-                return None;
-            }
-            Some(area.width() / area.height())
-        },
-        _ => None,
-    }
+    let view_point = Vec3::new(relative.dot(cam.right), relative.dot(cam.up), depth);
+    let normalized = projection.project_normalized(view_point)?;
+    Some((normalized.x, normalized.y, depth))
 }
 
 // ============================================================================
@@ -155,19 +175,19 @@ pub struct ScreenSpaceBounds {
 }
 
 impl ScreenSpaceBounds {
-    /// Projects world-space points to normalized screen space and computes margins.
-    /// Returns `None` if any point is behind the camera (perspective only).
-    pub fn from_points(
+    /// Projects world-space points to normalized screen space and computes margins from the
+    /// visible area's `half_extents`. Returns `None` if the projection cannot see any point
+    /// (e.g. behind a perspective camera).
+    pub(crate) fn from_points(
         points: &[Vec3],
         cam_global: &GlobalTransform,
-        projection: &Projection,
-        viewport_aspect: f32,
+        projection: &dyn FitProjection,
+        half_extents: Vec2,
     ) -> Option<(Self, PointDepths)> {
-        let ProjectionParams {
-            half_extent_x,
-            half_extent_y,
-            is_ortho,
-        } = ProjectionParams::from_projection(projection, viewport_aspect)?;
+        let Vec2 {
+            x: half_extent_x,
+            y: half_extent_y,
+        } = half_extents;
 
         let cam = CameraBasis::from_global_transform(cam_global);
 
@@ -183,7 +203,7 @@ impl ScreenSpaceBounds {
         let mut depth_sum = 0.0_f32;
 
         for point in points {
-            let (norm_x, norm_y, depth) = project_point(*point, &cam, is_ortho)?;
+            let (norm_x, norm_y, depth) = project_point(*point, &cam, projection)?;
 
             #[cfg(feature = "visualization")]
             {
@@ -252,58 +272,694 @@ impl ScreenSpaceBounds {
 // Mesh utilities
 // ============================================================================
 
-/// Extracts world-space vertex positions from all meshes on an entity and its descendants.
-/// Returns `(vertices, geometric_center)` where `geometric_center` is the root entity's
-/// `GlobalTransform` translation.
-pub fn extract_mesh_vertices(
-    entity: Entity,
-    children_query: &Query<&Children>,
-    mesh_query: &Query<&Mesh3d>,
-    global_transform_query: &Query<&GlobalTransform>,
-    meshes: &Assets<Mesh>,
-) -> Option<(Vec<Vec3>, Vec3)> {
-    let mesh_entities: Vec<Entity> = std::iter::once(entity)
-        .chain(children_query.iter_descendants(entity))
-        .filter(|e| mesh_query.get(*e).is_ok())
-        .collect();
+/// Queries and assets used to extract fit points from entity targets.
+///
+/// The skinning and morph target assets are optional so fits still work in apps that never
+/// register them; [`FitPose::Animated`] then falls back to the bind pose.
+#[derive(SystemParam)]
+pub struct FitTargetQueries<'w, 's> {
+    pub mesh_query:             Query<'w, 's, &'static Mesh3d>,
+    pub children_query:         Query<'w, 's, &'static Children>,
+    pub ignore_query:           Query<'w, 's, (), With<FitIgnore>>,
+    pub visibility_query:       Query<'w, 's, &'static InheritedVisibility>,
+    pub render_layers_query:    Query<'w, 's, &'static RenderLayers>,
+    pub filter_query:           Query<'w, 's, &'static FitFilter>,
+    pub fallback_query:         Query<'w, 's, &'static FitFallback>,
+    pub geometry_query:         Query<'w, 's, Ref<'static, FitGeometryPoints>>,
+    pub global_transform_query: Query<'w, 's, &'static GlobalTransform>,
+    pub skinned_mesh_query:     Query<'w, 's, &'static SkinnedMesh>,
+    pub morph_weights_query:    Query<'w, 's, &'static MeshMorphWeights>,
+    pub meshes:                 Res<'w, Assets<Mesh>>,
+    pub inverse_bindposes:      Option<Res<'w, Assets<SkinnedMeshInverseBindposes>>>,
+    pub images:                 Option<Res<'w, Assets<Image>>>,
+    pub cache:                  ResMut<'w, MeshFitCache>,
+}
 
-    if mesh_entities.is_empty() {
-        return None;
+/// Which meshes under an entity target contribute fit points, resolved once per fit from the
+/// camera's [`FitFilter`].
+struct MeshFilter {
+    visible_only:  bool,
+    /// The camera's layers, when meshes must share one with it.
+    render_layers: Option<RenderLayers>,
+}
+
+impl MeshFilter {
+    fn for_camera(camera: Entity, queries: &FitTargetQueries) -> Self {
+        let filter = queries
+            .filter_query
+            .get(camera)
+            .copied()
+            .unwrap_or_default();
+        Self {
+            visible_only:  filter.visible_only,
+            render_layers: filter.render_layers.then(|| {
+                queries
+                    .render_layers_query
+                    .get(camera)
+                    .cloned()
+                    .unwrap_or_default()
+            }),
+        }
     }
 
+    fn includes(&self, entity: Entity, queries: &FitTargetQueries) -> bool {
+        if self.visible_only
+            && queries
+                .visibility_query
+                .get(entity)
+                .is_ok_and(|visibility| !visibility.get())
+        {
+            return false;
+        }
+        self.render_layers.as_ref().is_none_or(|camera_layers| {
+            queries.render_layers_query.get(entity).map_or_else(
+                |_| camera_layers.intersects(&RenderLayers::default()),
+                |layers| camera_layers.intersects(layers),
+            )
+        })
+    }
+
+    /// Returns the entities with a `Mesh3d` or [`FitGeometry`](crate::FitGeometry) points at or
+    /// under `root` that pass this filter, skipping [`FitIgnore`] subtrees.
+    fn mesh_entities(&self, root: Entity, queries: &FitTargetQueries) -> Vec<Entity> {
+        let mut mesh_entities = Vec::new();
+        let mut pending = vec![root];
+        while let Some(entity) = pending.pop() {
+            if queries.ignore_query.contains(entity) {
+                continue;
+            }
+            if let Ok(children) = queries.children_query.get(entity) {
+                pending.extend(children.iter().rev());
+            }
+            if (queries.mesh_query.contains(entity) || queries.geometry_query.contains(entity))
+                && self.includes(entity, queries)
+            {
+                mesh_entities.push(entity);
+            }
+        }
+        mesh_entities
+    }
+}
+
+/// Extracts world-space fit points from all meshes on an entity and its descendants: every
+/// vertex, or the reduced per-mesh points for the requested [`FitVolume`]. Local-space points
+/// come from the [`MeshFitCache`], so only the `GlobalTransform` is applied per call.
+/// Points from [`FitGeometry`](crate::FitGeometry) components are reduced and transformed the
+/// same way, per entity. Meshes under [`FitIgnore`] and those the camera's [`FitFilter`] excludes
+/// are left out. With [`FitPose::Animated`], skinned and morphed meshes are posed on the CPU first
+/// and reduced in world space instead.
+/// Returns `(vertices, geometric_center)` where `geometric_center` is the root entity's
+/// `GlobalTransform` translation. Meshes that are still loading or have no positions are
+/// skipped; if none contribute, the error explains why.
+fn extract_mesh_vertices(
+    entity: Entity,
+    filter: &MeshFilter,
+    volume: FitVolume,
+    pose: FitPose,
+    queries: &mut FitTargetQueries,
+) -> Result<(Vec<Vec3>, Vec3), FitError> {
+    let mesh_entities = filter.mesh_entities(entity, queries);
+
     let mut all_vertices = Vec::new();
+    let mut error = FitError::NoMesh;
 
     for mesh_entity in &mesh_entities {
-        let Ok(mesh3d) = mesh_query.get(*mesh_entity) else {
+        if let Ok(provided) = queries.geometry_query.get(*mesh_entity)
+            && let Ok(global_transform) = queries.global_transform_query.get(*mesh_entity)
+        {
+            all_vertices.extend(
+                queries
+                    .cache
+                    .geometry_points(*mesh_entity, &provided, volume)
+                    .iter()
+                    .map(|pos| global_transform.transform_point(*pos)),
+            );
+        }
+        let Ok(mesh3d) = queries.mesh_query.get(*mesh_entity) else {
             continue;
         };
-        let Some(mesh) = meshes.get(&mesh3d.0) else {
+        let Some(mesh) = queries.meshes.get(&mesh3d.0) else {
+            error = more_specific_error(error, FitError::MeshNotLoaded);
             continue;
         };
-        let Ok(global_transform) = global_transform_query.get(*mesh_entity) else {
+        let Ok(global_transform) = queries.global_transform_query.get(*mesh_entity) else {
             continue;
         };
-        let Some(positions) = mesh
-            .attribute(Mesh::ATTRIBUTE_POSITION)
-            .and_then(|a| a.as_float3())
-        else {
+        if pose == FitPose::Animated
+            && let Some(posed) = posed_mesh_points(*mesh_entity, mesh, global_transform, queries)
+        {
+            all_vertices.extend(reduce_points(posed, volume));
+            continue;
+        }
+        let Some(local_points) = queries.cache.local_points(mesh3d.id(), mesh, volume) else {
+            error = more_specific_error(error, FitError::NoPositionAttribute);
             continue;
         };
 
         all_vertices.extend(
-            positions
+            local_points
                 .iter()
-                .map(|pos| global_transform.transform_point(Vec3::from_array(*pos))),
+                .map(|pos| global_transform.transform_point(*pos)),
         );
     }
 
     if all_vertices.is_empty() {
-        return None;
+        return Err(error);
     }
 
-    let geometric_center = global_transform_query
+    let geometric_center = queries
+        .global_transform_query
         .get(entity)
         .map_or(Vec3::ZERO, |gt| gt.translation());
 
-    Some((all_vertices, geometric_center))
+    Ok((all_vertices, geometric_center))
+}
+
+/// Extracts the world-space positions `region` selects from the `Mesh3d` on `entity`, in the
+/// requested `pose` and reduced according to `volume`. Returns `(vertices, geometric_center)`
+/// where `geometric_center` is the centroid of the selected positions. Vertices are selected in
+/// the bind pose (a [`MeshRegion::LocalAabb`] tests bind-pose positions) and then follow the
+/// mesh's skinning and morph targets with [`FitPose::Animated`]. [`FitIgnore`] and the camera's
+/// [`FitFilter`] do not apply: the region names its mesh explicitly.
+fn extract_region_vertices(
+    entity: Entity,
+    region: &MeshRegion,
+    volume: FitVolume,
+    pose: FitPose,
+    queries: &FitTargetQueries,
+) -> Result<(Vec<Vec3>, Vec3), FitError> {
+    let mesh3d = queries
+        .mesh_query
+        .get(entity)
+        .map_err(|_| FitError::NoMesh)?;
+    let mesh = queries
+        .meshes
+        .get(&mesh3d.0)
+        .ok_or(FitError::MeshNotLoaded)?;
+    let global_transform = queries
+        .global_transform_query
+        .get(entity)
+        .map_err(|_| FitError::NoMesh)?;
+    let positions = mesh
+        .attribute(Mesh::ATTRIBUTE_POSITION)
+        .and_then(|a| a.as_float3())
+        .ok_or(FitError::NoPositionAttribute)?;
+    let in_mesh = |index: &usize| *index < positions.len();
+
+    let selected: Vec<usize> = match region {
+        MeshRegion::Vertices(indices) => indices
+            .iter()
+            .map(|index| *index as usize)
+            .filter(in_mesh)
+            .collect(),
+        MeshRegion::Triangles(range) => {
+            if mesh.primitive_topology() != PrimitiveTopology::TriangleList {
+                return Err(FitError::NoMesh);
+            }
+            let start = range.start as usize * 3;
+            let count = (range.end as usize * 3).saturating_sub(start);
+            match mesh.indices() {
+                Some(indices) => indices
+                    .iter()
+                    .skip(start)
+                    .take(count)
+                    .filter(in_mesh)
+                    .collect(),
+                None => (start..start + count).take_while(in_mesh).collect(),
+            }
+        },
+        MeshRegion::LocalAabb(aabb) => {
+            let (min, max) = (Vec3::from(aabb.min), Vec3::from(aabb.max));
+            positions
+                .iter()
+                .enumerate()
+                .filter(|(_, pos)| {
+                    let pos = Vec3::from_array(**pos);
+                    pos.cmpge(min).all() && pos.cmple(max).all()
+                })
+                .map(|(index, _)| index)
+                .collect()
+        },
+    };
+
+    let posed = (pose == FitPose::Animated)
+        .then(|| posed_mesh_points(entity, mesh, global_transform, queries))
+        .flatten();
+    let points: Vec<Vec3> = match posed {
+        Some(posed) => selected
+            .iter()
+            .filter_map(|index| posed.get(*index).copied())
+            .collect(),
+        None => selected
+            .iter()
+            .map(|index| global_transform.transform_point(Vec3::from_array(positions[*index])))
+            .collect(),
+    };
+    let center = points_centroid(&points).ok_or(FitError::NoMesh)?;
+    Ok((reduce_points(points, volume), center))
+}
+
+/// Picks the extraction failure that better explains an empty target: a mesh still loading
+/// over one without positions, over no mesh at all.
+fn more_specific_error(current: FitError, new: FitError) -> FitError {
+    let rank = |error: FitError| match error {
+        FitError::MeshNotLoaded => 2,
+        FitError::NoPositionAttribute => 1,
+        _ => 0,
+    };
+    if rank(new) > rank(current) {
+        new
+    } else {
+        current
+    }
+}
+
+/// Returns the local-space points standing in for a mesh under `volume`, or `None` if the mesh
+/// has no float3 position attribute. [`FitVolume::BoundingSphere`] uses the mesh's box corners;
+/// the sphere itself is built once over the whole target.
+pub fn mesh_local_points(mesh: &Mesh, volume: FitVolume) -> Option<Vec<Vec3>> {
+    let positions = mesh
+        .attribute(Mesh::ATTRIBUTE_POSITION)
+        .and_then(|a| a.as_float3())?;
+    let positions: Vec<Vec3> = positions.iter().map(|pos| Vec3::from_array(*pos)).collect();
+    Some(reduce_points(positions, volume))
+}
+
+/// Returns the world-space vertices of a mesh in its current pose: morph target displacements
+/// weighted by `MeshMorphWeights`, then `SkinnedMesh` joint transforms (or the entity's
+/// `GlobalTransform` for unskinned meshes). Returns `None` when the mesh is neither skinned nor
+/// morphed, so the caller can use the cached bind-pose points.
+fn posed_mesh_points(
+    entity: Entity,
+    mesh: &Mesh,
+    global_transform: &GlobalTransform,
+    queries: &FitTargetQueries,
+) -> Option<Vec<Vec3>> {
+    let morph_weights = queries.morph_weights_query.get(entity).ok();
+    let joint_matrices = skin_joint_matrices(entity, queries);
+    if morph_weights.is_none() && joint_matrices.is_none() {
+        return None;
+    }
+
+    let mut positions: Vec<Vec3> = mesh
+        .attribute(Mesh::ATTRIBUTE_POSITION)
+        .and_then(|a| a.as_float3())?
+        .iter()
+        .map(|pos| Vec3::from_array(*pos))
+        .collect();
+
+    if let Some(weights) = morph_weights {
+        apply_morph_targets(
+            &mut positions,
+            mesh,
+            weights.weights(),
+            queries.images.as_deref(),
+        );
+    }
+
+    let skinned = joint_matrices.and_then(|joints| skin_positions(&positions, mesh, &joints));
+    Some(skinned.unwrap_or_else(|| {
+        positions
+            .iter()
+            .map(|pos| global_transform.transform_point(*pos))
+            .collect()
+    }))
+}
+
+/// Returns each joint's skinning matrix (joint `GlobalTransform` times inverse bind pose), or
+/// `None` if the entity is not skinned or its bind poses or joints are unavailable.
+fn skin_joint_matrices(entity: Entity, queries: &FitTargetQueries) -> Option<Vec<Mat4>> {
+    let skinned_mesh = queries.skinned_mesh_query.get(entity).ok()?;
+    let inverse_bindposes = queries
+        .inverse_bindposes
+        .as_ref()?
+        .get(&skinned_mesh.inverse_bindposes)?;
+    skinned_mesh
+        .joints
+        .iter()
+        .zip(inverse_bindposes.iter())
+        .map(|(joint, inverse_bindpose)| {
+            let joint_transform = queries.global_transform_query.get(*joint).ok()?;
+            Some(joint_transform.to_matrix() * *inverse_bindpose)
+        })
+        .collect()
+}
+
+/// Applies linear blend skinning to local-space positions, producing world-space positions.
+/// Skinned meshes ignore their own `GlobalTransform`, as in Bevy's renderer. Returns `None` if
+/// the mesh lacks joint index or weight attributes.
+fn skin_positions(positions: &[Vec3], mesh: &Mesh, joint_matrices: &[Mat4]) -> Option<Vec<Vec3>> {
+    let Some(VertexAttributeValues::Uint16x4(joint_indices)) =
+        mesh.attribute(Mesh::ATTRIBUTE_JOINT_INDEX)
+    else {
+        return None;
+    };
+    let Some(VertexAttributeValues::Float32x4(joint_weights)) =
+        mesh.attribute(Mesh::ATTRIBUTE_JOINT_WEIGHT)
+    else {
+        return None;
+    };
+
+    Some(
+        positions
+            .iter()
+            .zip(joint_indices.iter().zip(joint_weights))
+            .map(|(position, (indices, weights))| {
+                let skin = indices
+                    .iter()
+                    .zip(weights)
+                    .fold(Mat4::ZERO, |skin, (index, weight)| {
+                        joint_matrices
+                            .get(usize::from(*index))
+                            .map_or(skin, |joint| skin + *joint * *weight)
+                    });
+                skin.transform_point3(*position)
+            })
+            .collect(),
+    )
+}
+
+/// Adds the weighted morph target position displacements to `positions`. Meshes without morph
+/// targets, or whose morph target image is not available on the CPU, are left unchanged.
+fn apply_morph_targets(
+    positions: &mut [Vec3],
+    mesh: &Mesh,
+    weights: &[f32],
+    images: Option<&Assets<Image>>,
+) {
+    let Some(image) = mesh
+        .try_morph_targets()
+        .ok()
+        .and_then(|handle| images?.get(handle))
+    else {
+        return;
+    };
+    let Some(data) = image.data.as_deref() else {
+        return;
+    };
+
+    // One R32Float layer per target, each holding position, normal and tangent displacements
+    // for every vertex in turn.
+    let layer_len = (image.width() * image.height()) as usize;
+    let component = |index: usize| {
+        data.get(index * 4..index * 4 + 4)
+            .and_then(|bytes| bytes.try_into().ok())
+            .map_or(0.0, f32::from_ne_bytes)
+    };
+    for (target, weight) in weights.iter().enumerate().filter(|(_, w)| **w != 0.0) {
+        for (vertex, position) in positions.iter_mut().enumerate() {
+            let base = target * layer_len + vertex * MorphAttributes::COMPONENT_COUNT;
+            let displacement = Vec3::new(component(base), component(base + 1), component(base + 2));
+            *position += displacement * *weight;
+        }
+    }
+}
+
+/// Reduces a point set to the points standing in for it under `volume`.
+pub fn reduce_points(points: Vec<Vec3>, volume: FitVolume) -> Vec<Vec3> {
+    match volume {
+        FitVolume::Vertices => points,
+        FitVolume::MeshAabb | FitVolume::BoundingSphere => points_aabb(&points)
+            .map(|aabb| aabb_corners(&aabb).to_vec())
+            .unwrap_or_default(),
+        FitVolume::ConvexHull => convex_hull_3d(&points),
+    }
+}
+
+/// Number of surface samples used to approximate a sphere.
+const SPHERE_SAMPLE_COUNT: usize = 256;
+
+/// Scale applied to the sphere samples so their convex hull encloses the sphere. The hull of 256
+/// Fibonacci samples on a unit sphere has an inradius of about 0.9855 (narrowest near the poles),
+/// so unscaled samples would frame up to 1.5% short of the silhouette from some directions.
+const SPHERE_SAMPLE_INFLATION: f32 = 1.015;
+
+/// Extracts world-space vertex positions for a [`FitTarget`], combined into a single point set
+/// and reduced according to `volume`, with meshes in the requested `pose` and filtered by the
+/// fitting `camera`'s [`FitFilter`]. Returns `(vertices, geometric_center)`.
+///
+/// - Entity targets: `geometric_center` is the mean of the contributing entities' `GlobalTransform`
+///   translations. Entities without extractable mesh vertices are skipped; fails only when no
+///   entity contributes any vertices, with the most specific reason among them.
+/// - Mesh regions: only the selected positions of the entity's own mesh, in the requested pose.
+///   `geometric_center` is their centroid. Fails with [`FitError::NoMesh`] when the region selects
+///   nothing.
+/// - Geometry targets: points are used as-is (or reduced to their box or hull), a box contributes
+///   its 8 corners and a sphere is sampled on its surface. `geometric_center` is the centroid, box
+///   center or sphere center. Fails with [`FitError::NoMesh`] for an empty point set.
+///
+/// [`FitVolume::BoundingSphere`] replaces the combined points with samples on a sphere bounding
+/// them; `geometric_center` is unaffected.
+///
+/// With [`FitFallback::use_transform`] on the camera, an entity target with no meshes at all is
+/// a single point at its [`fit_target_translation`] instead of failing with
+/// [`FitError::NoMesh`]. Targets whose meshes are all left out by [`FitIgnore`] or the camera's
+/// [`FitFilter`] still fail, as do mesh regions that select nothing.
+pub fn extract_fit_target_vertices(
+    target: &FitTarget,
+    camera: Entity,
+    volume: FitVolume,
+    pose: FitPose,
+    queries: &mut FitTargetQueries,
+) -> Result<(Vec<Vec3>, Vec3), FitError> {
+    let use_transform = queries
+        .fallback_query
+        .get(camera)
+        .is_ok_and(|fallback| fallback.use_transform);
+    match extract_target_points(target, camera, volume, pose, queries) {
+        Err(FitError::NoMesh)
+            if use_transform
+                && !matches!(target, FitTarget::MeshRegion { .. })
+                && !target.entities().is_empty()
+                && target
+                    .entities()
+                    .iter()
+                    .all(|entity| is_bare_transform(*entity, queries)) =>
+        {
+            let translation = fit_target_translation(target, &queries.global_transform_query)
+                .ok_or(FitError::NoMesh)?;
+            Ok((vec![translation], translation))
+        },
+        result => result,
+    }
+}
+
+/// [`extract_fit_target_vertices`] without the [`FitFallback`].
+fn extract_target_points(
+    target: &FitTarget,
+    camera: Entity,
+    volume: FitVolume,
+    pose: FitPose,
+    queries: &mut FitTargetQueries,
+) -> Result<(Vec<Vec3>, Vec3), FitError> {
+    let filter = MeshFilter::for_camera(camera, queries);
+    let (vertices, geometric_center) = match target {
+        FitTarget::Entity(entity) => {
+            extract_mesh_vertices(*entity, &filter, volume, pose, queries)?
+        },
+        FitTarget::Entities(entities) => {
+            let mut all_vertices = Vec::new();
+            let mut center_sum = Vec3::ZERO;
+            let mut contributing = 0_usize;
+            let mut error = FitError::NoMesh;
+
+            for entity in entities {
+                match extract_mesh_vertices(*entity, &filter, volume, pose, queries) {
+                    Ok((vertices, center)) => {
+                        all_vertices.extend(vertices);
+                        center_sum += center;
+                        contributing += 1;
+                    },
+                    Err(entity_error) => error = more_specific_error(error, entity_error),
+                }
+            }
+
+            if contributing == 0 {
+                return Err(error);
+            }
+
+            (all_vertices, center_sum / contributing as f32)
+        },
+        FitTarget::Points(points) => {
+            let center = points_centroid(points).ok_or(FitError::NoMesh)?;
+            (reduce_points(points.clone(), volume), center)
+        },
+        FitTarget::Aabb(aabb) => (aabb_corners(aabb).to_vec(), aabb.center().into()),
+        FitTarget::Sphere(sphere) => (sphere_surface_points(sphere), sphere.center.into()),
+        FitTarget::MeshRegion { entity, region } => {
+            extract_region_vertices(*entity, region, volume, pose, queries)?
+        },
+    };
+
+    let vertices = match volume {
+        FitVolume::BoundingSphere => {
+            sphere_surface_points(&points_bounding_sphere(&vertices).ok_or(FitError::NoMesh)?)
+        },
+        FitVolume::Vertices | FitVolume::MeshAabb | FitVolume::ConvexHull => vertices,
+    };
+
+    Ok((vertices, geometric_center))
+}
+
+/// Returns `true` if `root` is an entity with nothing to fit at or under it, before any
+/// filtering: no `Mesh3d`, no [`FitGeometry`](crate::FitGeometry) points and no [`FitIgnore`].
+/// These are the targets [`FitFallback::use_transform`] frames at their transform.
+fn is_bare_transform(root: Entity, queries: &FitTargetQueries) -> bool {
+    if queries.ignore_query.contains(root) {
+        return false;
+    }
+    let mut pending = vec![root];
+    while let Some(entity) = pending.pop() {
+        if queries.mesh_query.contains(entity) || queries.geometry_query.contains(entity) {
+            return false;
+        }
+        if let Ok(children) = queries.children_query.get(entity) {
+            pending.extend(children.iter());
+        }
+    }
+    true
+}
+
+/// Returns `true` once an entity target can be fitted in full: every entity has at least one
+/// `Mesh3d` or [`FitGeometry`](crate::FitGeometry) source at or under it (after [`FitIgnore`] and
+/// `camera`'s [`FitFilter`]), and every such mesh has its asset in `Assets<Mesh>` and a
+/// `GlobalTransform`; entities with [`FitGeometry`](crate::FitGeometry) points need a
+/// `GlobalTransform`. With [`FitFallback::use_transform`] on the camera, an entity with nothing to
+/// fit at all needs only its `GlobalTransform`. A mesh region needs only its entity's own mesh.
+/// Geometry targets are always loaded.
+pub fn fit_target_loaded(target: &FitTarget, camera: Entity, queries: &FitTargetQueries) -> bool {
+    if let FitTarget::MeshRegion { entity, .. } = target {
+        return queries
+            .mesh_query
+            .get(*entity)
+            .is_ok_and(|mesh3d| queries.meshes.contains(&mesh3d.0))
+            && queries.global_transform_query.contains(*entity);
+    }
+    let filter = MeshFilter::for_camera(camera, queries);
+    let use_transform = queries
+        .fallback_query
+        .get(camera)
+        .is_ok_and(|fallback| fallback.use_transform);
+    target.entities().iter().all(|entity| {
+        let mesh_entities = filter.mesh_entities(*entity, queries);
+        if mesh_entities.is_empty() {
+            return use_transform
+                && is_bare_transform(*entity, queries)
+                && queries.global_transform_query.contains(*entity);
+        }
+        mesh_entities.iter().all(|mesh_entity| {
+            queries
+                .mesh_query
+                .get(*mesh_entity)
+                .ok()
+                .is_none_or(|mesh3d| queries.meshes.contains(&mesh3d.0))
+                && queries.global_transform_query.contains(*mesh_entity)
+        })
+    })
+}
+
+/// Stands a sphere of `min_radius` in for a target whose `points` all lie within that distance of
+/// their bounding-box center, returning the sphere's surface samples and center, or `None` for
+/// targets at least that large. Fails with [`FitError::NoMesh`] when there are no points.
+pub fn min_radius_stand_in(
+    points: &[Vec3],
+    min_radius: f32,
+) -> Result<Option<(Vec<Vec3>, Vec3)>, FitError> {
+    let sphere = points_bounding_sphere(points).ok_or(FitError::NoMesh)?;
+    let center = Vec3::from(sphere.center);
+    if sphere.radius() < min_radius {
+        let stand_in = BoundingSphere::new(center, min_radius);
+        return Ok(Some((sphere_surface_points(&stand_in), center)));
+    }
+    Ok(None)
+}
+
+/// Returns the world-space anchor of a [`FitTarget`]: the mean `GlobalTransform` translation
+/// for entity targets (a mesh region's entity included), or the geometric center for geometry
+/// targets.
+/// Entities without a `GlobalTransform` are skipped; returns `None` if none have one.
+pub fn fit_target_translation(
+    target: &FitTarget,
+    global_transform_query: &Query<&GlobalTransform>,
+) -> Option<Vec3> {
+    match target {
+        FitTarget::Entity(_) | FitTarget::Entities(_) | FitTarget::MeshRegion { .. } => {
+            let (sum, count) = target
+                .entities()
+                .iter()
+                .filter_map(|entity| global_transform_query.get(*entity).ok())
+                .fold((Vec3::ZERO, 0_usize), |(sum, count), gt| {
+                    (sum + gt.translation(), count + 1)
+                });
+            (count > 0).then(|| sum / count as f32)
+        },
+        FitTarget::Points(points) => points_centroid(points),
+        FitTarget::Aabb(aabb) => Some(aabb.center().into()),
+        FitTarget::Sphere(sphere) => Some(sphere.center.into()),
+    }
+}
+
+/// Returns the mean of a point set, or `None` if it is empty.
+fn points_centroid(points: &[Vec3]) -> Option<Vec3> {
+    if points.is_empty() {
+        return None;
+    }
+    Some(points.iter().copied().sum::<Vec3>() / points.len() as f32)
+}
+
+/// Returns the axis-aligned box enclosing a point set, or `None` if it is empty.
+fn points_aabb(points: &[Vec3]) -> Option<Aabb3d> {
+    if points.is_empty() {
+        return None;
+    }
+    let (min, max) = points
+        .iter()
+        .fold((Vec3::INFINITY, Vec3::NEG_INFINITY), |(min, max), p| {
+            (min.min(*p), max.max(*p))
+        });
+    Some(Aabb3d::new((min + max) * 0.5, (max - min) * 0.5))
+}
+
+/// Returns a sphere enclosing a point set, centered on the point set's box, or `None` if it is
+/// empty.
+fn points_bounding_sphere(points: &[Vec3]) -> Option<BoundingSphere> {
+    let center = Vec3::from(points_aabb(points)?.center());
+    let radius = points
+        .iter()
+        .map(|p| p.distance(center))
+        .fold(0.0_f32, f32::max);
+    Some(BoundingSphere::new(center, radius))
+}
+
+/// Returns the 8 corners of an axis-aligned box.
+pub fn aabb_corners(aabb: &Aabb3d) -> [Vec3; 8] {
+    let min = Vec3::from(aabb.min);
+    let max = Vec3::from(aabb.max);
+    [
+        Vec3::new(min.x, min.y, min.z),
+        Vec3::new(max.x, min.y, min.z),
+        Vec3::new(min.x, max.y, min.z),
+        Vec3::new(max.x, max.y, min.z),
+        Vec3::new(min.x, min.y, max.z),
+        Vec3::new(max.x, min.y, max.z),
+        Vec3::new(min.x, max.y, max.z),
+        Vec3::new(max.x, max.y, max.z),
+    ]
+}
+
+/// Samples points evenly over a sphere's surface using a Fibonacci lattice, pushed out by
+/// [`SPHERE_SAMPLE_INFLATION`] so that framing the samples never clips the sphere.
+pub fn sphere_surface_points(sphere: &BoundingSphere) -> Vec<Vec3> {
+    let center = Vec3::from(sphere.center);
+    let radius = sphere.radius() * SPHERE_SAMPLE_INFLATION;
+    let golden_angle = std::f32::consts::PI * (3.0 - 5.0_f32.sqrt());
+
+    (0..SPHERE_SAMPLE_COUNT)
+        .map(|i| {
+            let y = 1.0 - 2.0 * (i as f32 + 0.5) / SPHERE_SAMPLE_COUNT as f32;
+            let ring_radius = (1.0 - y * y).sqrt();
+            let theta = golden_angle * i as f32;
+            center + radius * Vec3::new(ring_radius * theta.cos(), y, ring_radius * theta.sin())
+        })
+        .collect()
 }
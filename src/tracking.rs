@@ -0,0 +1,329 @@
+//! Continuous tracking of a moving target with [`TrackTarget`].
+
+use std::time::Duration;
+
+use bevy::prelude::*;
+use bevy_panorbit_camera::PanOrbitCamera;
+
+use crate::animation::CameraMoveList;
+use crate::animation::EXTERNAL_INPUT_TOLERANCE;
+use crate::animation::perspective_fov;
+use crate::animation::set_perspective_fov;
+use crate::components::CameraInputInterruptBehavior;
+use crate::components::FitAlgorithm;
+use crate::components::FitStrategy;
+use crate::components::UnoccludedViewport;
+use crate::events::FitAnchor;
+use crate::events::FitFrame;
+use crate::events::FitMargins;
+use crate::events::FitPose;
+use crate::events::FitTarget;
+use crate::events::FitVolume;
+use crate::fit::FitError;
+use crate::fit::FitParams;
+use crate::observers::fit_points;
+use crate::projection::FitProjectionRegistry;
+use crate::support::FitTargetQueries;
+use crate::support::extract_fit_target_vertices;
+use crate::support::fit_target_translation;
+
+/// Fraction of the hysteresis the tracking error must drop below before a following camera
+/// settles and holds still again.
+const SETTLE_FRACTION: f32 = 0.1;
+
+/// What a [`TrackTarget`] keeps up to date as its target moves.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Reflect)]
+pub enum TrackMode {
+    /// Moves the focus to the target's translation (the mean `GlobalTransform` translation of
+    /// entity targets); radius, yaw and pitch stay under the user's control.
+    #[default]
+    Focus,
+    /// Re-fits the target every frame from the camera's current yaw and pitch, keeping the focus
+    /// centered and the radius framing the target with the requested margin. Fits are solved as
+    /// for [`ZoomToFit`](crate::ZoomToFit), with the camera's [`FitAlgorithm`],
+    /// [`FitStrategy`] and [`FitFallback`](crate::FitFallback); with
+    /// [`FitStrategy::FieldOfView`] the perspective field of view eases toward the fit instead of
+    /// the radius.
+    Fit,
+}
+
+/// Keeps the camera on a moving [`FitTarget`], such as a vehicle or character, until removed.
+///
+/// Insert this component on a camera entity with a `PanOrbitCamera`. Every frame the camera's
+/// target focus (and in [`TrackMode::Fit`], radius or field of view) eases toward where the target
+/// is now:
+///
+/// - `smoothing` — time for the camera to cover about two thirds of the remaining distance;
+///   `Duration::ZERO` follows rigidly. Applies on top of the `PanOrbitCamera`'s own smoothness.
+/// - `hysteresis` — how far the target may drift, as a fraction of the radius, before the camera
+///   starts following. Once following, the camera keeps going until it has nearly caught up, so
+///   small motion doesn't make it jitter.
+/// - `margin`, `frame`, `anchor`, `volume` and `pose` — the framing used in [`TrackMode::Fit`]; see
+///   [`ZoomToFit`](crate::ZoomToFit). Use [`FitPose::Animated`] to follow what a skinned character
+///   shows on screen rather than its bind pose.
+///
+/// Tracking pauses while a [`CameraMoveList`] animation plays on the camera. User input to the
+/// tracked values (panning, and zooming in fit mode) follows the camera's
+/// [`CameraInputInterruptBehavior`]:
+///
+/// - [`Ignore`](CameraInputInterruptBehavior::Ignore) — tracking wins and pulls the camera back.
+/// - [`Cancel`](CameraInputInterruptBehavior::Cancel) — input breaks tracking and removes this
+///   component.
+/// - [`Complete`](CameraInputInterruptBehavior::Complete) — input temporarily overrides tracking,
+///   which resumes once the camera has been left alone for `resume_delay`.
+///
+/// Orbiting is never treated as input: in fit mode the target is re-fitted from the new angle.
+#[derive(Component, Reflect, Clone, Debug)]
+#[reflect(Component)]
+pub struct TrackTarget {
+    pub target:       FitTarget,
+    pub mode:         TrackMode,
+    pub margin:       FitMargins,
+    pub frame:        FitFrame,
+    pub anchor:       FitAnchor,
+    pub volume:       FitVolume,
+    pub pose:         FitPose,
+    pub smoothing:    Duration,
+    pub hysteresis:   f32,
+    pub resume_delay: Duration,
+    state:            TrackState,
+}
+
+impl TrackTarget {
+    pub fn new(target: impl Into<FitTarget>) -> Self {
+        Self {
+            target:       target.into(),
+            mode:         TrackMode::Focus,
+            margin:       FitMargins::uniform(0.1),
+            frame:        FitFrame::Viewport,
+            anchor:       FitAnchor::Center,
+            volume:       FitVolume::Vertices,
+            pose:         FitPose::BindPose,
+            smoothing:    Duration::from_millis(250),
+            hysteresis:   0.05,
+            resume_delay: Duration::from_secs(1),
+            state:        TrackState::default(),
+        }
+    }
+
+    pub const fn mode(mut self, mode: TrackMode) -> Self {
+        self.mode = mode;
+        self
+    }
+
+    pub fn margin(mut self, margin: impl Into<FitMargins>) -> Self {
+        self.margin = margin.into();
+        self
+    }
+
+    pub const fn frame(mut self, frame: FitFrame) -> Self {
+        self.frame = frame;
+        self
+    }
+
+    pub const fn anchor(mut self, anchor: FitAnchor) -> Self {
+        self.anchor = anchor;
+        self
+    }
+
+    pub const fn volume(mut self, volume: FitVolume) -> Self {
+        self.volume = volume;
+        self
+    }
+
+    pub const fn pose(mut self, pose: FitPose) -> Self {
+        self.pose = pose;
+        self
+    }
+
+    pub const fn smoothing(mut self, smoothing: Duration) -> Self {
+        self.smoothing = smoothing;
+        self
+    }
+
+    pub const fn hysteresis(mut self, hysteresis: f32) -> Self {
+        self.hysteresis = hysteresis;
+        self
+    }
+
+    pub const fn resume_delay(mut self, resume_delay: Duration) -> Self {
+        self.resume_delay = resume_delay;
+        self
+    }
+}
+
+/// Per-camera tracking state.
+#[derive(Clone, Copy, Debug, Default, Reflect)]
+struct TrackState {
+    /// Target focus and radius as tracking last left them — if the camera's current values
+    /// differ, external input occurred. `None` until synced with the camera, and again after an
+    /// animation moves it.
+    written:   Option<(Vec3, f32)>,
+    /// Whether the camera is catching up with the target rather than holding inside the
+    /// hysteresis band.
+    following: bool,
+    /// Elapsed time (in seconds) at which tracking resumes after user input overrode it.
+    resume_at: Option<f32>,
+}
+
+impl TrackState {
+    /// Returns `true` if the tracked values were changed by something other than tracking.
+    fn externally_modified(&self, mode: TrackMode, camera: &PanOrbitCamera) -> bool {
+        self.written.is_some_and(|(focus, radius)| {
+            focus.distance(camera.target_focus) > EXTERNAL_INPUT_TOLERANCE
+                || (mode == TrackMode::Fit
+                    && (radius - camera.target_radius).abs() > EXTERNAL_INPUT_TOLERANCE)
+        })
+    }
+
+    const fn sync(&mut self, camera: &PanOrbitCamera) {
+        self.written = Some((camera.target_focus, camera.target_radius));
+    }
+}
+
+/// System that moves cameras with a [`TrackTarget`] toward their targets.
+#[allow(clippy::type_complexity)]
+pub fn track_targets(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut camera_query: Query<(
+        Entity,
+        &mut PanOrbitCamera,
+        &mut TrackTarget,
+        &Camera,
+        &mut Projection,
+        Option<&CameraInputInterruptBehavior>,
+        Option<&FitAlgorithm>,
+        Option<&FitStrategy>,
+        Option<&UnoccludedViewport>,
+        Has<CameraMoveList>,
+    )>,
+    projections: Res<FitProjectionRegistry>,
+    mut queries: FitTargetQueries,
+) {
+    let now = time.elapsed_secs();
+    for (
+        entity,
+        mut panorbit,
+        mut track,
+        cam,
+        mut projection,
+        interrupt_behavior,
+        algorithm,
+        strategy,
+        unoccluded,
+        animating,
+    ) in &mut camera_query
+    {
+        if animating {
+            track.state = TrackState::default();
+            continue;
+        }
+
+        if track.state.externally_modified(track.mode, &panorbit) {
+            match interrupt_behavior.copied().unwrap_or_default() {
+                CameraInputInterruptBehavior::Ignore => {},
+                CameraInputInterruptBehavior::Cancel => {
+                    commands.entity(entity).remove::<TrackTarget>();
+                    continue;
+                },
+                CameraInputInterruptBehavior::Complete => {
+                    track.state.resume_at = Some(now + track.resume_delay.as_secs_f32());
+                },
+            }
+        }
+        if let Some(resume_at) = track.state.resume_at {
+            if now < resume_at {
+                track.state.sync(&panorbit);
+                continue;
+            }
+            track.state.resume_at = None;
+        }
+
+        let desired = match track.mode {
+            TrackMode::Focus => {
+                fit_target_translation(&track.target, &queries.global_transform_query)
+                    .map(|focus| (focus, panorbit.target_radius, None))
+            },
+            TrackMode::Fit => {
+                let fit = projections
+                    .resolve(&projection)
+                    .ok_or(FitError::UnsupportedProjection)
+                    .and_then(|fit_projection| {
+                        let (vertices, geometric_center) = extract_fit_target_vertices(
+                            &track.target,
+                            entity,
+                            track.volume,
+                            track.pose,
+                            &mut queries,
+                        )?;
+                        let params = FitParams {
+                            yaw:       panorbit.target_yaw,
+                            pitch:     panorbit.target_pitch,
+                            margins:   track.margin,
+                            frame:     track.frame.resolve_unoccluded(unoccluded.map(|u| u.0)),
+                            anchor:    track.anchor,
+                            algorithm: algorithm.copied().unwrap_or_default(),
+                            strategy:  strategy.copied().unwrap_or_default(),
+                            fallback:  queries
+                                .fallback_query
+                                .get(entity)
+                                .copied()
+                                .unwrap_or_default(),
+                        };
+                        fit_points(
+                            &vertices,
+                            geometric_center,
+                            &params,
+                            &projection,
+                            fit_projection,
+                            cam,
+                            &panorbit,
+                        )
+                    });
+                fit.inspect_err(|reason| {
+                    debug!("TrackTarget: cannot fit {:?}: {reason}", track.target);
+                })
+                .ok()
+                .map(|fit| (fit.focus, fit.radius, fit.fov))
+            },
+        };
+        let Some((focus, radius, fov)) = desired else {
+            debug!("TrackTarget: no position for {:?} this frame", track.target);
+            track.state.sync(&panorbit);
+            continue;
+        };
+
+        // Hysteresis: start following past `hysteresis`, stop once nearly caught up.
+        let scale = panorbit.target_radius.max(f32::EPSILON);
+        let current_fov = perspective_fov(Some(&projection));
+        let fov_error = fov.zip(current_fov).map_or(0.0, |(fov, current)| {
+            (fov - current).abs() / current.max(f32::EPSILON)
+        });
+        let error = (focus.distance(panorbit.target_focus) / scale)
+            .max((radius - panorbit.target_radius).abs() / scale)
+            .max(fov_error);
+        if error > track.hysteresis {
+            track.state.following = true;
+        } else if error <= track.hysteresis * SETTLE_FRACTION {
+            track.state.following = false;
+        }
+
+        if track.state.following {
+            let smoothing = track.smoothing.as_secs_f32();
+            let t = if smoothing > 0.0 {
+                1.0 - (-time.delta_secs() / smoothing).exp()
+            } else {
+                1.0
+            };
+            panorbit.target_focus = panorbit.target_focus.lerp(focus, t);
+            if track.mode == TrackMode::Fit {
+                panorbit.target_radius = panorbit.target_radius.lerp(radius, t);
+            }
+            if let Some((fov, current)) = fov.zip(current_fov) {
+                set_perspective_fov(Some(&mut projection), current.lerp(fov, t));
+            }
+        }
+        track.state.sync(&panorbit);
+    }
+}
@@ -1,17 +1,31 @@
 use bevy::prelude::*;
 
 use crate::fit::Edge;
+use crate::fit::EdgeMargins;
+use crate::projection::FitProjection;
 use crate::support::CameraBasis;
 use crate::support::ScreenSpaceBounds;
 
-/// Returns true if horizontal margins are balanced.
-pub fn is_horizontally_balanced(bounds: &ScreenSpaceBounds, tolerance: f32) -> bool {
-    (bounds.left_margin - bounds.right_margin).abs() < tolerance
+/// Returns true if horizontal margins are balanced, i.e. both sides exceed their requested
+/// margin by the same amount (equal margins when the requested margins are symmetric).
+pub fn is_horizontally_balanced(
+    bounds: &ScreenSpaceBounds,
+    requested: &EdgeMargins,
+    tolerance: f32,
+) -> bool {
+    ((bounds.left_margin - requested.left) - (bounds.right_margin - requested.right)).abs()
+        < tolerance
 }
 
-/// Returns true if vertical margins are balanced.
-pub fn is_vertically_balanced(bounds: &ScreenSpaceBounds, tolerance: f32) -> bool {
-    (bounds.top_margin - bounds.bottom_margin).abs() < tolerance
+/// Returns true if vertical margins are balanced, i.e. both sides exceed their requested
+/// margin by the same amount (equal margins when the requested margins are symmetric).
+pub fn is_vertically_balanced(
+    bounds: &ScreenSpaceBounds,
+    requested: &EdgeMargins,
+    tolerance: f32,
+) -> bool {
+    ((bounds.top_margin - requested.top) - (bounds.bottom_margin - requested.bottom)).abs()
+        < tolerance
 }
 
 /// Returns the screen edges in normalized space: (left, right, top, bottom).
@@ -71,9 +85,10 @@ pub fn screen_edge_center(bounds: &ScreenSpaceBounds, edge: Edge) -> (f32, f32)
     }
 }
 
-/// Converts normalized screen-space coordinates to world space.
+/// Converts normalized screen-space coordinates to world space on the plane at `avg_depth`,
+/// through [`FitProjection::unproject_normalized`].
 ///
-/// For perspective, reverses the perspective divide by multiplying by `avg_depth`.
+/// For perspective, this reverses the perspective divide by multiplying by `avg_depth`.
 /// For orthographic, coordinates are already in world units — `avg_depth` is only
 /// used for the forward component to position the gizmo plane.
 pub fn normalized_to_world(
@@ -81,14 +96,10 @@ pub fn normalized_to_world(
     norm_y: f32,
     cam: &CameraBasis,
     avg_depth: f32,
-    is_ortho: bool,
+    projection: &dyn FitProjection,
 ) -> Vec3 {
-    let (world_x, world_y) = if is_ortho {
-        (norm_x, norm_y)
-    } else {
-        (norm_x * avg_depth, norm_y * avg_depth)
-    };
-    cam.pos + cam.right * world_x + cam.up * world_y + cam.forward * avg_depth
+    let view_point = projection.unproject_normalized(Vec2::new(norm_x, norm_y), avg_depth);
+    cam.pos + cam.right * view_point.x + cam.up * view_point.y + cam.forward * view_point.z
 }
 
 /// Returns the margin percentage for a given edge.
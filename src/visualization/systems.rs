@@ -1,6 +1,5 @@
 use bevy::prelude::*;
 
-use super::convex_hull::convex_hull_2d;
 use super::convex_hull::project_vertices_to_2d;
 use super::labels::BoundsLabel;
 use super::labels::MarginLabel;
@@ -19,13 +18,23 @@ use super::screen_space::screen_edge_center;
 use super::types::FitTargetGizmo;
 use super::types::FitTargetViewportMargins;
 use super::types::FitTargetVisualizationConfig;
+use crate::components::CurrentFitOptions;
 use crate::components::CurrentFitTarget;
 use crate::components::FitVisualization;
+use crate::components::UnoccludedViewport;
+use crate::convex_hull::convex_hull_2d;
+use crate::events::FitMargins;
 use crate::fit::Edge;
+use crate::fit::EdgeMargins;
+use crate::projection::FitProjection;
+use crate::projection::FitProjectionRegistry;
 use crate::support::CameraBasis;
+use crate::support::FitTargetQueries;
 use crate::support::ScreenSpaceBounds;
-use crate::support::extract_mesh_vertices;
-use crate::support::projection_aspect_ratio;
+use crate::support::extract_fit_target_vertices;
+
+/// Half the length of the anchor crosshair's arms, as a fraction of the visible half height.
+const ANCHOR_CROSSHAIR_SIZE: f32 = 0.04;
 
 /// Calculates the color for an edge based on balance state.
 const fn calculate_edge_color(
@@ -57,7 +66,7 @@ fn create_screen_corners(
     bounds: &ScreenSpaceBounds,
     cam: &CameraBasis,
     avg_depth: f32,
-    is_ortho: bool,
+    projection: &dyn FitProjection,
 ) -> [Vec3; 4] {
     [
         normalized_to_world(
@@ -65,28 +74,28 @@ fn create_screen_corners(
             bounds.min_norm_y,
             cam,
             avg_depth,
-            is_ortho,
+            projection,
         ),
         normalized_to_world(
             bounds.max_norm_x,
             bounds.min_norm_y,
             cam,
             avg_depth,
-            is_ortho,
+            projection,
         ),
         normalized_to_world(
             bounds.max_norm_x,
             bounds.max_norm_y,
             cam,
             avg_depth,
-            is_ortho,
+            projection,
         ),
         normalized_to_world(
             bounds.min_norm_x,
             bounds.max_norm_y,
             cam,
             avg_depth,
-            is_ortho,
+            projection,
         ),
     ]
 }
@@ -103,16 +112,66 @@ fn draw_rectangle(
     }
 }
 
+/// Draws a frame inset from the screen edges by `insets` — with the requested margins, the
+/// area the fit places the target in.
+fn draw_inset_frame(
+    gizmos: &mut Gizmos<FitTargetGizmo>,
+    bounds: &ScreenSpaceBounds,
+    insets: &EdgeMargins,
+    cam: &CameraBasis,
+    avg_depth: f32,
+    projection: &dyn FitProjection,
+    color: Color,
+) {
+    let left = -bounds.half_extent_x + insets.left;
+    let right = bounds.half_extent_x - insets.right;
+    let top = bounds.half_extent_y - insets.top;
+    let bottom = -bounds.half_extent_y + insets.bottom;
+
+    let corners = [
+        normalized_to_world(left, bottom, cam, avg_depth, projection),
+        normalized_to_world(right, bottom, cam, avg_depth, projection),
+        normalized_to_world(right, top, cam, avg_depth, projection),
+        normalized_to_world(left, top, cam, avg_depth, projection),
+    ];
+    for i in 0..4 {
+        let next = (i + 1) % 4;
+        gizmos.line(corners[i], corners[next], color);
+    }
+}
+
+/// Draws a crosshair at a [`FitAnchor`](crate::FitAnchor) given in normalized screen space.
+fn draw_anchor(
+    gizmos: &mut Gizmos<FitTargetGizmo>,
+    anchor: Vec2,
+    bounds: &ScreenSpaceBounds,
+    cam: &CameraBasis,
+    avg_depth: f32,
+    projection: &dyn FitProjection,
+    color: Color,
+) {
+    let arm = ANCHOR_CROSSHAIR_SIZE * bounds.half_extent_y;
+    for offset in [Vec2::X * arm, Vec2::Y * arm] {
+        let start = anchor - offset;
+        let end = anchor + offset;
+        gizmos.line(
+            normalized_to_world(start.x, start.y, cam, avg_depth, projection),
+            normalized_to_world(end.x, end.y, cam, avg_depth, projection),
+            color,
+        );
+    }
+}
+
 /// Draws the silhouette polygon (convex hull of projected vertices) using gizmo lines.
 fn draw_silhouette(
     gizmos: &mut Gizmos<FitTargetGizmo>,
     vertices: &[Vec3],
     cam: &CameraBasis,
     avg_depth: f32,
-    is_ortho: bool,
+    projection: &dyn FitProjection,
     color: Color,
 ) {
-    let projected = project_vertices_to_2d(vertices, cam, is_ortho);
+    let projected = project_vertices_to_2d(vertices, cam, projection);
     let hull = convex_hull_2d(&projected);
 
     if hull.len() < 2 {
@@ -121,8 +180,8 @@ fn draw_silhouette(
 
     for i in 0..hull.len() {
         let next = (i + 1) % hull.len();
-        let start = normalized_to_world(hull[i].0, hull[i].1, cam, avg_depth, is_ortho);
-        let end = normalized_to_world(hull[next].0, hull[next].1, cam, avg_depth, is_ortho);
+        let start = normalized_to_world(hull[i].0, hull[i].1, cam, avg_depth, projection);
+        let end = normalized_to_world(hull[next].0, hull[next].1, cam, avg_depth, projection);
         gizmos.line(start, end, color);
     }
 }
@@ -136,14 +195,15 @@ fn draw_margin_lines_and_labels(
     label_query: &mut Query<(Entity, &MarginLabel, &mut Text, &mut Node, &mut TextColor)>,
     camera: Entity,
     bounds: &ScreenSpaceBounds,
+    requested: &EdgeMargins,
     cam_basis: &CameraBasis,
     avg_depth: f32,
-    is_ortho: bool,
+    projection: &dyn FitProjection,
     config: &FitTargetVisualizationConfig,
     viewport_size: Option<Vec2>,
 ) -> Vec<Edge> {
-    let h_balanced = is_horizontally_balanced(bounds, crate::fit::TOLERANCE);
-    let v_balanced = is_vertically_balanced(bounds, crate::fit::TOLERANCE);
+    let h_balanced = is_horizontally_balanced(bounds, requested, crate::fit::TOLERANCE);
+    let v_balanced = is_vertically_balanced(bounds, requested, crate::fit::TOLERANCE);
 
     let mut visible_edges: Vec<Edge> = Vec::new();
 
@@ -155,8 +215,8 @@ fn draw_margin_lines_and_labels(
 
         let (screen_x, screen_y) = screen_edge_center(bounds, edge);
         let boundary_pos =
-            normalized_to_world(boundary_x, boundary_y, cam_basis, avg_depth, is_ortho);
-        let screen_pos = normalized_to_world(screen_x, screen_y, cam_basis, avg_depth, is_ortho);
+            normalized_to_world(boundary_x, boundary_y, cam_basis, avg_depth, projection);
+        let screen_pos = normalized_to_world(screen_x, screen_y, cam_basis, avg_depth, projection);
 
         let color = calculate_edge_color(edge, h_balanced, v_balanced, config);
         gizmos.line(boundary_pos, screen_pos, color);
@@ -212,43 +272,68 @@ pub fn draw_fit_target_bounds(
             &GlobalTransform,
             &Projection,
             &CurrentFitTarget,
+            Option<&CurrentFitOptions>,
+            Option<&UnoccludedViewport>,
         ),
         With<FitVisualization>,
     >,
-    mesh_query: Query<&Mesh3d>,
-    children_query: Query<&Children>,
-    global_transform_query: Query<&GlobalTransform>,
-    meshes: Res<Assets<Mesh>>,
+    projections: Res<FitProjectionRegistry>,
+    mut queries: FitTargetQueries,
     mut label_query: Query<(Entity, &MarginLabel, &mut Text, &mut Node, &mut TextColor)>,
     mut bounds_label_query: Query<(Entity, &BoundsLabel, &mut Node), Without<MarginLabel>>,
 ) {
-    for (camera, cam, cam_global, projection, current_target) in &camera_query {
-        let Some((vertices, _)) = extract_mesh_vertices(
-            current_target.0,
-            &children_query,
-            &mesh_query,
-            &global_transform_query,
-            &meshes,
+    for (camera, cam, cam_global, projection, current_target, current_options, unoccluded) in
+        &camera_query
+    {
+        let current_options = current_options.copied().unwrap_or_default();
+        let Ok((vertices, _)) = extract_fit_target_vertices(
+            &current_target.0,
+            camera,
+            current_options.volume,
+            current_options.pose,
+            &mut queries,
         ) else {
             continue;
         };
 
         let cam_basis = CameraBasis::from_global_transform(cam_global);
 
-        let Some(aspect_ratio) = projection_aspect_ratio(projection, cam.logical_viewport_size())
-        else {
+        let Some(projection) = projections.resolve(projection) else {
+            continue;
+        };
+        let viewport_size = cam.logical_viewport_size();
+        let Some(half_extents) = projection.half_extents(viewport_size) else {
             continue;
         };
 
         let Some((bounds, depths)) =
-            ScreenSpaceBounds::from_points(&vertices, cam_global, projection, aspect_ratio)
+            ScreenSpaceBounds::from_points(&vertices, cam_global, projection, half_extents)
         else {
             continue;
         };
 
         let avg_depth = depths.depth_sum / depths.point_count as f32;
-        let is_ortho = matches!(projection, Projection::Orthographic(_));
-        let viewport_size = cam.logical_viewport_size();
+        let frame = current_options
+            .frame
+            .resolve_unoccluded(unoccluded.map(|u| u.0))
+            .to_normalized(viewport_size)
+            .unwrap_or(Rect::new(0.0, 0.0, 1.0, 1.0));
+        let requested = EdgeMargins::from_fit_margins(
+            &current_options.margin,
+            frame,
+            &bounds,
+            &depths,
+            projection.is_orthographic(),
+            viewport_size,
+        )
+        .unwrap_or_default();
+        let anchor = current_options.anchor;
+        // Anchored fits balance the margins around the anchor rather than the margin frame.
+        let balance_margins = requested.anchored(
+            anchor,
+            frame,
+            Vec2::new(bounds.half_extent_x, bounds.half_extent_y),
+        );
 
         // Update margin percentages on camera entity for BRP inspection.
         // `try_insert` silently skips if the entity was despawned this frame
@@ -258,16 +343,62 @@ pub fn draw_fit_target_bounds(
             .try_insert(FitTargetViewportMargins::from_bounds(&bounds));
 
         // Bounding rectangle
-        let corners = create_screen_corners(&bounds, &cam_basis, avg_depth, is_ortho);
+        let corners = create_screen_corners(&bounds, &cam_basis, avg_depth, projection);
         draw_rectangle(&mut gizmos, &corners, &config);
 
+        // Requested fit frame, when it is smaller than the viewport
+        if frame != Rect::new(0.0, 0.0, 1.0, 1.0) {
+            let frame_insets = EdgeMargins::from_fit_margins(
+                &FitMargins::default(),
+                frame,
+                &bounds,
+                &depths,
+                projection.is_orthographic(),
+                viewport_size,
+            )
+            .unwrap_or_default();
+            draw_inset_frame(
+                &mut gizmos,
+                &bounds,
+                &frame_insets,
+                &cam_basis,
+                avg_depth,
+                projection,
+                config.fit_frame_color,
+            );
+        }
+
+        // Requested margin frame
+        draw_inset_frame(
+            &mut gizmos,
+            &bounds,
+            &requested,
+            &cam_basis,
+            avg_depth,
+            projection,
+            config.target_frame_color,
+        );
+
+        // Anchor the fit centers the target on
+        if let Some(point) = anchor.to_screen(frame, half_extents) {
+            draw_anchor(
+                &mut gizmos,
+                point,
+                &bounds,
+                &cam_basis,
+                avg_depth,
+                projection,
+                config.anchor_color,
+            );
+        }
+
         // Silhouette convex hull
         draw_silhouette(
             &mut gizmos,
             &vertices,
             &cam_basis,
             avg_depth,
-            is_ortho,
+            projection,
             config.silhouette_color,
         );
 
@@ -295,9 +426,10 @@ pub fn draw_fit_target_bounds(
             &mut label_query,
             camera,
             &bounds,
+            &balance_margins,
             &cam_basis,
             avg_depth,
-            is_ortho,
+            projection,
             &config,
             viewport_size,
         );
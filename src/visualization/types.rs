@@ -38,21 +38,31 @@ impl FitTargetViewportMargins {
 #[derive(Resource, Reflect, Debug, Clone)]
 #[reflect(Resource)]
 pub struct FitTargetVisualizationConfig {
-    pub rectangle_color:  Color,
-    pub silhouette_color: Color,
-    pub balanced_color:   Color,
-    pub unbalanced_color: Color,
-    pub line_width:       f32,
+    pub rectangle_color:    Color,
+    pub silhouette_color:   Color,
+    /// Color of the frame inset from the screen edges by the requested fit margins.
+    pub target_frame_color: Color,
+    /// Color of the outline of the requested [`FitFrame`](crate::FitFrame) when it is smaller
+    /// than the viewport.
+    pub fit_frame_color:    Color,
+    /// Color of the crosshair marking a [`FitAnchor`](crate::FitAnchor) other than the center.
+    pub anchor_color:       Color,
+    pub balanced_color:     Color,
+    pub unbalanced_color:   Color,
+    pub line_width:         f32,
 }
 
 impl Default for FitTargetVisualizationConfig {
     fn default() -> Self {
         Self {
-            rectangle_color:  Color::srgb(1.0, 1.0, 0.0), // Yellow
-            silhouette_color: Color::srgb(1.0, 0.5, 0.0), // Orange
-            balanced_color:   Color::srgb(0.0, 1.0, 0.0), // Green
-            unbalanced_color: Color::srgb(1.0, 0.0, 0.0), // Red
-            line_width:       2.0,
+            rectangle_color:    Color::srgb(1.0, 1.0, 0.0), // Yellow
+            silhouette_color:   Color::srgb(1.0, 0.5, 0.0), // Orange
+            target_frame_color: Color::srgb(0.0, 1.0, 1.0), // Cyan
+            fit_frame_color:    Color::srgb(1.0, 0.0, 1.0), // Magenta
+            anchor_color:       Color::srgb(1.0, 1.0, 1.0), // White
+            balanced_color:     Color::srgb(0.0, 1.0, 0.0), // Green
+            unbalanced_color:   Color::srgb(1.0, 0.0, 0.0), // Red
+            line_width:         2.0,
         }
     }
 }
@@ -1,8 +1,21 @@
 use std::collections::VecDeque;
 use std::time::Duration;
 
+use bevy::asset::RenderAssetUsages;
+use bevy::camera::CameraProjection;
+use bevy::camera::SubCameraView;
+use bevy::camera::visibility::RenderLayers;
+use bevy::ecs::system::RunSystemOnce;
+use bevy::math::Vec3A;
+use bevy::math::bounding::Aabb3d;
+use bevy::math::bounding::BoundingSphere;
 use bevy::math::curve::easing::EaseFunction;
+use bevy::mesh::PrimitiveTopology;
+use bevy::mesh::VertexAttributeValues;
+use bevy::mesh::skinning::SkinnedMesh;
+use bevy::mesh::skinning::SkinnedMeshInverseBindposes;
 use bevy::prelude::*;
+use bevy::time::TimeUpdateStrategy;
 use bevy_panorbit_camera::PanOrbitCamera;
 use bevy_panorbit_camera_ext::AnimateToFit;
 use bevy_panorbit_camera_ext::AnimationBegin;
@@ -10,13 +23,45 @@ use bevy_panorbit_camera_ext::AnimationCancelled;
 use bevy_panorbit_camera_ext::AnimationConflictPolicy;
 use bevy_panorbit_camera_ext::AnimationEnd;
 use bevy_panorbit_camera_ext::AnimationRejected;
+use bevy_panorbit_camera_ext::AutoFit;
+use bevy_panorbit_camera_ext::BestView;
 use bevy_panorbit_camera_ext::CameraInputInterruptBehavior;
 use bevy_panorbit_camera_ext::CameraMove;
 use bevy_panorbit_camera_ext::CameraMoveList;
+use bevy_panorbit_camera_ext::CurrentFitOptions;
 use bevy_panorbit_camera_ext::CurrentFitTarget;
+use bevy_panorbit_camera_ext::DeferredFit;
+use bevy_panorbit_camera_ext::FitAlgorithm;
+use bevy_panorbit_camera_ext::FitAnchor;
+use bevy_panorbit_camera_ext::FitError;
+use bevy_panorbit_camera_ext::FitExecution;
+use bevy_panorbit_camera_ext::FitFailed;
+use bevy_panorbit_camera_ext::FitFallback;
+use bevy_panorbit_camera_ext::FitFilter;
+use bevy_panorbit_camera_ext::FitGeometry;
+use bevy_panorbit_camera_ext::FitGeometryAppExt;
+use bevy_panorbit_camera_ext::FitIgnore;
+use bevy_panorbit_camera_ext::FitLimited;
+use bevy_panorbit_camera_ext::FitLimits;
+use bevy_panorbit_camera_ext::FitMargins;
+use bevy_panorbit_camera_ext::FitOptions;
+use bevy_panorbit_camera_ext::FitPose;
+use bevy_panorbit_camera_ext::FitPreview;
+use bevy_panorbit_camera_ext::FitProjection;
+use bevy_panorbit_camera_ext::FitProjectionAppExt;
+use bevy_panorbit_camera_ext::FitShape;
+use bevy_panorbit_camera_ext::FitSolver;
+use bevy_panorbit_camera_ext::FitStrategy;
+use bevy_panorbit_camera_ext::FitTarget;
+use bevy_panorbit_camera_ext::FitVolume;
+use bevy_panorbit_camera_ext::MaintainFit;
+use bevy_panorbit_camera_ext::MeshRegion;
 use bevy_panorbit_camera_ext::PanOrbitCameraExtPlugin;
+use bevy_panorbit_camera_ext::PendingFit;
 use bevy_panorbit_camera_ext::PlayAnimation;
 use bevy_panorbit_camera_ext::SetFitTarget;
+use bevy_panorbit_camera_ext::TrackMode;
+use bevy_panorbit_camera_ext::TrackTarget;
 use bevy_panorbit_camera_ext::ZoomBegin;
 use bevy_panorbit_camera_ext::ZoomCancelled;
 use bevy_panorbit_camera_ext::ZoomContext;
@@ -32,6 +77,8 @@ enum LifecycleEvent {
     ZoomBegin,
     ZoomEnd,
     ZoomCancelled,
+    FitFailed(FitError),
+    FitLimited(FitLimits),
 }
 
 #[derive(Resource, Default, Debug)]
@@ -65,6 +112,14 @@ fn record_zoom_cancelled(_: On<ZoomCancelled>, mut log: ResMut<EventLog>) {
     log.0.push(LifecycleEvent::ZoomCancelled);
 }
 
+fn record_fit_failed(failed: On<FitFailed>, mut log: ResMut<EventLog>) {
+    log.0.push(LifecycleEvent::FitFailed(failed.reason));
+}
+
+fn record_fit_limited(limited: On<FitLimited>, mut log: ResMut<EventLog>) {
+    log.0.push(LifecycleEvent::FitLimited(limited.limits));
+}
+
 fn add_lifecycle_log_observers(app: &mut App) {
     app.init_resource::<EventLog>();
     app.add_observer(record_animation_begin);
@@ -74,6 +129,8 @@ fn add_lifecycle_log_observers(app: &mut App) {
     app.add_observer(record_zoom_begin);
     app.add_observer(record_zoom_end);
     app.add_observer(record_zoom_cancelled);
+    app.add_observer(record_fit_failed);
+    app.add_observer(record_fit_limited);
 }
 
 fn spawn_fit_camera_and_target(app: &mut App) -> (Entity, Entity) {
@@ -247,525 +304,2717 @@ fn zoom_to_fit_zero_duration_emits_zoom_begin_then_zoom_end_without_animation_qu
 }
 
 #[test]
-fn animate_to_fit_zero_duration_emits_animation_begin_then_end_without_animation_queue() {
+fn zoom_to_fit_without_mesh_emits_fit_failed_and_leaves_camera_untouched() {
     let mut app = App::new();
     app.add_plugins(MinimalPlugins);
     app.add_plugins(PanOrbitCameraExtPlugin);
     add_lifecycle_log_observers(&mut app);
 
-    let (camera, target) = spawn_fit_camera_and_target(&mut app);
+    let (camera, _) = spawn_fit_camera_and_target(&mut app);
+    let empty = app.world_mut().spawn(GlobalTransform::default()).id();
 
-    app.world_mut()
-        .trigger(AnimateToFit::new(camera, target).easing(EaseFunction::Linear));
+    app.world_mut().trigger(ZoomToFit::new(camera, empty));
     app.update();
 
     let log = app.world().resource::<EventLog>();
-    assert_eq!(
-        log.0,
-        vec![LifecycleEvent::AnimationBegin, LifecycleEvent::AnimationEnd]
-    );
+    assert_eq!(log.0, vec![LifecycleEvent::FitFailed(FitError::NoMesh)]);
+    assert!(app.world().get::<CurrentFitTarget>(camera).is_none());
     assert!(app.world().get::<CameraMoveList>(camera).is_none());
 }
 
 #[test]
-fn interrupt_cancel_emits_cancelled_and_restores_smoothness_without_jumping_to_final() {
+fn animate_to_fit_with_unloaded_mesh_reports_mesh_not_loaded() {
     let mut app = App::new();
     app.add_plugins(MinimalPlugins);
     app.add_plugins(PanOrbitCameraExtPlugin);
     add_lifecycle_log_observers(&mut app);
 
-    let camera = PanOrbitCamera {
-        zoom_smoothness: 0.25,
-        pan_smoothness: 0.5,
-        orbit_smoothness: 0.75,
-        ..default()
-    };
-    let camera = app
+    let (camera, _) = spawn_fit_camera_and_target(&mut app);
+    let loading = app
         .world_mut()
-        .spawn((camera, CameraInputInterruptBehavior::Cancel))
+        .spawn((
+            Mesh3d(Handle::<Mesh>::default()),
+            GlobalTransform::default(),
+        ))
         .id();
 
-    let first_move = CameraMove::ToOrbit {
-        focus:    Vec3::new(1.0, 2.0, 3.0),
-        yaw:      0.4,
-        pitch:    -0.2,
-        radius:   6.0,
-        duration: Duration::from_millis(800),
-        easing:   EaseFunction::Linear,
-    };
     app.world_mut()
-        .trigger(PlayAnimation::new(camera, VecDeque::from([first_move])));
-    app.update();
-
-    let sentinel_focus = Vec3::new(100.0, 200.0, 300.0);
-    let sentinel_yaw = 1.25;
-    let sentinel_pitch = -0.75;
-    let sentinel_radius = 12.5;
-    {
-        let mut camera = app
-            .world_mut()
-            .get_mut::<PanOrbitCamera>(camera)
-            .expect("camera should exist");
-        camera.target_focus = sentinel_focus;
-        camera.target_yaw = sentinel_yaw;
-        camera.target_pitch = sentinel_pitch;
-        camera.target_radius = sentinel_radius;
-    }
-
-    app.update();
+        .trigger(AnimateToFit::new(camera, loading).duration(Duration::ZERO));
     app.update();
 
     let log = app.world().resource::<EventLog>();
     assert_eq!(
         log.0,
-        vec![
-            LifecycleEvent::AnimationBegin,
-            LifecycleEvent::AnimationCancelled
-        ]
+        vec![LifecycleEvent::FitFailed(FitError::MeshNotLoaded)]
     );
-    assert!(app.world().get::<CameraMoveList>(camera).is_none());
-
-    let camera = app
-        .world()
-        .get::<PanOrbitCamera>(camera)
-        .expect("camera should exist");
-    assert_eq!(camera.zoom_smoothness, 0.25);
-    assert_eq!(camera.pan_smoothness, 0.5);
-    assert_eq!(camera.orbit_smoothness, 0.75);
-    assert!(camera.enabled);
-    assert_eq!(camera.target_focus, sentinel_focus);
-    assert_eq!(camera.target_yaw, sentinel_yaw);
-    assert_eq!(camera.target_pitch, sentinel_pitch);
-    assert_eq!(camera.target_radius, sentinel_radius);
 }
 
 #[test]
-fn interrupt_complete_emits_end_jumps_to_final_and_restores_smoothness() {
+fn zoom_to_fit_on_entity_without_camera_reports_missing_camera() {
     let mut app = App::new();
     app.add_plugins(MinimalPlugins);
     app.add_plugins(PanOrbitCameraExtPlugin);
     add_lifecycle_log_observers(&mut app);
 
-    let camera = PanOrbitCamera {
-        zoom_smoothness: 0.15,
-        pan_smoothness: 0.35,
-        orbit_smoothness: 0.55,
-        ..default()
-    };
-    let camera = app
-        .world_mut()
-        .spawn((camera, CameraInputInterruptBehavior::Complete))
-        .id();
-
-    let first_move = CameraMove::ToOrbit {
-        focus:    Vec3::new(1.0, 0.0, 0.0),
-        yaw:      0.1,
-        pitch:    0.2,
-        radius:   4.0,
-        duration: Duration::from_millis(900),
-        easing:   EaseFunction::Linear,
-    };
-    let final_move = CameraMove::ToOrbit {
-        focus:    Vec3::new(9.0, 8.0, 7.0),
-        yaw:      0.9,
-        pitch:    -0.4,
-        radius:   11.0,
-        duration: Duration::from_millis(900),
-        easing:   EaseFunction::Linear,
-    };
-    app.world_mut().trigger(PlayAnimation::new(
-        camera,
-        VecDeque::from([first_move, final_move.clone()]),
-    ));
-    app.update();
-
-    {
-        let mut camera = app
-            .world_mut()
-            .get_mut::<PanOrbitCamera>(camera)
-            .expect("camera should exist");
-        camera.target_focus = Vec3::new(-1.0, -1.0, -1.0);
-        camera.target_yaw = -1.0;
-        camera.target_pitch = -1.0;
-        camera.target_radius = 2.0;
-    }
+    let (_, target) = spawn_fit_camera_and_target(&mut app);
+    let not_a_camera = app.world_mut().spawn_empty().id();
 
-    app.update();
+    app.world_mut()
+        .trigger(ZoomToFit::new(not_a_camera, target));
     app.update();
 
     let log = app.world().resource::<EventLog>();
     assert_eq!(
         log.0,
-        vec![LifecycleEvent::AnimationBegin, LifecycleEvent::AnimationEnd]
+        vec![LifecycleEvent::FitFailed(FitError::MissingCamera)]
     );
-    assert!(app.world().get::<CameraMoveList>(camera).is_none());
+}
 
-    let camera = app
-        .world()
-        .get::<PanOrbitCamera>(camera)
-        .expect("camera should exist");
-    assert_eq!(camera.zoom_smoothness, 0.15);
-    assert_eq!(camera.pan_smoothness, 0.35);
-    assert_eq!(camera.orbit_smoothness, 0.55);
-    assert!(camera.enabled);
-    match final_move {
-        CameraMove::ToOrbit {
-            focus,
-            yaw,
-            pitch,
-            radius,
-            ..
-        } => {
-            assert_eq!(camera.target_focus, focus);
-            assert_eq!(camera.target_yaw, yaw);
-            assert_eq!(camera.target_pitch, pitch);
-            assert_eq!(camera.target_radius, radius);
-        },
-        CameraMove::ToPosition { .. } => unreachable!("test uses ToOrbit final move"),
+/// A `Projection::Custom` that renders exactly like the default perspective projection.
+#[derive(Debug, Clone, Default)]
+struct CustomPerspective(PerspectiveProjection);
+
+impl CameraProjection for CustomPerspective {
+    fn get_clip_from_view(&self) -> Mat4 { self.0.get_clip_from_view() }
+
+    fn get_clip_from_view_for_sub(&self, sub_view: &SubCameraView) -> Mat4 {
+        self.0.get_clip_from_view_for_sub(sub_view)
+    }
+
+    fn update(&mut self, width: f32, height: f32) { self.0.update(width, height); }
+
+    fn far(&self) -> f32 { self.0.far }
+
+    fn get_frustum_corners(&self, z_near: f32, z_far: f32) -> [Vec3A; 8] {
+        self.0.get_frustum_corners(z_near, z_far)
+    }
+}
+
+impl FitProjection for CustomPerspective {
+    fn project_normalized(&self, view_point: Vec3) -> Option<Vec2> {
+        self.0.project_normalized(view_point)
+    }
+
+    fn unproject_normalized(&self, normalized: Vec2, depth: f32) -> Vec3 {
+        self.0.unproject_normalized(normalized, depth)
+    }
+
+    fn half_extents(&self, viewport_size: Option<Vec2>) -> Option<Vec2> {
+        self.0.half_extents(viewport_size)
     }
 }
 
 #[test]
-fn interrupt_ignore_keeps_animation_running_and_emits_no_interrupt_events() {
+fn zoom_to_fit_with_registered_custom_projection_matches_perspective() {
     let mut app = App::new();
     app.add_plugins(MinimalPlugins);
     app.add_plugins(PanOrbitCameraExtPlugin);
+    app.register_fit_projection::<CustomPerspective>();
     add_lifecycle_log_observers(&mut app);
 
-    let camera = app
+    let (perspective_camera, target) = spawn_fit_camera_and_target(&mut app);
+    let custom_camera = app
         .world_mut()
         .spawn((
             PanOrbitCamera::default(),
-            CameraInputInterruptBehavior::Ignore,
+            Camera::default(),
+            Projection::custom(CustomPerspective::default()),
         ))
         .id();
 
-    app.world_mut().trigger(PlayAnimation::new(
-        camera,
-        VecDeque::from([make_move(Duration::from_millis(5000))]),
-    ));
-    app.update();
-
-    // Isolate interrupt behavior events.
-    app.world_mut().resource_mut::<EventLog>().0.clear();
-
-    let sentinel_focus = Vec3::new(999.0, 888.0, 777.0);
-    let sentinel_yaw = 1.25;
-    let sentinel_pitch = -0.75;
-    let sentinel_radius = 12.5;
-    {
-        let mut camera = app
-            .world_mut()
-            .get_mut::<PanOrbitCamera>(camera)
-            .expect("camera should exist");
-        camera.target_focus = sentinel_focus;
-        camera.target_yaw = sentinel_yaw;
-        camera.target_pitch = sentinel_pitch;
-        camera.target_radius = sentinel_radius;
+    for camera in [perspective_camera, custom_camera] {
+        app.world_mut().trigger(ZoomToFit::new(camera, target));
     }
-
-    app.update();
     app.update();
 
-    let log = app.world().resource::<EventLog>();
-    assert_eq!(log.0, Vec::<LifecycleEvent>::new());
-    assert!(app.world().get::<CameraMoveList>(camera).is_some());
-
-    let camera = app
-        .world()
-        .get::<PanOrbitCamera>(camera)
-        .expect("camera should exist");
-    assert!(!camera.enabled);
-    assert_ne!(camera.target_focus, sentinel_focus);
-    assert_ne!(camera.target_yaw, sentinel_yaw);
-    assert_ne!(camera.target_pitch, sentinel_pitch);
-    assert_ne!(camera.target_radius, sentinel_radius);
+    let radius = |camera| {
+        app.world()
+            .get::<PanOrbitCamera>(camera)
+            .expect("camera should exist")
+            .target_radius
+    };
+    assert!(
+        (radius(custom_camera) - radius(perspective_camera)).abs() < 1e-3,
+        "custom projection radius {} should match perspective radius {}",
+        radius(custom_camera),
+        radius(perspective_camera),
+    );
 }
 
 #[test]
-fn interrupt_default_is_ignore() {
+fn zoom_to_fit_with_unregistered_custom_projection_reports_unsupported_projection() {
     let mut app = App::new();
     app.add_plugins(MinimalPlugins);
     app.add_plugins(PanOrbitCameraExtPlugin);
     add_lifecycle_log_observers(&mut app);
 
-    // No CameraInputInterruptBehavior component inserted — should default to Ignore.
-    let camera = app.world_mut().spawn(PanOrbitCamera::default()).id();
-
-    app.world_mut().trigger(PlayAnimation::new(
-        camera,
-        VecDeque::from([make_move(Duration::from_millis(5000))]),
-    ));
-    app.update();
-    app.world_mut().resource_mut::<EventLog>().0.clear();
-
-    let camera_before_interrupt = app
-        .world()
-        .get::<PanOrbitCamera>(camera)
-        .expect("camera should exist");
-    assert!(!camera_before_interrupt.enabled);
-
-    {
-        let mut camera = app
-            .world_mut()
-            .get_mut::<PanOrbitCamera>(camera)
-            .expect("camera should exist");
-        camera.target_focus = Vec3::new(50.0, 60.0, 70.0);
-    }
+    let (_, target) = spawn_fit_camera_and_target(&mut app);
+    let camera = app
+        .world_mut()
+        .spawn((
+            PanOrbitCamera::default(),
+            Camera::default(),
+            Projection::custom(CustomPerspective::default()),
+        ))
+        .id();
 
+    app.world_mut().trigger(ZoomToFit::new(camera, target));
     app.update();
 
     let log = app.world().resource::<EventLog>();
-    assert_eq!(log.0, Vec::<LifecycleEvent>::new());
-    assert!(app.world().get::<CameraMoveList>(camera).is_some());
+    assert_eq!(
+        log.0,
+        vec![LifecycleEvent::FitFailed(FitError::UnsupportedProjection)]
+    );
 }
 
 #[test]
-fn interrupt_ignore_restores_original_enabled_state_after_completion() {
+fn zoom_to_fit_group_frames_all_entities_and_sets_group_fit_target() {
     let mut app = App::new();
     app.add_plugins(MinimalPlugins);
     app.add_plugins(PanOrbitCameraExtPlugin);
     add_lifecycle_log_observers(&mut app);
 
-    let camera = PanOrbitCamera {
-        enabled: false,
-        ..default()
-    };
-    let camera = app
+    let (camera, target) = spawn_fit_camera_and_target(&mut app);
+    let mesh_handle = app
+        .world()
+        .get::<Mesh3d>(target)
+        .expect("target should have a mesh")
+        .0
+        .clone();
+    let far_target = app
         .world_mut()
-        .spawn((camera, CameraInputInterruptBehavior::Ignore))
+        .spawn((
+            Mesh3d(mesh_handle),
+            GlobalTransform::from_translation(Vec3::new(10.0, 0.0, 0.0)),
+        ))
         .id();
 
-    app.world_mut().trigger(PlayAnimation::new(
-        camera,
-        VecDeque::from([make_move(Duration::ZERO)]),
-    ));
-
-    // Start animation and stash state.
-    app.update();
-    // Drain queue and remove CameraMoveList, triggering restore.
+    app.world_mut().trigger(ZoomToFit::new(camera, target));
     app.update();
+    let single_radius = app
+        .world()
+        .get::<PanOrbitCamera>(camera)
+        .expect("camera should exist")
+        .target_radius;
+
+    app.world_mut()
+        .trigger(ZoomToFit::new(camera, [target, far_target]));
     app.update();
 
-    let camera = app
+    let panorbit = app
         .world()
         .get::<PanOrbitCamera>(camera)
         .expect("camera should exist");
-    assert!(!camera.enabled);
-}
+    assert!(
+        panorbit.target_radius > single_radius,
+        "group radius {:.3} should exceed single-target radius {single_radius:.3}",
+        panorbit.target_radius,
+    );
+    assert!(
+        (panorbit.target_focus.x - 5.0).abs() < 0.1,
+        "group focus {:?} should be centered between both targets",
+        panorbit.target_focus,
+    );
+
+    let current = app
+        .world()
+        .get::<CurrentFitTarget>(camera)
+        .expect("fit target should be set by observer");
+    assert_eq!(current.0, FitTarget::Entities(vec![target, far_target]));
+
+    let log = app.world().resource::<EventLog>();
+    assert_eq!(
+        log.0,
+        vec![
+            LifecycleEvent::ZoomBegin,
+            LifecycleEvent::ZoomEnd,
+            LifecycleEvent::ZoomBegin,
+            LifecycleEvent::ZoomEnd,
+        ]
+    );
+}
+
+#[test]
+fn zoom_to_fit_world_space_aabb_frames_box_without_entity() {
+    let mut app = App::new();
+    app.add_plugins(MinimalPlugins);
+    app.add_plugins(PanOrbitCameraExtPlugin);
+    add_lifecycle_log_observers(&mut app);
+
+    let (camera, _) = spawn_fit_camera_and_target(&mut app);
+    let center = Vec3::new(3.0, 1.0, -2.0);
+
+    app.world_mut().trigger(
+        ZoomToFit::new(camera, Aabb3d::new(center, Vec3::splat(0.5)))
+            .duration(Duration::from_millis(500)),
+    );
+    app.update();
+
+    let log = app.world().resource::<EventLog>();
+    assert_eq!(
+        log.0,
+        vec![LifecycleEvent::ZoomBegin, LifecycleEvent::AnimationBegin]
+    );
+
+    let queue = app
+        .world()
+        .get::<CameraMoveList>(camera)
+        .expect("animated geometry fit should queue a move");
+    let focus = queue
+        .camera_moves
+        .front()
+        .expect("queue should hold the fit move")
+        .focus();
+    assert!(
+        focus.distance(center) < 0.1,
+        "focus {focus:?} should be near box center {center:?}"
+    );
+}
+
+#[test]
+fn zoom_to_fit_volumes_agree_on_box_mesh_and_sphere_is_looser() {
+    let mut app = App::new();
+    app.add_plugins(MinimalPlugins);
+    app.add_plugins(PanOrbitCameraExtPlugin);
+
+    let (camera, target) = spawn_fit_camera_and_target(&mut app);
+    let mut fit_radius = |volume: FitVolume| {
+        app.world_mut()
+            .trigger(ZoomToFit::new(camera, target).volume(volume));
+        app.update();
+        app.world()
+            .get::<PanOrbitCamera>(camera)
+            .expect("camera should exist")
+            .target_radius
+    };
+
+    let vertices = fit_radius(FitVolume::Vertices);
+    let aabb = fit_radius(FitVolume::MeshAabb);
+    let hull = fit_radius(FitVolume::ConvexHull);
+    let sphere = fit_radius(FitVolume::BoundingSphere);
+
+    // A cuboid's vertices are its box corners and its hull, so these frame identically.
+    assert!(
+        (aabb - vertices).abs() < 0.01,
+        "aabb {aabb:.3} vs vertices {vertices:.3}"
+    );
+    assert!(
+        (hull - vertices).abs() < 0.01,
+        "hull {hull:.3} vs vertices {vertices:.3}"
+    );
+    assert!(
+        sphere > vertices,
+        "sphere radius {sphere:.3} should exceed vertex radius {vertices:.3}"
+    );
+}
+
+#[test]
+fn bounding_sphere_volume_never_clips_the_sphere_from_any_direction() {
+    let mut app = App::new();
+    app.add_plugins(MinimalPlugins);
+    app.add_plugins(PanOrbitCameraExtPlugin);
+
+    let (camera, target) = spawn_fit_camera_and_target(&mut app);
+    app.update();
+
+    // The unit cube's bounding sphere, framed edge to edge by a square 45° perspective view.
+    let sphere_radius = 3.0_f32.sqrt() / 2.0;
+    let exact = sphere_radius / (std::f32::consts::FRAC_PI_4 / 2.0).sin();
+    for pitch in [0.0, 0.7, 1.4, -1.2] {
+        for step in 0..8 {
+            let yaw = step as f32 * 0.4;
+            let fit = app
+                .world_mut()
+                .run_system_once(move |mut solver: FitSolver| {
+                    solver.solve(
+                        camera,
+                        target,
+                        FitOptions::default()
+                            .yaw(yaw)
+                            .pitch(pitch)
+                            .margin(0.0)
+                            .volume(FitVolume::BoundingSphere),
+                    )
+                })
+                .expect("system should run")
+                .expect("fit should succeed")
+                .solution;
+            assert!(
+                fit.radius >= exact * 0.9999 && fit.radius <= exact * 1.015,
+                "radius {:.4} at yaw {yaw} pitch {pitch} should frame the sphere at {exact:.4}",
+                fit.radius
+            );
+        }
+    }
+}
+
+#[test]
+fn sphere_target_is_framed_edge_to_edge_without_clipping() {
+    let mut app = App::new();
+    app.add_plugins(MinimalPlugins);
+    app.add_plugins(PanOrbitCameraExtPlugin);
+
+    let (camera, _) = spawn_fit_camera_and_target(&mut app);
+    let center = Vec3::new(3.0, 1.0, -2.0);
+    for pitch in [0.0, 1.3] {
+        app.world_mut().entity_mut(camera).insert(PanOrbitCamera {
+            yaw: Some(0.9),
+            pitch: Some(pitch),
+            target_yaw: 0.9,
+            target_pitch: pitch,
+            ..default()
+        });
+        app.world_mut()
+            .trigger(ZoomToFit::new(camera, BoundingSphere::new(center, 2.0)).margin(0.0));
+        app.update();
+
+        // A square 45° perspective view frames the sphere edge to edge at r / sin(22.5°).
+        let exact = 2.0 / (std::f32::consts::FRAC_PI_4 / 2.0).sin();
+        let panorbit = app
+            .world()
+            .get::<PanOrbitCamera>(camera)
+            .expect("camera should exist");
+        assert!(panorbit.target_focus.distance(center) < 0.05);
+        assert!(
+            panorbit.target_radius >= exact * 0.9999 && panorbit.target_radius <= exact * 1.015,
+            "radius {:.4} at pitch {pitch} should frame the sphere at {exact:.4}",
+            panorbit.target_radius
+        );
+    }
+}
+
+#[test]
+fn zoom_to_fit_analytic_algorithm_matches_binary_search() {
+    let mut app = App::new();
+    app.add_plugins(MinimalPlugins);
+    app.add_plugins(PanOrbitCameraExtPlugin);
+
+    let (camera, target) = spawn_fit_camera_and_target(&mut app);
+    let mut fit = |algorithm: FitAlgorithm| {
+        app.world_mut().entity_mut(camera).insert(algorithm);
+        app.world_mut().trigger(ZoomToFit::new(camera, target));
+        app.update();
+        let panorbit = app
+            .world()
+            .get::<PanOrbitCamera>(camera)
+            .expect("camera should exist");
+        (panorbit.target_focus, panorbit.target_radius)
+    };
+
+    let (binary_focus, binary_radius) = fit(FitAlgorithm::BinarySearch);
+    let (analytic_focus, analytic_radius) = fit(FitAlgorithm::Analytic);
+
+    assert!(
+        (analytic_radius - binary_radius).abs() < 0.01,
+        "analytic radius {analytic_radius:.4} vs binary search {binary_radius:.4}"
+    );
+    assert!(
+        analytic_focus.distance(binary_focus) < 0.01,
+        "analytic focus {analytic_focus:?} vs binary search {binary_focus:?}"
+    );
+}
+
+#[test]
+fn zoom_to_fit_anchor_moves_target_off_center_and_records_anchor() {
+    let mut app = App::new();
+    app.add_plugins(MinimalPlugins);
+    app.add_plugins(PanOrbitCameraExtPlugin);
+
+    let (camera, target) = spawn_fit_camera_and_target(&mut app);
+    let mut fit = |anchor: FitAnchor| {
+        app.world_mut()
+            .trigger(ZoomToFit::new(camera, target).anchor(anchor));
+        app.update();
+        let panorbit = app
+            .world()
+            .get::<PanOrbitCamera>(camera)
+            .expect("camera should exist");
+        (panorbit.target_focus, panorbit.target_radius)
+    };
+
+    let (centered_focus, centered_radius) = fit(FitAnchor::Center);
+    let (anchored_focus, anchored_radius) = fit(FitAnchor::THIRDS_BOTTOM_LEFT);
+
+    // Looking down -Z, placing the target left of and below center moves the focus right and up.
+    assert!(
+        anchored_focus.x > centered_focus.x + 0.1 && anchored_focus.y > centered_focus.y + 0.1,
+        "anchored focus {anchored_focus:?} should be right of and above {centered_focus:?}"
+    );
+    assert!(
+        anchored_radius > centered_radius,
+        "anchored radius {anchored_radius:.3} should leave room for the margins nearest the anchor"
+    );
+    let options = app
+        .world()
+        .get::<CurrentFitOptions>(camera)
+        .expect("fit should record its options");
+    assert_eq!(options.anchor, FitAnchor::THIRDS_BOTTOM_LEFT);
+}
+
+#[test]
+fn zoom_to_fit_leaves_out_ignored_hidden_and_other_layer_descendants() {
+    let mut app = App::new();
+    app.add_plugins(MinimalPlugins);
+    app.add_plugins(PanOrbitCameraExtPlugin);
+
+    let (camera, target) = spawn_fit_camera_and_target(&mut app);
+    let fit_radius = |app: &mut App| {
+        app.world_mut().trigger(ZoomToFit::new(camera, target));
+        app.update();
+        app.world()
+            .get::<PanOrbitCamera>(camera)
+            .expect("camera should exist")
+            .target_radius
+    };
+    let cube_radius = fit_radius(&mut app);
+
+    // Each child is a ground plane far larger than the cube.
+    let plane = app
+        .world_mut()
+        .resource_mut::<Assets<Mesh>>()
+        .add(Cuboid::new(20.0, 0.1, 20.0));
+    let spawn_plane = |app: &mut App, extra: (InheritedVisibility, RenderLayers)| {
+        app.world_mut()
+            .spawn((
+                Mesh3d(plane.clone()),
+                GlobalTransform::default(),
+                ChildOf(target),
+                extra,
+            ))
+            .id()
+    };
+    let ignored = spawn_plane(
+        &mut app,
+        (InheritedVisibility::VISIBLE, RenderLayers::default()),
+    );
+    app.world_mut().entity_mut(ignored).insert(FitIgnore);
+    spawn_plane(
+        &mut app,
+        (InheritedVisibility::HIDDEN, RenderLayers::default()),
+    );
+    spawn_plane(
+        &mut app,
+        (InheritedVisibility::VISIBLE, RenderLayers::layer(1)),
+    );
+
+    let unfiltered_radius = fit_radius(&mut app);
+    assert!(
+        unfiltered_radius > cube_radius * 5.0,
+        "without a FitFilter the hidden and other-layer planes should be framed ({unfiltered_radius:.3} vs {cube_radius:.3})"
+    );
+
+    app.world_mut().entity_mut(camera).insert(FitFilter {
+        visible_only:  true,
+        render_layers: true,
+    });
+    let filtered_radius = fit_radius(&mut app);
+    assert!(
+        (filtered_radius - cube_radius).abs() < 0.01,
+        "filtered radius {filtered_radius:.4} should frame only the cube ({cube_radius:.4})"
+    );
+}
+
+#[test]
+fn zoom_to_fit_refits_after_target_mesh_is_modified() {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, AssetPlugin::default()));
+    app.init_asset::<Mesh>();
+    app.add_plugins(PanOrbitCameraExtPlugin);
+
+    let (camera, target) = spawn_fit_camera_and_target(&mut app);
+    let fit_radius = |app: &mut App| {
+        app.world_mut().trigger(ZoomToFit::new(camera, target));
+        app.update();
+        app.world()
+            .get::<PanOrbitCamera>(camera)
+            .expect("camera should exist")
+            .target_radius
+    };
+
+    let original = fit_radius(&mut app);
+    // A repeated fit reads the cached points and lands on the same radius.
+    assert!((fit_radius(&mut app) - original).abs() < 1e-4);
+
+    let handle = app
+        .world()
+        .get::<Mesh3d>(target)
+        .expect("target should have a mesh")
+        .0
+        .clone();
+    app.world_mut()
+        .resource_mut::<Assets<Mesh>>()
+        .insert(&handle, Cuboid::new(2.0, 2.0, 2.0).into())
+        .expect("mesh handle should be valid");
+    app.update();
+
+    let resized = fit_radius(&mut app);
+    assert!(
+        (resized - 2.0 * original).abs() < 0.05,
+        "radius {resized:.3} should double after the mesh doubles in size (was {original:.3})"
+    );
+}
+
+/// Binds every vertex of `target`'s mesh fully to a single joint at `joint_offset` whose bind
+/// pose is the origin, so the animated pose is the mesh moved by `joint_offset`.
+fn skin_to_offset_joint(app: &mut App, target: Entity, joint_offset: Vec3) {
+    let joint = app
+        .world_mut()
+        .spawn(GlobalTransform::from_translation(joint_offset))
+        .id();
+    let handle = app
+        .world()
+        .get::<Mesh3d>(target)
+        .expect("target should have a mesh")
+        .0
+        .clone();
+    {
+        let mut meshes = app.world_mut().resource_mut::<Assets<Mesh>>();
+        let mesh = meshes.get_mut(&handle).expect("mesh should be loaded");
+        let vertex_count = mesh.count_vertices();
+        mesh.insert_attribute(
+            Mesh::ATTRIBUTE_JOINT_INDEX,
+            VertexAttributeValues::Uint16x4(vec![[0; 4]; vertex_count]),
+        );
+        mesh.insert_attribute(
+            Mesh::ATTRIBUTE_JOINT_WEIGHT,
+            vec![[1.0_f32, 0.0, 0.0, 0.0]; vertex_count],
+        );
+    }
+    let inverse_bindposes = app
+        .world_mut()
+        .resource_mut::<Assets<SkinnedMeshInverseBindposes>>()
+        .add(SkinnedMeshInverseBindposes::from(vec![Mat4::IDENTITY]));
+    app.world_mut().entity_mut(target).insert(SkinnedMesh {
+        inverse_bindposes,
+        joints: vec![joint],
+    });
+}
+
+#[test]
+fn zoom_to_fit_animated_pose_frames_skinned_mesh_where_its_joint_moved_it() {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, AssetPlugin::default()));
+    app.init_asset::<Mesh>();
+    app.init_asset::<SkinnedMeshInverseBindposes>();
+    app.add_plugins(PanOrbitCameraExtPlugin);
+
+    let (camera, target) = spawn_fit_camera_and_target(&mut app);
+    let joint_offset = Vec3::new(4.0, 0.0, 0.0);
+    skin_to_offset_joint(&mut app, target, joint_offset);
+
+    let mut fit_focus = |pose: FitPose| {
+        app.world_mut()
+            .trigger(ZoomToFit::new(camera, target).pose(pose));
+        app.update();
+        app.world()
+            .get::<PanOrbitCamera>(camera)
+            .expect("camera should exist")
+            .target_focus
+    };
+
+    let bind = fit_focus(FitPose::BindPose);
+    let animated = fit_focus(FitPose::Animated);
+
+    assert!(
+        bind.distance(Vec3::ZERO) < 0.1,
+        "bind pose focus {bind:?} should stay on the mesh's rest position"
+    );
+    assert!(
+        animated.distance(joint_offset) < 0.1,
+        "animated focus {animated:?} should follow the joint to {joint_offset:?}"
+    );
+}
+
+#[test]
+fn animate_to_fit_zero_duration_emits_animation_begin_then_end_without_animation_queue() {
+    let mut app = App::new();
+    app.add_plugins(MinimalPlugins);
+    app.add_plugins(PanOrbitCameraExtPlugin);
+    add_lifecycle_log_observers(&mut app);
+
+    let (camera, target) = spawn_fit_camera_and_target(&mut app);
+
+    app.world_mut()
+        .trigger(AnimateToFit::new(camera, target).easing(EaseFunction::Linear));
+    app.update();
+
+    let log = app.world().resource::<EventLog>();
+    assert_eq!(
+        log.0,
+        vec![LifecycleEvent::AnimationBegin, LifecycleEvent::AnimationEnd]
+    );
+    assert!(app.world().get::<CameraMoveList>(camera).is_none());
+}
+
+#[test]
+fn animate_to_fit_respects_pitch_and_zoom_limits_and_reports_them() {
+    let mut app = App::new();
+    app.add_plugins(MinimalPlugins);
+    app.add_plugins(PanOrbitCameraExtPlugin);
+    add_lifecycle_log_observers(&mut app);
+
+    let (camera, target) = spawn_fit_camera_and_target(&mut app);
+    {
+        let mut panorbit = app
+            .world_mut()
+            .get_mut::<PanOrbitCamera>(camera)
+            .expect("camera should exist");
+        panorbit.pitch_upper_limit = Some(0.5);
+        panorbit.zoom_lower_limit = 10.0;
+    }
+
+    app.world_mut().trigger(
+        AnimateToFit::new(camera, target)
+            .yaw(0.3)
+            .pitch(1.2)
+            .duration(Duration::ZERO),
+    );
+    app.update();
+
+    let log = app.world().resource::<EventLog>();
+    assert_eq!(
+        log.0,
+        vec![
+            LifecycleEvent::FitLimited(FitLimits {
+                yaw:        false,
+                pitch:      true,
+                zoom:       true,
+                near_plane: false,
+                min_radius: false,
+            }),
+            LifecycleEvent::AnimationBegin,
+            LifecycleEvent::AnimationEnd,
+        ]
+    );
+    let panorbit = app
+        .world()
+        .get::<PanOrbitCamera>(camera)
+        .expect("camera should exist");
+    assert_eq!(panorbit.target_pitch, 0.5);
+    assert_eq!(panorbit.target_yaw, 0.3);
+    assert_eq!(panorbit.target_radius, 10.0);
+}
+
+#[test]
+fn zoom_to_fit_within_limits_emits_no_fit_limited() {
+    let mut app = App::new();
+    app.add_plugins(MinimalPlugins);
+    app.add_plugins(PanOrbitCameraExtPlugin);
+    add_lifecycle_log_observers(&mut app);
+
+    let (camera, target) = spawn_fit_camera_and_target(&mut app);
+    app.world_mut()
+        .get_mut::<PanOrbitCamera>(camera)
+        .expect("camera should exist")
+        .zoom_upper_limit = Some(100.0);
+
+    app.world_mut().trigger(ZoomToFit::new(camera, target));
+    app.update();
+
+    let log = app.world().resource::<EventLog>();
+    assert_eq!(
+        log.0,
+        vec![LifecycleEvent::ZoomBegin, LifecycleEvent::ZoomEnd]
+    );
+}
+
+#[test]
+fn play_animation_interpolation_stops_at_zoom_limit() {
+    let mut app = App::new();
+    app.add_plugins(MinimalPlugins);
+    app.add_plugins(PanOrbitCameraExtPlugin);
+
+    let camera = app
+        .world_mut()
+        .spawn(PanOrbitCamera {
+            target_radius: 5.0,
+            zoom_upper_limit: Some(8.0),
+            ..default()
+        })
+        .id();
+
+    let far_move = CameraMove::ToOrbit {
+        focus:    Vec3::ZERO,
+        yaw:      0.0,
+        pitch:    0.0,
+        radius:   50.0,
+        duration: Duration::from_millis(1),
+        easing:   EaseFunction::Linear,
+    };
+    app.world_mut()
+        .trigger(PlayAnimation::new(camera, VecDeque::from([far_move])));
+
+    for _ in 0..5 {
+        std::thread::sleep(Duration::from_millis(2));
+        app.update();
+        let radius = app
+            .world()
+            .get::<PanOrbitCamera>(camera)
+            .expect("camera should exist")
+            .target_radius;
+        assert!(
+            radius <= 8.0,
+            "radius {radius} should stay within the limit"
+        );
+    }
+    assert!(app.world().get::<CameraMoveList>(camera).is_none());
+}
+
+#[test]
+fn auto_fit_chooses_a_view_from_above_and_frames_the_target() {
+    let mut app = App::new();
+    app.add_plugins(MinimalPlugins);
+    app.add_plugins(PanOrbitCameraExtPlugin);
+    add_lifecycle_log_observers(&mut app);
+
+    let (camera, target) = spawn_fit_camera_and_target(&mut app);
+
+    app.world_mut()
+        .trigger(AutoFit::new(camera, target).view(BestView::PrincipalAxes));
+    app.update();
+
+    let log = app.world().resource::<EventLog>();
+    assert_eq!(
+        log.0,
+        vec![LifecycleEvent::AnimationBegin, LifecycleEvent::AnimationEnd]
+    );
+    let panorbit = app
+        .world()
+        .get::<PanOrbitCamera>(camera)
+        .expect("camera should exist");
+    assert!(
+        panorbit.target_pitch > 0.0,
+        "a 3/4 view should look down at the cube"
+    );
+    assert!(
+        panorbit.target_radius > 0.5,
+        "the cube should be framed from outside it"
+    );
+    assert_eq!(
+        app.world()
+            .get::<CurrentFitTarget>(camera)
+            .map(|current| current.0.clone()),
+        Some(FitTarget::Entity(target))
+    );
+}
+
+fn perspective_fov(app: &App, camera: Entity) -> f32 {
+    match app.world().get::<Projection>(camera) {
+        Some(Projection::Perspective(perspective)) => perspective.fov,
+        _ => panic!("camera should have a perspective projection"),
+    }
+}
+
+#[test]
+fn field_of_view_strategy_zooms_the_lens_and_keeps_the_radius() {
+    let mut app = App::new();
+    app.add_plugins(MinimalPlugins);
+    app.add_plugins(PanOrbitCameraExtPlugin);
+    add_lifecycle_log_observers(&mut app);
+
+    let (camera, target) = spawn_fit_camera_and_target(&mut app);
+    app.world_mut()
+        .entity_mut(camera)
+        .insert(FitStrategy::FieldOfView);
+    app.world_mut()
+        .get_mut::<PanOrbitCamera>(camera)
+        .expect("camera should exist")
+        .target_radius = 20.0;
+    let initial_fov = perspective_fov(&app, camera);
+
+    app.world_mut().trigger(ZoomToFit::new(camera, target));
+    app.update();
+
+    let log = app.world().resource::<EventLog>();
+    assert_eq!(
+        log.0,
+        vec![LifecycleEvent::ZoomBegin, LifecycleEvent::ZoomEnd]
+    );
+    let panorbit = app
+        .world()
+        .get::<PanOrbitCamera>(camera)
+        .expect("camera should exist");
+    assert_eq!(panorbit.target_radius, 20.0);
+    assert!(
+        perspective_fov(&app, camera) < initial_fov,
+        "a distant camera should narrow the lens to frame the cube"
+    );
+}
+
+#[test]
+fn animated_field_of_view_fit_moves_the_lens_through_the_move_list() {
+    let mut app = App::new();
+    app.add_plugins(MinimalPlugins);
+    app.add_plugins(PanOrbitCameraExtPlugin);
+
+    let (camera, target) = spawn_fit_camera_and_target(&mut app);
+    app.world_mut()
+        .entity_mut(camera)
+        .insert(FitStrategy::FieldOfView);
+    app.world_mut()
+        .get_mut::<PanOrbitCamera>(camera)
+        .expect("camera should exist")
+        .target_radius = 20.0;
+    let initial_fov = perspective_fov(&app, camera);
+
+    app.world_mut()
+        .trigger(ZoomToFit::new(camera, target).duration(Duration::from_millis(1)));
+    app.update();
+
+    let queued = app
+        .world()
+        .get::<CameraMoveList>(camera)
+        .and_then(|queue| queue.camera_moves.front().cloned())
+        .expect("animated fit should queue a move");
+    let Some(final_fov) = queued.fov() else {
+        panic!("field-of-view fit should queue a ToOrbitWithFov move");
+    };
+    assert!(final_fov < initial_fov);
+
+    for _ in 0..5 {
+        std::thread::sleep(Duration::from_millis(2));
+        app.update();
+    }
+    assert!(app.world().get::<CameraMoveList>(camera).is_none());
+    assert_eq!(perspective_fov(&app, camera), final_fov);
+    assert_eq!(
+        app.world()
+            .get::<PanOrbitCamera>(camera)
+            .expect("camera should exist")
+            .target_radius,
+        20.0
+    );
+}
+
+#[test]
+fn interrupt_cancel_emits_cancelled_and_restores_smoothness_without_jumping_to_final() {
+    let mut app = App::new();
+    app.add_plugins(MinimalPlugins);
+    app.add_plugins(PanOrbitCameraExtPlugin);
+    add_lifecycle_log_observers(&mut app);
+
+    let camera = PanOrbitCamera {
+        zoom_smoothness: 0.25,
+        pan_smoothness: 0.5,
+        orbit_smoothness: 0.75,
+        ..default()
+    };
+    let camera = app
+        .world_mut()
+        .spawn((camera, CameraInputInterruptBehavior::Cancel))
+        .id();
+
+    let first_move = CameraMove::ToOrbit {
+        focus:    Vec3::new(1.0, 2.0, 3.0),
+        yaw:      0.4,
+        pitch:    -0.2,
+        radius:   6.0,
+        duration: Duration::from_millis(800),
+        easing:   EaseFunction::Linear,
+    };
+    app.world_mut()
+        .trigger(PlayAnimation::new(camera, VecDeque::from([first_move])));
+    app.update();
+
+    let sentinel_focus = Vec3::new(100.0, 200.0, 300.0);
+    let sentinel_yaw = 1.25;
+    let sentinel_pitch = -0.75;
+    let sentinel_radius = 12.5;
+    {
+        let mut camera = app
+            .world_mut()
+            .get_mut::<PanOrbitCamera>(camera)
+            .expect("camera should exist");
+        camera.target_focus = sentinel_focus;
+        camera.target_yaw = sentinel_yaw;
+        camera.target_pitch = sentinel_pitch;
+        camera.target_radius = sentinel_radius;
+    }
+
+    app.update();
+    app.update();
+
+    let log = app.world().resource::<EventLog>();
+    assert_eq!(
+        log.0,
+        vec![
+            LifecycleEvent::AnimationBegin,
+            LifecycleEvent::AnimationCancelled
+        ]
+    );
+    assert!(app.world().get::<CameraMoveList>(camera).is_none());
+
+    let camera = app
+        .world()
+        .get::<PanOrbitCamera>(camera)
+        .expect("camera should exist");
+    assert_eq!(camera.zoom_smoothness, 0.25);
+    assert_eq!(camera.pan_smoothness, 0.5);
+    assert_eq!(camera.orbit_smoothness, 0.75);
+    assert!(camera.enabled);
+    assert_eq!(camera.target_focus, sentinel_focus);
+    assert_eq!(camera.target_yaw, sentinel_yaw);
+    assert_eq!(camera.target_pitch, sentinel_pitch);
+    assert_eq!(camera.target_radius, sentinel_radius);
+}
+
+#[test]
+fn interrupt_complete_emits_end_jumps_to_final_and_restores_smoothness() {
+    let mut app = App::new();
+    app.add_plugins(MinimalPlugins);
+    app.add_plugins(PanOrbitCameraExtPlugin);
+    add_lifecycle_log_observers(&mut app);
+
+    let camera = PanOrbitCamera {
+        zoom_smoothness: 0.15,
+        pan_smoothness: 0.35,
+        orbit_smoothness: 0.55,
+        ..default()
+    };
+    let camera = app
+        .world_mut()
+        .spawn((camera, CameraInputInterruptBehavior::Complete))
+        .id();
+
+    let first_move = CameraMove::ToOrbit {
+        focus:    Vec3::new(1.0, 0.0, 0.0),
+        yaw:      0.1,
+        pitch:    0.2,
+        radius:   4.0,
+        duration: Duration::from_millis(900),
+        easing:   EaseFunction::Linear,
+    };
+    let final_move = CameraMove::ToOrbit {
+        focus:    Vec3::new(9.0, 8.0, 7.0),
+        yaw:      0.9,
+        pitch:    -0.4,
+        radius:   11.0,
+        duration: Duration::from_millis(900),
+        easing:   EaseFunction::Linear,
+    };
+    app.world_mut().trigger(PlayAnimation::new(
+        camera,
+        VecDeque::from([first_move, final_move.clone()]),
+    ));
+    app.update();
+
+    {
+        let mut camera = app
+            .world_mut()
+            .get_mut::<PanOrbitCamera>(camera)
+            .expect("camera should exist");
+        camera.target_focus = Vec3::new(-1.0, -1.0, -1.0);
+        camera.target_yaw = -1.0;
+        camera.target_pitch = -1.0;
+        camera.target_radius = 2.0;
+    }
+
+    app.update();
+    app.update();
+
+    let log = app.world().resource::<EventLog>();
+    assert_eq!(
+        log.0,
+        vec![LifecycleEvent::AnimationBegin, LifecycleEvent::AnimationEnd]
+    );
+    assert!(app.world().get::<CameraMoveList>(camera).is_none());
+
+    let camera = app
+        .world()
+        .get::<PanOrbitCamera>(camera)
+        .expect("camera should exist");
+    assert_eq!(camera.zoom_smoothness, 0.15);
+    assert_eq!(camera.pan_smoothness, 0.35);
+    assert_eq!(camera.orbit_smoothness, 0.55);
+    assert!(camera.enabled);
+    match final_move {
+        CameraMove::ToOrbit {
+            focus,
+            yaw,
+            pitch,
+            radius,
+            ..
+        } => {
+            assert_eq!(camera.target_focus, focus);
+            assert_eq!(camera.target_yaw, yaw);
+            assert_eq!(camera.target_pitch, pitch);
+            assert_eq!(camera.target_radius, radius);
+        },
+        CameraMove::ToPosition { .. } | CameraMove::ToOrbitWithFov { .. } => {
+            unreachable!("test uses ToOrbit final move")
+        },
+    }
+}
+
+#[test]
+fn interrupt_ignore_keeps_animation_running_and_emits_no_interrupt_events() {
+    let mut app = App::new();
+    app.add_plugins(MinimalPlugins);
+    app.add_plugins(PanOrbitCameraExtPlugin);
+    add_lifecycle_log_observers(&mut app);
+
+    let camera = app
+        .world_mut()
+        .spawn((
+            PanOrbitCamera::default(),
+            CameraInputInterruptBehavior::Ignore,
+        ))
+        .id();
+
+    app.world_mut().trigger(PlayAnimation::new(
+        camera,
+        VecDeque::from([make_move(Duration::from_millis(5000))]),
+    ));
+    app.update();
+
+    // Isolate interrupt behavior events.
+    app.world_mut().resource_mut::<EventLog>().0.clear();
+
+    let sentinel_focus = Vec3::new(999.0, 888.0, 777.0);
+    let sentinel_yaw = 1.25;
+    let sentinel_pitch = -0.75;
+    let sentinel_radius = 12.5;
+    {
+        let mut camera = app
+            .world_mut()
+            .get_mut::<PanOrbitCamera>(camera)
+            .expect("camera should exist");
+        camera.target_focus = sentinel_focus;
+        camera.target_yaw = sentinel_yaw;
+        camera.target_pitch = sentinel_pitch;
+        camera.target_radius = sentinel_radius;
+    }
+
+    app.update();
+    app.update();
+
+    let log = app.world().resource::<EventLog>();
+    assert_eq!(log.0, Vec::<LifecycleEvent>::new());
+    assert!(app.world().get::<CameraMoveList>(camera).is_some());
+
+    let camera = app
+        .world()
+        .get::<PanOrbitCamera>(camera)
+        .expect("camera should exist");
+    assert!(!camera.enabled);
+    assert_ne!(camera.target_focus, sentinel_focus);
+    assert_ne!(camera.target_yaw, sentinel_yaw);
+    assert_ne!(camera.target_pitch, sentinel_pitch);
+    assert_ne!(camera.target_radius, sentinel_radius);
+}
+
+#[test]
+fn interrupt_default_is_ignore() {
+    let mut app = App::new();
+    app.add_plugins(MinimalPlugins);
+    app.add_plugins(PanOrbitCameraExtPlugin);
+    add_lifecycle_log_observers(&mut app);
+
+    // No CameraInputInterruptBehavior component inserted — should default to Ignore.
+    let camera = app.world_mut().spawn(PanOrbitCamera::default()).id();
+
+    app.world_mut().trigger(PlayAnimation::new(
+        camera,
+        VecDeque::from([make_move(Duration::from_millis(5000))]),
+    ));
+    app.update();
+    app.world_mut().resource_mut::<EventLog>().0.clear();
+
+    let camera_before_interrupt = app
+        .world()
+        .get::<PanOrbitCamera>(camera)
+        .expect("camera should exist");
+    assert!(!camera_before_interrupt.enabled);
+
+    {
+        let mut camera = app
+            .world_mut()
+            .get_mut::<PanOrbitCamera>(camera)
+            .expect("camera should exist");
+        camera.target_focus = Vec3::new(50.0, 60.0, 70.0);
+    }
+
+    app.update();
+
+    let log = app.world().resource::<EventLog>();
+    assert_eq!(log.0, Vec::<LifecycleEvent>::new());
+    assert!(app.world().get::<CameraMoveList>(camera).is_some());
+}
+
+#[test]
+fn interrupt_ignore_restores_original_enabled_state_after_completion() {
+    let mut app = App::new();
+    app.add_plugins(MinimalPlugins);
+    app.add_plugins(PanOrbitCameraExtPlugin);
+    add_lifecycle_log_observers(&mut app);
+
+    let camera = PanOrbitCamera {
+        enabled: false,
+        ..default()
+    };
+    let camera = app
+        .world_mut()
+        .spawn((camera, CameraInputInterruptBehavior::Ignore))
+        .id();
+
+    app.world_mut().trigger(PlayAnimation::new(
+        camera,
+        VecDeque::from([make_move(Duration::ZERO)]),
+    ));
+
+    // Start animation and stash state.
+    app.update();
+    // Drain queue and remove CameraMoveList, triggering restore.
+    app.update();
+    app.update();
+
+    let camera = app
+        .world()
+        .get::<PanOrbitCamera>(camera)
+        .expect("camera should exist");
+    assert!(!camera.enabled);
+}
+
+#[test]
+fn normal_completion_restores_smoothness_after_queue_finishes() {
+    let mut app = App::new();
+    app.add_plugins(MinimalPlugins);
+    app.add_plugins(PanOrbitCameraExtPlugin);
+    add_lifecycle_log_observers(&mut app);
+
+    let camera = PanOrbitCamera {
+        zoom_smoothness: 0.45,
+        pan_smoothness: 0.55,
+        orbit_smoothness: 0.65,
+        ..default()
+    };
+    let camera = app.world_mut().spawn(camera).id();
+
+    app.world_mut().trigger(PlayAnimation::new(
+        camera,
+        VecDeque::from([make_move(Duration::ZERO)]),
+    ));
+    app.update();
+    app.update();
+    app.update();
+
+    let log = app.world().resource::<EventLog>();
+    assert_eq!(
+        log.0,
+        vec![LifecycleEvent::AnimationBegin, LifecycleEvent::AnimationEnd]
+    );
+    assert!(app.world().get::<CameraMoveList>(camera).is_none());
+
+    let camera = app
+        .world()
+        .get::<PanOrbitCamera>(camera)
+        .expect("camera should exist");
+    assert_eq!(camera.zoom_smoothness, 0.45);
+    assert_eq!(camera.pan_smoothness, 0.55);
+    assert_eq!(camera.orbit_smoothness, 0.65);
+}
+
+#[test]
+fn conflict_last_wins_animation_cancels_animation() {
+    let mut app = App::new();
+    app.add_plugins(MinimalPlugins);
+    app.add_plugins(PanOrbitCameraExtPlugin);
+    add_lifecycle_log_observers(&mut app);
+
+    let camera = app
+        .world_mut()
+        .spawn((PanOrbitCamera::default(), AnimationConflictPolicy::LastWins))
+        .id();
+
+    // Start first animation (long duration so it's still in-flight)
+    app.world_mut().trigger(PlayAnimation::new(
+        camera,
+        VecDeque::from([make_move(Duration::from_millis(5000))]),
+    ));
+    app.update();
+
+    // Clear the log to isolate the second trigger's events
+    app.world_mut().resource_mut::<EventLog>().0.clear();
+
+    // Trigger second animation while first is in-flight
+    app.world_mut().trigger(PlayAnimation::new(
+        camera,
+        VecDeque::from([make_move(Duration::from_millis(500))]),
+    ));
+    app.update();
+
+    let log = app.world().resource::<EventLog>();
+    assert_eq!(
+        log.0,
+        vec![
+            LifecycleEvent::AnimationCancelled,
+            LifecycleEvent::AnimationBegin,
+        ]
+    );
+}
+
+#[test]
+fn conflict_first_wins_rejects_second() {
+    let mut app = App::new();
+    app.add_plugins(MinimalPlugins);
+    app.add_plugins(PanOrbitCameraExtPlugin);
+    add_lifecycle_log_observers(&mut app);
+
+    let camera = app
+        .world_mut()
+        .spawn((
+            PanOrbitCamera::default(),
+            AnimationConflictPolicy::FirstWins,
+        ))
+        .id();
+
+    // Start first animation
+    app.world_mut().trigger(PlayAnimation::new(
+        camera,
+        VecDeque::from([make_move(Duration::from_millis(5000))]),
+    ));
+    app.update();
+
+    // Clear the log
+    app.world_mut().resource_mut::<EventLog>().0.clear();
+
+    // Trigger second animation — should be rejected
+    app.world_mut().trigger(PlayAnimation::new(
+        camera,
+        VecDeque::from([make_move(Duration::from_millis(500))]),
+    ));
+    app.update();
+
+    let log = app.world().resource::<EventLog>();
+    assert_eq!(log.0, vec![LifecycleEvent::AnimationRejected]);
+
+    // Original queue should still be present
+    assert!(app.world().get::<CameraMoveList>(camera).is_some());
+}
+
+#[test]
+fn conflict_first_wins_allows_after_completion() {
+    let mut app = App::new();
+    app.add_plugins(MinimalPlugins);
+    app.add_plugins(PanOrbitCameraExtPlugin);
+    add_lifecycle_log_observers(&mut app);
+
+    let camera = app
+        .world_mut()
+        .spawn((
+            PanOrbitCamera::default(),
+            AnimationConflictPolicy::FirstWins,
+        ))
+        .id();
+
+    // Start a zero-duration animation (completes instantly)
+    app.world_mut().trigger(PlayAnimation::new(
+        camera,
+        VecDeque::from([make_move(Duration::ZERO)]),
+    ));
+    app.update();
+    app.update();
+    app.update();
+
+    // Clear the log
+    app.world_mut().resource_mut::<EventLog>().0.clear();
+
+    // New animation should succeed since queue is gone
+    app.world_mut().trigger(PlayAnimation::new(
+        camera,
+        VecDeque::from([make_move(Duration::ZERO)]),
+    ));
+    app.update();
+    app.update();
+    app.update();
+
+    let log = app.world().resource::<EventLog>();
+    assert_eq!(
+        log.0,
+        vec![LifecycleEvent::AnimationBegin, LifecycleEvent::AnimationEnd]
+    );
+}
+
+#[test]
+fn conflict_default_is_last_wins() {
+    let mut app = App::new();
+    app.add_plugins(MinimalPlugins);
+    app.add_plugins(PanOrbitCameraExtPlugin);
+    add_lifecycle_log_observers(&mut app);
+
+    // No AnimationConflictPolicy component — should default to LastWins
+    let camera = app.world_mut().spawn(PanOrbitCamera::default()).id();
+
+    // Start first animation
+    app.world_mut().trigger(PlayAnimation::new(
+        camera,
+        VecDeque::from([make_move(Duration::from_millis(5000))]),
+    ));
+    app.update();
+
+    // Clear the log
+    app.world_mut().resource_mut::<EventLog>().0.clear();
+
+    // Trigger second animation — should cancel first (LastWins behavior)
+    app.world_mut().trigger(PlayAnimation::new(
+        camera,
+        VecDeque::from([make_move(Duration::from_millis(500))]),
+    ));
+    app.update();
+
+    let log = app.world().resource::<EventLog>();
+    assert_eq!(
+        log.0,
+        vec![
+            LifecycleEvent::AnimationCancelled,
+            LifecycleEvent::AnimationBegin,
+        ]
+    );
+}
+
+fn make_zoom_context() -> ZoomContext {
+    ZoomContext {
+        target:   Entity::PLACEHOLDER.into(),
+        margin:   FitMargins::uniform(0.1),
+        duration: Duration::from_millis(500),
+        easing:   EaseFunction::Linear,
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Zoom lifecycle: cancellation / rejection / interrupt scenarios
+// ---------------------------------------------------------------------------
+
+#[test]
+fn zoom_animated_first_wins_rejection_emits_only_animation_rejected() {
+    let mut app = App::new();
+    app.add_plugins(MinimalPlugins);
+    app.add_plugins(PanOrbitCameraExtPlugin);
+    add_lifecycle_log_observers(&mut app);
+
+    let (camera, target) = spawn_fit_camera_and_target(&mut app);
+    app.world_mut()
+        .entity_mut(camera)
+        .insert(AnimationConflictPolicy::FirstWins);
+
+    // Start a long animation so it's still in-flight
+    app.world_mut().trigger(PlayAnimation::new(
+        camera,
+        VecDeque::from([make_move(Duration::from_millis(5000))]),
+    ));
+    app.update();
+
+    // Clear the log to isolate the zoom trigger's events
+    app.world_mut().resource_mut::<EventLog>().0.clear();
+
+    // Trigger ZoomToFit (animated) — should be rejected with no ZoomBegin leak
+    app.world_mut().trigger(
+        ZoomToFit::new(camera, target)
+            .duration(Duration::from_millis(500))
+            .easing(EaseFunction::Linear),
+    );
+    app.update();
+
+    let log = app.world().resource::<EventLog>();
+    assert_eq!(log.0, vec![LifecycleEvent::AnimationRejected]);
+}
+
+#[test]
+fn zoom_animated_last_wins_cancels_plain_animation() {
+    let mut app = App::new();
+    app.add_plugins(MinimalPlugins);
+    app.add_plugins(PanOrbitCameraExtPlugin);
+    add_lifecycle_log_observers(&mut app);
+
+    let camera = app
+        .world_mut()
+        .spawn((PanOrbitCamera::default(), AnimationConflictPolicy::LastWins))
+        .id();
+
+    // Start a long plain animation (no zoom context)
+    app.world_mut().trigger(PlayAnimation::new(
+        camera,
+        VecDeque::from([make_move(Duration::from_millis(5000))]),
+    ));
+    app.update();
+
+    app.world_mut().resource_mut::<EventLog>().0.clear();
+
+    // Trigger zoom animation — should cancel plain, then begin zoom
+    app.world_mut().trigger(
+        PlayAnimation::new(
+            camera,
+            VecDeque::from([make_move(Duration::from_millis(500))]),
+        )
+        .zoom_context(make_zoom_context()),
+    );
+    app.update();
+
+    let log = app.world().resource::<EventLog>();
+    assert_eq!(
+        log.0,
+        vec![
+            LifecycleEvent::AnimationCancelled,
+            LifecycleEvent::ZoomBegin,
+            LifecycleEvent::AnimationBegin,
+        ]
+    );
+}
+
+#[test]
+fn zoom_animated_last_wins_cancels_in_flight_zoom() {
+    let mut app = App::new();
+    app.add_plugins(MinimalPlugins);
+    app.add_plugins(PanOrbitCameraExtPlugin);
+    add_lifecycle_log_observers(&mut app);
+
+    let camera = app
+        .world_mut()
+        .spawn((PanOrbitCamera::default(), AnimationConflictPolicy::LastWins))
+        .id();
+
+    // Start a long zoom animation
+    app.world_mut().trigger(
+        PlayAnimation::new(
+            camera,
+            VecDeque::from([make_move(Duration::from_millis(5000))]),
+        )
+        .zoom_context(make_zoom_context()),
+    );
+    app.update();
+
+    app.world_mut().resource_mut::<EventLog>().0.clear();
+
+    // Trigger another zoom — should cancel in-flight zoom, then begin new zoom
+    app.world_mut().trigger(
+        PlayAnimation::new(
+            camera,
+            VecDeque::from([make_move(Duration::from_millis(500))]),
+        )
+        .zoom_context(make_zoom_context()),
+    );
+    app.update();
+
+    let log = app.world().resource::<EventLog>();
+    assert_eq!(
+        log.0,
+        vec![
+            LifecycleEvent::AnimationCancelled,
+            LifecycleEvent::ZoomCancelled,
+            LifecycleEvent::ZoomBegin,
+            LifecycleEvent::AnimationBegin,
+        ]
+    );
+}
+
+#[test]
+fn zoom_animated_cancel_interrupt_emits_cancelled_and_zoom_cancelled() {
+    let mut app = App::new();
+    app.add_plugins(MinimalPlugins);
+    app.add_plugins(PanOrbitCameraExtPlugin);
+    add_lifecycle_log_observers(&mut app);
+
+    let camera = app
+        .world_mut()
+        .spawn((
+            PanOrbitCamera::default(),
+            CameraInputInterruptBehavior::Cancel,
+        ))
+        .id();
+
+    // Start a zoom animation
+    app.world_mut().trigger(
+        PlayAnimation::new(
+            camera,
+            VecDeque::from([make_move(Duration::from_millis(5000))]),
+        )
+        .zoom_context(make_zoom_context()),
+    );
+    app.update();
+
+    // Simulate external user input by modifying camera targets
+    {
+        let mut camera = app
+            .world_mut()
+            .get_mut::<PanOrbitCamera>(camera)
+            .expect("camera should exist");
+        camera.target_focus = Vec3::new(100.0, 200.0, 300.0);
+        camera.target_yaw = 1.25;
+        camera.target_pitch = -0.75;
+        camera.target_radius = 12.5;
+    }
+
+    app.update();
+    app.update();
+
+    let log = app.world().resource::<EventLog>();
+    assert_eq!(
+        log.0,
+        vec![
+            LifecycleEvent::ZoomBegin,
+            LifecycleEvent::AnimationBegin,
+            LifecycleEvent::AnimationCancelled,
+            LifecycleEvent::ZoomCancelled,
+        ]
+    );
+    assert!(app.world().get::<CameraMoveList>(camera).is_none());
+}
+
+#[test]
+fn zoom_animated_complete_interrupt_emits_end_and_zoom_end() {
+    let mut app = App::new();
+    app.add_plugins(MinimalPlugins);
+    app.add_plugins(PanOrbitCameraExtPlugin);
+    add_lifecycle_log_observers(&mut app);
+
+    let camera = app
+        .world_mut()
+        .spawn((
+            PanOrbitCamera::default(),
+            CameraInputInterruptBehavior::Complete,
+        ))
+        .id();
+
+    // Start a zoom animation
+    app.world_mut().trigger(
+        PlayAnimation::new(
+            camera,
+            VecDeque::from([make_move(Duration::from_millis(5000))]),
+        )
+        .zoom_context(make_zoom_context()),
+    );
+    app.update();
+
+    // Simulate external user input
+    {
+        let mut camera = app
+            .world_mut()
+            .get_mut::<PanOrbitCamera>(camera)
+            .expect("camera should exist");
+        camera.target_focus = Vec3::new(-1.0, -1.0, -1.0);
+        camera.target_yaw = -1.0;
+        camera.target_pitch = -1.0;
+        camera.target_radius = 2.0;
+    }
+
+    app.update();
+    app.update();
+
+    let log = app.world().resource::<EventLog>();
+    assert_eq!(
+        log.0,
+        vec![
+            LifecycleEvent::ZoomBegin,
+            LifecycleEvent::AnimationBegin,
+            LifecycleEvent::AnimationEnd,
+            LifecycleEvent::ZoomEnd,
+        ]
+    );
+    assert!(app.world().get::<CameraMoveList>(camera).is_none());
+}
+
+#[test]
+fn zoom_animated_normal_completion_emits_full_lifecycle() {
+    let mut app = App::new();
+    app.add_plugins(MinimalPlugins);
+    app.add_plugins(PanOrbitCameraExtPlugin);
+    add_lifecycle_log_observers(&mut app);
+
+    let camera = app.world_mut().spawn(PanOrbitCamera::default()).id();
+
+    // Use a zero-duration CameraMove so the queue drains immediately
+    app.world_mut().trigger(
+        PlayAnimation::new(camera, VecDeque::from([make_move(Duration::ZERO)]))
+            .zoom_context(make_zoom_context()),
+    );
+    app.update();
+    app.update();
+    app.update();
+
+    let log = app.world().resource::<EventLog>();
+    assert_eq!(
+        log.0,
+        vec![
+            LifecycleEvent::ZoomBegin,
+            LifecycleEvent::AnimationBegin,
+            LifecycleEvent::AnimationEnd,
+            LifecycleEvent::ZoomEnd,
+        ]
+    );
+    assert!(app.world().get::<CameraMoveList>(camera).is_none());
+}
+
+#[test]
+fn track_target_focus_follows_target_past_hysteresis_only() {
+    let mut app = App::new();
+    app.add_plugins(MinimalPlugins);
+    app.add_plugins(PanOrbitCameraExtPlugin);
+
+    let (camera, target) = spawn_fit_camera_and_target(&mut app);
+    app.world_mut().entity_mut(camera).insert(
+        TrackTarget::new(target)
+            .smoothing(Duration::ZERO)
+            .hysteresis(0.1),
+    );
+    app.update();
+    let focus = |app: &App| {
+        app.world()
+            .get::<PanOrbitCamera>(camera)
+            .expect("camera should exist")
+            .target_focus
+    };
+    assert_eq!(focus(&app), Vec3::ZERO);
+
+    // Default radius is 1.0, so a 0.05 drift stays inside the hysteresis band.
+    app.world_mut()
+        .entity_mut(target)
+        .insert(GlobalTransform::from_xyz(0.05, 0.0, 0.0));
+    app.update();
+    assert_eq!(
+        focus(&app),
+        Vec3::ZERO,
+        "small motion should not move the camera"
+    );
+
+    app.world_mut()
+        .entity_mut(target)
+        .insert(GlobalTransform::from_xyz(3.0, 0.0, 0.0));
+    app.update();
+    assert!(
+        focus(&app).distance(Vec3::new(3.0, 0.0, 0.0)) < 1e-5,
+        "focus {:?} should follow the target",
+        focus(&app)
+    );
+}
+
+#[test]
+fn track_target_fit_matches_zoom_to_fit_and_user_input_overrides_it() {
+    let mut app = App::new();
+    app.add_plugins(MinimalPlugins);
+    app.add_plugins(PanOrbitCameraExtPlugin);
+
+    let (camera, target) = spawn_fit_camera_and_target(&mut app);
+    app.world_mut()
+        .trigger(ZoomToFit::new(camera, target).margin(0.1));
+    app.update();
+    let fitted_radius = app
+        .world()
+        .get::<PanOrbitCamera>(camera)
+        .expect("camera should exist")
+        .target_radius;
+
+    // Start tracking from a far-off radius; fit mode pulls it back to the fitted radius.
+    {
+        let world = app.world_mut();
+        let mut entity = world.entity_mut(camera);
+        entity
+            .get_mut::<PanOrbitCamera>()
+            .expect("camera should exist")
+            .target_radius = fitted_radius * 4.0;
+        entity.insert((
+            CameraInputInterruptBehavior::Complete,
+            TrackTarget::new(target)
+                .mode(TrackMode::Fit)
+                .margin(0.1)
+                .smoothing(Duration::ZERO)
+                .resume_delay(Duration::from_secs(3600)),
+        ));
+    }
+    app.update();
+    let radius = |app: &App| {
+        app.world()
+            .get::<PanOrbitCamera>(camera)
+            .expect("camera should exist")
+            .target_radius
+    };
+    assert!(
+        (radius(&app) - fitted_radius).abs() < 1e-3,
+        "tracked radius {:.4} should match ZoomToFit ({fitted_radius:.4})",
+        radius(&app)
+    );
+
+    // A user zoom pauses tracking instead of being pulled back.
+    let zoomed = fitted_radius * 2.0;
+    app.world_mut()
+        .get_mut::<PanOrbitCamera>(camera)
+        .expect("camera should exist")
+        .target_radius = zoomed;
+    app.update();
+    app.update();
+    assert_eq!(radius(&app), zoomed);
+    assert!(app.world().get::<TrackTarget>(camera).is_some());
+}
+
+#[test]
+fn track_target_fit_zooms_the_lens_of_a_field_of_view_camera() {
+    let mut app = App::new();
+    app.add_plugins(MinimalPlugins);
+    app.add_plugins(PanOrbitCameraExtPlugin);
+
+    let (camera, target) = spawn_fit_camera_and_target(&mut app);
+    {
+        let world = app.world_mut();
+        let mut entity = world.entity_mut(camera);
+        entity
+            .get_mut::<PanOrbitCamera>()
+            .expect("camera should exist")
+            .target_radius = 20.0;
+        entity.insert((
+            FitStrategy::FieldOfView,
+            TrackTarget::new(target)
+                .mode(TrackMode::Fit)
+                .smoothing(Duration::ZERO),
+        ));
+    }
+    let initial_fov = perspective_fov(&app, camera);
+    app.update();
+
+    let panorbit = app
+        .world()
+        .get::<PanOrbitCamera>(camera)
+        .expect("camera should exist");
+    assert_eq!(
+        panorbit.target_radius, 20.0,
+        "a field-of-view camera should not be dollied to the target"
+    );
+    assert!(
+        perspective_fov(&app, camera) < initial_fov,
+        "a distant camera should narrow the lens to frame the cube"
+    );
+}
+
+#[test]
+fn track_target_fit_animated_pose_follows_a_skinned_mesh_joint() {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, AssetPlugin::default()));
+    app.init_asset::<Mesh>();
+    app.init_asset::<SkinnedMeshInverseBindposes>();
+    app.add_plugins(PanOrbitCameraExtPlugin);
+
+    let (camera, target) = spawn_fit_camera_and_target(&mut app);
+    let joint_offset = Vec3::new(4.0, 0.0, 0.0);
+    skin_to_offset_joint(&mut app, target, joint_offset);
+    app.world_mut().entity_mut(camera).insert(
+        TrackTarget::new(target)
+            .mode(TrackMode::Fit)
+            .pose(FitPose::Animated)
+            .smoothing(Duration::ZERO),
+    );
+    app.update();
+
+    let focus = app
+        .world()
+        .get::<PanOrbitCamera>(camera)
+        .expect("camera should exist")
+        .target_focus;
+    assert!(
+        focus.distance(joint_offset) < 0.1,
+        "tracked focus {focus:?} should follow the joint to {joint_offset:?}"
+    );
+}
+
+#[test]
+fn track_target_fit_frames_an_empty_marker_with_fit_fallback() {
+    let mut app = App::new();
+    app.add_plugins(MinimalPlugins);
+    app.add_plugins(PanOrbitCameraExtPlugin);
+
+    let (camera, _) = spawn_fit_camera_and_target(&mut app);
+    let marker_position = Vec3::new(2.0, 0.0, 0.0);
+    let marker = app
+        .world_mut()
+        .spawn(GlobalTransform::from_translation(marker_position))
+        .id();
+    app.world_mut().entity_mut(camera).insert((
+        FitFallback::new(1.0),
+        TrackTarget::new(marker)
+            .mode(TrackMode::Fit)
+            .smoothing(Duration::ZERO),
+    ));
+    app.update();
+
+    let panorbit = app
+        .world()
+        .get::<PanOrbitCamera>(camera)
+        .expect("camera should exist");
+    assert!(
+        panorbit.target_focus.distance(marker_position) < 0.01,
+        "tracked focus {:?} should move to the marker",
+        panorbit.target_focus
+    );
+    assert!(
+        panorbit.target_radius > 1.0,
+        "radius {} should frame the fallback sphere",
+        panorbit.target_radius
+    );
+}
+
+#[test]
+fn track_target_is_removed_by_input_with_cancel_behavior() {
+    let mut app = App::new();
+    app.add_plugins(MinimalPlugins);
+    app.add_plugins(PanOrbitCameraExtPlugin);
+
+    let (camera, target) = spawn_fit_camera_and_target(&mut app);
+    app.world_mut().entity_mut(camera).insert((
+        CameraInputInterruptBehavior::Cancel,
+        TrackTarget::new(target),
+    ));
+    app.update();
+    assert!(app.world().get::<TrackTarget>(camera).is_some());
+
+    app.world_mut()
+        .get_mut::<PanOrbitCamera>(camera)
+        .expect("camera should exist")
+        .target_focus = Vec3::new(0.0, 2.0, 0.0);
+    app.update();
+    assert!(app.world().get::<TrackTarget>(camera).is_none());
+}
+
+#[test]
+fn fit_solver_previews_zoom_to_fit_without_moving_the_camera() {
+    let mut app = App::new();
+    app.add_plugins(MinimalPlugins);
+    app.add_plugins(PanOrbitCameraExtPlugin);
+
+    let (camera, target) = spawn_fit_camera_and_target(&mut app);
+    app.update();
+    let before = *app
+        .world()
+        .get::<PanOrbitCamera>(camera)
+        .expect("camera should exist");
+
+    let preview = app
+        .world_mut()
+        .run_system_once(move |mut solver: FitSolver| {
+            solver.solve(
+                camera,
+                target,
+                FitOptions::default().yaw(0.5).pitch(0.3).margin(0.2),
+            )
+        })
+        .expect("system should run")
+        .expect("fit should succeed");
+    let FitPreview { solution, bounds } = preview;
+
+    let after = app
+        .world()
+        .get::<PanOrbitCamera>(camera)
+        .expect("camera should exist");
+    assert_eq!(after.target_focus, before.target_focus);
+    assert_eq!(after.target_radius, before.target_radius);
+    assert_eq!(after.target_yaw, before.target_yaw);
+
+    app.world_mut().trigger(
+        AnimateToFit::new(camera, target)
+            .yaw(0.5)
+            .pitch(0.3)
+            .margin(0.2)
+            .duration(Duration::ZERO),
+    );
+    app.update();
+    let fitted = app
+        .world()
+        .get::<PanOrbitCamera>(camera)
+        .expect("camera should exist");
+    assert!((fitted.target_radius - solution.radius).abs() < 1e-4);
+    assert!(fitted.target_focus.distance(solution.focus) < 1e-4);
+    assert_eq!((solution.yaw, solution.pitch), (0.5, 0.3));
+
+    // A uniform 0.2 margin is 10% per edge: the tighter axis touches it, the other has room to
+    // spare.
+    let fraction = |margin: f32, half_extent: f32| margin / (2.0 * half_extent);
+    let horizontal = fraction(bounds.left_margin, bounds.half_extent_x)
+        .min(fraction(bounds.right_margin, bounds.half_extent_x));
+    let vertical = fraction(bounds.top_margin, bounds.half_extent_y)
+        .min(fraction(bounds.bottom_margin, bounds.half_extent_y));
+    assert!(
+        (horizontal.min(vertical) - 0.1).abs() < 0.005,
+        "tightest margin should be 10% (horizontal {horizontal:.4}, vertical {vertical:.4})"
+    );
+    assert!(horizontal >= 0.095 && vertical >= 0.095);
+}
+
+fn set_aspect_ratio(app: &mut App, camera: Entity, aspect_ratio: f32) {
+    let mut projection = app
+        .world_mut()
+        .get_mut::<Projection>(camera)
+        .expect("camera should exist");
+    let Projection::Perspective(perspective) = projection.as_mut() else {
+        panic!("camera should be perspective");
+    };
+    perspective.aspect_ratio = aspect_ratio;
+}
+
+fn solved_radius(app: &mut App, camera: Entity, target: Entity) -> f32 {
+    app.world_mut()
+        .run_system_once(move |mut solver: FitSolver| {
+            solver.solve(camera, target, FitOptions::default())
+        })
+        .expect("system should run")
+        .expect("fit should succeed")
+        .solution
+        .radius
+}
+
+#[test]
+fn maintain_fit_refits_at_rest_when_the_aspect_ratio_changes() {
+    let mut app = App::new();
+    app.add_plugins(MinimalPlugins);
+    app.add_plugins(PanOrbitCameraExtPlugin);
+
+    let (camera, target) = spawn_fit_camera_and_target(&mut app);
+    app.world_mut()
+        .entity_mut(camera)
+        .insert(MaintainFit::default());
+    app.world_mut().trigger(ZoomToFit::new(camera, target));
+    app.update();
+    let radius = |app: &App| {
+        app.world()
+            .get::<PanOrbitCamera>(camera)
+            .expect("camera should exist")
+            .target_radius
+    };
+    let square_radius = radius(&app);
+
+    // Narrowing the view makes the width the limiting axis, so the camera must back out.
+    set_aspect_ratio(&mut app, camera, 0.5);
+    app.update();
+    let expected = solved_radius(&mut app, camera, target);
+    assert!(expected > square_radius * 1.2);
+    assert!(
+        (radius(&app) - expected).abs() < 1e-4,
+        "maintained radius {:.4} should match a fresh fit ({expected:.4})",
+        radius(&app)
+    );
+}
+
+#[test]
+fn maintain_fit_retargets_an_in_flight_zoom_for_the_new_aspect_ratio() {
+    let mut app = App::new();
+    app.add_plugins(MinimalPlugins);
+    app.add_plugins(PanOrbitCameraExtPlugin);
+    add_lifecycle_log_observers(&mut app);
+
+    let (camera, target) = spawn_fit_camera_and_target(&mut app);
+    app.world_mut()
+        .entity_mut(camera)
+        .insert(MaintainFit::default());
+    app.update();
+    app.world_mut()
+        .trigger(ZoomToFit::new(camera, target).duration(Duration::from_secs(60)));
+    app.update();
+    let endpoint_radius = |app: &App| {
+        let Some(CameraMove::ToOrbit { radius, .. }) = app
+            .world()
+            .get::<CameraMoveList>(camera)
+            .expect("zoom should be in flight")
+            .camera_moves
+            .back()
+        else {
+            panic!("zoom should end in a ToOrbit move");
+        };
+        *radius
+    };
+    let square_radius = endpoint_radius(&app);
+
+    set_aspect_ratio(&mut app, camera, 0.5);
+    app.update();
+    let expected = solved_radius(&mut app, camera, target);
+    assert!(expected > square_radius * 1.2);
+    assert!(
+        (endpoint_radius(&app) - expected).abs() < 1e-4,
+        "retargeted endpoint {:.4} should match a fresh fit ({expected:.4})",
+        endpoint_radius(&app)
+    );
+    assert!(
+        !app.world()
+            .resource::<EventLog>()
+            .0
+            .contains(&LifecycleEvent::ZoomCancelled),
+        "retargeting should not cancel the zoom"
+    );
+}
+
+#[test]
+fn maintain_fit_does_not_retarget_a_field_of_view_zoom_for_its_own_lens_change() {
+    let mut app = App::new();
+    app.add_plugins(MinimalPlugins);
+    app.add_plugins(PanOrbitCameraExtPlugin);
+    app.insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_secs(1)));
+
+    let (camera, target) = spawn_fit_camera_and_target(&mut app);
+    app.world_mut()
+        .entity_mut(camera)
+        .insert((MaintainFit::default(), FitStrategy::FieldOfView));
+    app.world_mut()
+        .get_mut::<PanOrbitCamera>(camera)
+        .expect("camera should exist")
+        .target_radius = 20.0;
+    app.update();
+    app.world_mut()
+        .trigger(ZoomToFit::new(camera, target).duration(Duration::from_secs(10)));
+    app.update();
+    let endpoint_fov = |app: &App| {
+        app.world()
+            .get::<CameraMoveList>(camera)
+            .and_then(|list| list.camera_moves.back())
+            .and_then(CameraMove::fov)
+            .expect("field-of-view zoom should be in flight")
+    };
+    let initial_fov = perspective_fov(&app, camera);
+    let fov = endpoint_fov(&app);
+
+    // Growing the target would change the endpoint if the lens animation counted as a new view.
+    app.world_mut()
+        .entity_mut(target)
+        .insert(GlobalTransform::from_scale(Vec3::splat(2.0)));
+    app.update();
+    app.update();
+    assert_ne!(perspective_fov(&app, camera), initial_fov);
+    assert_eq!(endpoint_fov(&app), fov);
+}
+
+#[test]
+fn maintain_fit_retries_a_failed_refit_on_the_next_frame() {
+    let mut app = App::new();
+    app.add_plugins(MinimalPlugins);
+    app.add_plugins(PanOrbitCameraExtPlugin);
+
+    let (camera, target) = spawn_fit_camera_and_target(&mut app);
+    app.world_mut()
+        .entity_mut(camera)
+        .insert(MaintainFit::default());
+    app.world_mut().trigger(ZoomToFit::new(camera, target));
+    app.update();
+    let radius = |app: &App| {
+        app.world()
+            .get::<PanOrbitCamera>(camera)
+            .expect("camera should exist")
+            .target_radius
+    };
+    let square_radius = radius(&app);
+
+    // The mesh unloads, so the re-fit for the narrower view fails until it is back.
+    let handle = app
+        .world()
+        .get::<Mesh3d>(target)
+        .expect("target should have a mesh")
+        .0
+        .clone();
+    let mesh = app
+        .world_mut()
+        .resource_mut::<Assets<Mesh>>()
+        .remove(&handle)
+        .expect("mesh should be loaded");
+    set_aspect_ratio(&mut app, camera, 0.5);
+    app.update();
+    assert_eq!(radius(&app), square_radius);
+
+    app.world_mut()
+        .resource_mut::<Assets<Mesh>>()
+        .insert(&handle, mesh)
+        .expect("handle should accept the mesh again");
+    app.update();
+    let expected = solved_radius(&mut app, camera, target);
+    assert!(expected > square_radius * 1.2);
+    assert!((radius(&app) - expected).abs() < 1e-4);
+}
+
+/// Runs updates until the camera's background fit has been applied.
+fn update_until_fit_solved(app: &mut App, camera: Entity) {
+    for _ in 0..1000 {
+        if app.world().get::<PendingFit>(camera).is_none() {
+            return;
+        }
+        app.update();
+        std::thread::yield_now();
+    }
+    panic!("background fit should finish");
+}
+
+#[test]
+fn async_zoom_to_fit_matches_an_immediate_fit() {
+    let mut app = App::new();
+    app.add_plugins(MinimalPlugins);
+    app.add_plugins(PanOrbitCameraExtPlugin);
+    add_lifecycle_log_observers(&mut app);
+
+    let (camera, target) = spawn_fit_camera_and_target(&mut app);
+    app.update();
+    let expected = solved_radius(&mut app, camera, target);
+    app.world_mut()
+        .entity_mut(camera)
+        .insert(FitExecution::Async);
+
+    app.world_mut().trigger(ZoomToFit::new(camera, target));
+    // `World::trigger` leaves observer commands queued until the next flush.
+    app.world_mut().flush();
+    assert!(app.world().get::<PendingFit>(camera).is_some());
+    assert!(app.world().resource::<EventLog>().0.is_empty());
+    update_until_fit_solved(&mut app, camera);
+
+    let panorbit = app
+        .world()
+        .get::<PanOrbitCamera>(camera)
+        .expect("camera should exist");
+    assert!((panorbit.target_radius - expected).abs() < 1e-4);
+    assert_eq!(
+        app.world().resource::<EventLog>().0,
+        vec![LifecycleEvent::ZoomBegin, LifecycleEvent::ZoomEnd]
+    );
+    let current = app
+        .world()
+        .get::<CurrentFitTarget>(camera)
+        .expect("fit target should be recorded");
+    assert_eq!(current.0, FitTarget::from(target));
+}
 
 #[test]
-fn normal_completion_restores_smoothness_after_queue_finishes() {
+fn async_fit_is_replaced_by_a_newer_request() {
     let mut app = App::new();
     app.add_plugins(MinimalPlugins);
     app.add_plugins(PanOrbitCameraExtPlugin);
     add_lifecycle_log_observers(&mut app);
 
-    let camera = PanOrbitCamera {
-        zoom_smoothness: 0.45,
-        pan_smoothness: 0.55,
-        orbit_smoothness: 0.65,
-        ..default()
-    };
-    let camera = app.world_mut().spawn(camera).id();
-
-    app.world_mut().trigger(PlayAnimation::new(
-        camera,
-        VecDeque::from([make_move(Duration::ZERO)]),
-    ));
-    app.update();
-    app.update();
+    let (camera, target) = spawn_fit_camera_and_target(&mut app);
+    app.world_mut()
+        .entity_mut(camera)
+        .insert(FitExecution::Async);
     app.update();
 
-    let log = app.world().resource::<EventLog>();
+    app.world_mut().trigger(
+        AnimateToFit::new(camera, target)
+            .yaw(1.0)
+            .duration(Duration::ZERO),
+    );
+    app.world_mut().flush();
+    app.world_mut().trigger(
+        AnimateToFit::new(camera, target)
+            .yaw(-1.0)
+            .duration(Duration::ZERO),
+    );
+    app.world_mut().flush();
+    update_until_fit_solved(&mut app, camera);
+
+    let panorbit = app
+        .world()
+        .get::<PanOrbitCamera>(camera)
+        .expect("camera should exist");
+    assert!((panorbit.target_yaw + 1.0).abs() < 1e-6);
     assert_eq!(
-        log.0,
-        vec![LifecycleEvent::AnimationBegin, LifecycleEvent::AnimationEnd]
+        app.world().resource::<EventLog>().0,
+        vec![LifecycleEvent::AnimationBegin, LifecycleEvent::AnimationEnd],
+        "only the newer fit should run"
     );
-    assert!(app.world().get::<CameraMoveList>(camera).is_none());
+}
 
-    let camera = app
+#[test]
+fn async_fit_is_cancelled_by_a_newer_request_that_fails() {
+    let mut app = App::new();
+    app.add_plugins(MinimalPlugins);
+    app.add_plugins(PanOrbitCameraExtPlugin);
+    add_lifecycle_log_observers(&mut app);
+
+    let (camera, target) = spawn_fit_camera_and_target(&mut app);
+    let empty = app.world_mut().spawn(GlobalTransform::default()).id();
+    app.world_mut()
+        .entity_mut(camera)
+        .insert(FitExecution::Async);
+    app.update();
+
+    app.world_mut().trigger(
+        AnimateToFit::new(camera, target)
+            .yaw(1.0)
+            .duration(Duration::ZERO),
+    );
+    app.world_mut().flush();
+    app.world_mut().trigger(ZoomToFit::new(camera, empty));
+    app.world_mut().flush();
+
+    assert!(app.world().get::<PendingFit>(camera).is_none());
+    app.update();
+    let panorbit = app
         .world()
         .get::<PanOrbitCamera>(camera)
         .expect("camera should exist");
-    assert_eq!(camera.zoom_smoothness, 0.45);
-    assert_eq!(camera.pan_smoothness, 0.55);
-    assert_eq!(camera.orbit_smoothness, 0.65);
+    assert_eq!(panorbit.target_yaw, 0.0, "the stale fit should not run");
+    assert_eq!(
+        app.world().resource::<EventLog>().0,
+        vec![LifecycleEvent::FitFailed(FitError::NoMesh)]
+    );
 }
 
 #[test]
-fn conflict_last_wins_animation_cancels_animation() {
+fn async_fit_first_wins_rejects_requests_while_pending() {
     let mut app = App::new();
     app.add_plugins(MinimalPlugins);
     app.add_plugins(PanOrbitCameraExtPlugin);
     add_lifecycle_log_observers(&mut app);
 
-    let camera = app
-        .world_mut()
-        .spawn((PanOrbitCamera::default(), AnimationConflictPolicy::LastWins))
-        .id();
+    let (camera, target) = spawn_fit_camera_and_target(&mut app);
+    app.world_mut()
+        .entity_mut(camera)
+        .insert((FitExecution::Async, AnimationConflictPolicy::FirstWins));
+    app.update();
 
-    // Start first animation (long duration so it's still in-flight)
+    app.world_mut().trigger(
+        AnimateToFit::new(camera, target)
+            .yaw(1.0)
+            .duration(Duration::ZERO),
+    );
+    app.world_mut().flush();
+    app.world_mut().trigger(
+        AnimateToFit::new(camera, target)
+            .yaw(-1.0)
+            .duration(Duration::ZERO),
+    );
+    app.world_mut().flush();
     app.world_mut().trigger(PlayAnimation::new(
         camera,
-        VecDeque::from([make_move(Duration::from_millis(5000))]),
+        [make_move(Duration::from_secs(1))],
     ));
-    app.update();
+    update_until_fit_solved(&mut app, camera);
 
-    // Clear the log to isolate the second trigger's events
-    app.world_mut().resource_mut::<EventLog>().0.clear();
+    let panorbit = app
+        .world()
+        .get::<PanOrbitCamera>(camera)
+        .expect("camera should exist");
+    assert!((panorbit.target_yaw - 1.0).abs() < 1e-6);
+    assert_eq!(
+        app.world().resource::<EventLog>().0,
+        vec![
+            LifecycleEvent::AnimationRejected,
+            LifecycleEvent::AnimationRejected,
+            LifecycleEvent::AnimationBegin,
+            LifecycleEvent::AnimationEnd,
+        ]
+    );
+}
 
-    // Trigger second animation while first is in-flight
+#[test]
+fn async_fit_first_wins_rejects_requests_during_an_animation_without_solving() {
+    let mut app = App::new();
+    app.add_plugins(MinimalPlugins);
+    app.add_plugins(PanOrbitCameraExtPlugin);
+    add_lifecycle_log_observers(&mut app);
+
+    let (camera, target) = spawn_fit_camera_and_target(&mut app);
+    app.world_mut()
+        .entity_mut(camera)
+        .insert((FitExecution::Async, AnimationConflictPolicy::FirstWins));
     app.world_mut().trigger(PlayAnimation::new(
         camera,
-        VecDeque::from([make_move(Duration::from_millis(500))]),
+        [make_move(Duration::from_secs(60))],
     ));
     app.update();
 
-    let log = app.world().resource::<EventLog>();
+    app.world_mut()
+        .trigger(ZoomToFit::new(camera, target).duration(Duration::from_secs(1)));
+    app.world_mut().flush();
+
+    assert!(app.world().get::<PendingFit>(camera).is_none());
     assert_eq!(
-        log.0,
+        app.world().resource::<EventLog>().0,
         vec![
-            LifecycleEvent::AnimationCancelled,
             LifecycleEvent::AnimationBegin,
+            LifecycleEvent::AnimationRejected
         ]
     );
 }
 
 #[test]
-fn conflict_first_wins_rejects_second() {
+fn async_fit_first_wins_snaps_instant_fits_during_an_animation_like_immediate_fits() {
     let mut app = App::new();
     app.add_plugins(MinimalPlugins);
     app.add_plugins(PanOrbitCameraExtPlugin);
     add_lifecycle_log_observers(&mut app);
 
-    let camera = app
-        .world_mut()
-        .spawn((
-            PanOrbitCamera::default(),
-            AnimationConflictPolicy::FirstWins,
-        ))
-        .id();
-
-    // Start first animation
+    let (camera, target) = spawn_fit_camera_and_target(&mut app);
+    app.world_mut()
+        .entity_mut(camera)
+        .insert(AnimationConflictPolicy::FirstWins);
     app.world_mut().trigger(PlayAnimation::new(
         camera,
-        VecDeque::from([make_move(Duration::from_millis(5000))]),
+        [make_move(Duration::from_secs(60))],
     ));
     app.update();
 
-    // Clear the log
-    app.world_mut().resource_mut::<EventLog>().0.clear();
+    let instant_fit_events = |app: &mut App, execution: FitExecution| {
+        app.world_mut().entity_mut(camera).insert(execution);
+        app.world_mut().resource_mut::<EventLog>().0.clear();
+        app.world_mut().trigger(ZoomToFit::new(camera, target));
+        app.world_mut().flush();
+        update_until_fit_solved(app, camera);
+        app.world_mut().resource_mut::<EventLog>().0.clone()
+    };
 
-    // Trigger second animation — should be rejected
-    app.world_mut().trigger(PlayAnimation::new(
-        camera,
-        VecDeque::from([make_move(Duration::from_millis(500))]),
+    let immediate = instant_fit_events(&mut app, FitExecution::Immediate);
+    let background = instant_fit_events(&mut app, FitExecution::Async);
+    assert_eq!(
+        immediate,
+        vec![LifecycleEvent::ZoomBegin, LifecycleEvent::ZoomEnd]
+    );
+    assert_eq!(background, immediate);
+}
+
+#[test]
+fn zoom_to_fit_waiting_for_load_begins_once_the_target_has_loaded() {
+    let mut app = App::new();
+    app.add_plugins(MinimalPlugins);
+    app.add_plugins(PanOrbitCameraExtPlugin);
+    add_lifecycle_log_observers(&mut app);
+
+    let (camera, _) = spawn_fit_camera_and_target(&mut app);
+    // A scene root whose children have not been spawned yet.
+    let root = app.world_mut().spawn(GlobalTransform::default()).id();
+    app.update();
+
+    app.world_mut()
+        .trigger(ZoomToFit::new(camera, root).wait_for_load(Duration::from_secs(60)));
+    app.update();
+    assert!(app.world().get::<DeferredFit>(camera).is_some());
+
+    let handle = app.world().resource::<Assets<Mesh>>().reserve_handle();
+    app.world_mut().spawn((
+        Mesh3d(handle.clone()),
+        GlobalTransform::default(),
+        ChildOf(root),
     ));
     app.update();
+    assert!(app.world().get::<DeferredFit>(camera).is_some());
+    assert!(app.world().resource::<EventLog>().0.is_empty());
 
-    let log = app.world().resource::<EventLog>();
-    assert_eq!(log.0, vec![LifecycleEvent::AnimationRejected]);
+    app.world_mut()
+        .resource_mut::<Assets<Mesh>>()
+        .insert(&handle, Cuboid::new(1.0, 1.0, 1.0).into())
+        .expect("reserved handle should accept the mesh");
+    app.update();
 
-    // Original queue should still be present
-    assert!(app.world().get::<CameraMoveList>(camera).is_some());
+    assert!(app.world().get::<DeferredFit>(camera).is_none());
+    assert_eq!(
+        app.world().resource::<EventLog>().0,
+        vec![LifecycleEvent::ZoomBegin, LifecycleEvent::ZoomEnd]
+    );
+    let expected = solved_radius(&mut app, camera, root);
+    let panorbit = app
+        .world()
+        .get::<PanOrbitCamera>(camera)
+        .expect("camera should exist");
+    assert!((panorbit.target_radius - expected).abs() < 1e-4);
 }
 
 #[test]
-fn conflict_first_wins_allows_after_completion() {
+fn deferred_fit_reports_failure_after_its_load_timeout() {
     let mut app = App::new();
     app.add_plugins(MinimalPlugins);
     app.add_plugins(PanOrbitCameraExtPlugin);
     add_lifecycle_log_observers(&mut app);
 
-    let camera = app
+    let (camera, _) = spawn_fit_camera_and_target(&mut app);
+    let loading = app
         .world_mut()
         .spawn((
-            PanOrbitCamera::default(),
-            AnimationConflictPolicy::FirstWins,
+            Mesh3d(Handle::<Mesh>::default()),
+            GlobalTransform::default(),
         ))
         .id();
+    app.update();
 
-    // Start a zero-duration animation (completes instantly)
+    app.world_mut()
+        .trigger(AnimateToFit::new(camera, loading).wait_for_load(Duration::from_millis(20)));
+    for _ in 0..1000 {
+        app.update();
+        if app.world().get::<DeferredFit>(camera).is_none() {
+            break;
+        }
+        std::thread::sleep(Duration::from_millis(1));
+    }
+
+    assert!(app.world().get::<DeferredFit>(camera).is_none());
+    assert_eq!(
+        app.world().resource::<EventLog>().0,
+        vec![LifecycleEvent::FitFailed(FitError::MeshNotLoaded)]
+    );
+}
+
+#[test]
+fn deferred_fit_first_wins_rejects_requests_while_waiting() {
+    let mut app = App::new();
+    app.add_plugins(MinimalPlugins);
+    app.add_plugins(PanOrbitCameraExtPlugin);
+    add_lifecycle_log_observers(&mut app);
+
+    let (camera, target) = spawn_fit_camera_and_target(&mut app);
+    app.world_mut()
+        .entity_mut(camera)
+        .insert(AnimationConflictPolicy::FirstWins);
+    let loading = app
+        .world_mut()
+        .spawn((
+            Mesh3d(Handle::<Mesh>::default()),
+            GlobalTransform::default(),
+        ))
+        .id();
+    app.update();
+
+    app.world_mut()
+        .trigger(ZoomToFit::new(camera, loading).wait_for_load(Duration::from_secs(60)));
+    app.world_mut().flush();
+    app.world_mut().trigger(ZoomToFit::new(camera, target));
+    app.world_mut().flush();
     app.world_mut().trigger(PlayAnimation::new(
         camera,
-        VecDeque::from([make_move(Duration::ZERO)]),
+        [make_move(Duration::from_secs(1))],
+    ));
+    app.update();
+
+    assert!(app.world().get::<DeferredFit>(camera).is_some());
+    assert!(app.world().get::<CameraMoveList>(camera).is_none());
+    assert_eq!(
+        app.world().resource::<EventLog>().0,
+        vec![
+            LifecycleEvent::AnimationRejected,
+            LifecycleEvent::AnimationRejected
+        ]
+    );
+}
+
+#[test]
+fn zoom_to_fit_on_a_zero_scale_mesh_reports_zero_extent() {
+    let mut app = App::new();
+    app.add_plugins(MinimalPlugins);
+    app.add_plugins(PanOrbitCameraExtPlugin);
+    add_lifecycle_log_observers(&mut app);
+
+    let (camera, target) = spawn_fit_camera_and_target(&mut app);
+    app.world_mut()
+        .entity_mut(target)
+        .insert(GlobalTransform::from_scale(Vec3::ZERO));
+
+    app.world_mut().trigger(ZoomToFit::new(camera, target));
+    app.update();
+
+    assert_eq!(
+        app.world().resource::<EventLog>().0,
+        vec![LifecycleEvent::FitFailed(FitError::ZeroExtent)]
+    );
+}
+
+#[test]
+fn fit_fallback_frames_an_empty_marker_like_a_sphere_of_min_radius() {
+    let mut app = App::new();
+    app.add_plugins(MinimalPlugins);
+    app.add_plugins(PanOrbitCameraExtPlugin);
+    add_lifecycle_log_observers(&mut app);
+
+    let (camera, _) = spawn_fit_camera_and_target(&mut app);
+    app.world_mut()
+        .entity_mut(camera)
+        .insert(FitFallback::new(2.0));
+    let position = Vec3::new(3.0, 1.0, -2.0);
+    let marker = app
+        .world_mut()
+        .spawn(GlobalTransform::from_translation(position))
+        .id();
+    app.update();
+
+    let sphere = app
+        .world_mut()
+        .run_system_once(move |mut solver: FitSolver| {
+            solver.solve(
+                camera,
+                FitTarget::Sphere(BoundingSphere::new(position, 2.0)),
+                FitOptions::default(),
+            )
+        })
+        .expect("system should run")
+        .expect("fit should succeed")
+        .solution;
+    assert!(!sphere.limits.min_radius);
+
+    app.world_mut().trigger(ZoomToFit::new(camera, marker));
+    app.update();
+
+    let panorbit = app
+        .world()
+        .get::<PanOrbitCamera>(camera)
+        .expect("camera should exist");
+    assert!(panorbit.target_focus.distance(sphere.focus) < 1e-3);
+    assert!((panorbit.target_radius - sphere.radius).abs() < 1e-3);
+    assert_eq!(
+        app.world().resource::<EventLog>().0,
+        vec![
+            LifecycleEvent::FitLimited(FitLimits {
+                min_radius: true,
+                ..default()
+            }),
+            LifecycleEvent::ZoomBegin,
+            LifecycleEvent::ZoomEnd,
+        ]
+    );
+}
+
+#[test]
+fn mesh_region_fits_like_the_selected_world_points() {
+    let mut app = App::new();
+    app.add_plugins(MinimalPlugins);
+    app.add_plugins(PanOrbitCameraExtPlugin);
+
+    let (camera, target) = spawn_fit_camera_and_target(&mut app);
+    let mesh = Mesh::from(Cuboid::new(4.0, 1.0, 1.0));
+    let positions: Vec<Vec3> = mesh
+        .attribute(Mesh::ATTRIBUTE_POSITION)
+        .and_then(VertexAttributeValues::as_float3)
+        .expect("cuboid should have positions")
+        .iter()
+        .map(|pos| Vec3::from_array(*pos))
+        .collect();
+    let triangle_indices: Vec<usize> = mesh
+        .indices()
+        .expect("cuboid should be indexed")
+        .iter()
+        .take(6)
+        .collect();
+    let mesh_handle = app.world_mut().resource_mut::<Assets<Mesh>>().add(mesh);
+    let transform = GlobalTransform::from(
+        Transform::from_xyz(1.0, 2.0, -3.0).with_rotation(Quat::from_rotation_y(0.5)),
+    );
+    app.world_mut()
+        .entity_mut(target)
+        .insert((Mesh3d(mesh_handle), transform));
+    app.update();
+
+    let end_face: Vec<u32> = (0..positions.len() as u32)
+        .filter(|index| positions[*index as usize].x > 1.5)
+        .collect();
+    let cases = [
+        (
+            MeshRegion::Vertices(end_face.clone()),
+            end_face
+                .iter()
+                .map(|index| positions[*index as usize])
+                .collect::<Vec<_>>(),
+        ),
+        (
+            MeshRegion::Triangles(0..2),
+            triangle_indices
+                .iter()
+                .map(|index| positions[*index])
+                .collect(),
+        ),
+        (
+            MeshRegion::LocalAabb(Aabb3d::new(Vec3::new(2.0, 0.0, 0.0), Vec3::splat(0.5))),
+            end_face
+                .iter()
+                .map(|index| positions[*index as usize])
+                .collect(),
+        ),
+    ];
+
+    for (region, local_points) in cases {
+        let world_points: Vec<Vec3> = local_points
+            .iter()
+            .map(|pos| transform.transform_point(*pos))
+            .collect();
+        let (region_fit, points_fit) = app
+            .world_mut()
+            .run_system_once(move |mut solver: FitSolver| {
+                let options = FitOptions::default().yaw(0.3).pitch(0.2);
+                (
+                    solver.solve(
+                        camera,
+                        FitTarget::mesh_region(target, region.clone()),
+                        options,
+                    ),
+                    solver.solve(camera, FitTarget::Points(world_points.clone()), options),
+                )
+            })
+            .expect("system should run");
+        let region_fit = region_fit.expect("region fit should succeed").solution;
+        let points_fit = points_fit.expect("points fit should succeed").solution;
+        assert!(region_fit.focus.distance(points_fit.focus) < 1e-4);
+        assert!((region_fit.radius - points_fit.radius).abs() < 1e-4);
+    }
+}
+
+#[test]
+fn mesh_region_animated_pose_follows_the_skinned_mesh() {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, AssetPlugin::default()));
+    app.init_asset::<Mesh>();
+    app.init_asset::<SkinnedMeshInverseBindposes>();
+    app.add_plugins(PanOrbitCameraExtPlugin);
+
+    let (camera, target) = spawn_fit_camera_and_target(&mut app);
+    let joint_offset = Vec3::new(4.0, 0.0, 0.0);
+    skin_to_offset_joint(&mut app, target, joint_offset);
+    // The cube's +X face.
+    let region = MeshRegion::LocalAabb(Aabb3d::new(
+        Vec3::new(0.5, 0.0, 0.0),
+        Vec3::new(0.1, 0.6, 0.6),
     ));
-    app.update();
-    app.update();
-    app.update();
 
-    // Clear the log
-    app.world_mut().resource_mut::<EventLog>().0.clear();
+    let mut fit_focus = |pose: FitPose| {
+        app.world_mut().trigger(
+            ZoomToFit::new(camera, FitTarget::mesh_region(target, region.clone())).pose(pose),
+        );
+        app.update();
+        app.world()
+            .get::<PanOrbitCamera>(camera)
+            .expect("camera should exist")
+            .target_focus
+    };
 
-    // New animation should succeed since queue is gone
-    app.world_mut().trigger(PlayAnimation::new(
-        camera,
-        VecDeque::from([make_move(Duration::ZERO)]),
-    ));
-    app.update();
-    app.update();
-    app.update();
+    let face = Vec3::new(0.5, 0.0, 0.0);
+    let bind = fit_focus(FitPose::BindPose);
+    let animated = fit_focus(FitPose::Animated);
 
-    let log = app.world().resource::<EventLog>();
-    assert_eq!(
-        log.0,
-        vec![LifecycleEvent::AnimationBegin, LifecycleEvent::AnimationEnd]
+    assert!(
+        bind.distance(face) < 0.1,
+        "bind pose focus {bind:?} should stay on the face's rest position"
+    );
+    assert!(
+        animated.distance(face + joint_offset) < 0.1,
+        "animated focus {animated:?} should follow the joint"
     );
 }
 
 #[test]
-fn conflict_default_is_last_wins() {
+fn mesh_region_selecting_no_vertices_reports_no_mesh() {
     let mut app = App::new();
     app.add_plugins(MinimalPlugins);
     app.add_plugins(PanOrbitCameraExtPlugin);
     add_lifecycle_log_observers(&mut app);
 
-    // No AnimationConflictPolicy component — should default to LastWins
-    let camera = app.world_mut().spawn(PanOrbitCamera::default()).id();
-
-    // Start first animation
-    app.world_mut().trigger(PlayAnimation::new(
-        camera,
-        VecDeque::from([make_move(Duration::from_millis(5000))]),
-    ));
+    let (camera, target) = spawn_fit_camera_and_target(&mut app);
+    // A region is never a bare marker: the transform fallback must not frame it.
+    app.world_mut()
+        .entity_mut(camera)
+        .insert(FitFallback::new(1.0));
+    let marker = app.world_mut().spawn(GlobalTransform::default()).id();
     app.update();
 
-    // Clear the log
-    app.world_mut().resource_mut::<EventLog>().0.clear();
-
-    // Trigger second animation — should cancel first (LastWins behavior)
-    app.world_mut().trigger(PlayAnimation::new(
-        camera,
-        VecDeque::from([make_move(Duration::from_millis(500))]),
-    ));
+    for entity in [target, marker] {
+        app.world_mut().trigger(ZoomToFit::new(
+            camera,
+            FitTarget::mesh_region(entity, MeshRegion::Vertices(vec![1_000])),
+        ));
+    }
     app.update();
 
-    let log = app.world().resource::<EventLog>();
     assert_eq!(
-        log.0,
+        app.world().resource::<EventLog>().0,
         vec![
-            LifecycleEvent::AnimationCancelled,
-            LifecycleEvent::AnimationBegin,
+            LifecycleEvent::FitFailed(FitError::NoMesh),
+            LifecycleEvent::FitFailed(FitError::NoMesh)
         ]
     );
 }
 
-fn make_zoom_context() -> ZoomContext {
-    ZoomContext {
-        target:   Entity::PLACEHOLDER,
-        margin:   0.1,
-        duration: Duration::from_millis(500),
-        easing:   EaseFunction::Linear,
-    }
-}
-
-// ---------------------------------------------------------------------------
-// Zoom lifecycle: cancellation / rejection / interrupt scenarios
-// ---------------------------------------------------------------------------
-
 #[test]
-fn zoom_animated_first_wins_rejection_emits_only_animation_rejected() {
+fn fit_fallback_does_not_frame_a_target_whose_meshes_are_ignored() {
     let mut app = App::new();
     app.add_plugins(MinimalPlugins);
     app.add_plugins(PanOrbitCameraExtPlugin);
@@ -774,251 +3023,236 @@ fn zoom_animated_first_wins_rejection_emits_only_animation_rejected() {
     let (camera, target) = spawn_fit_camera_and_target(&mut app);
     app.world_mut()
         .entity_mut(camera)
-        .insert(AnimationConflictPolicy::FirstWins);
-
-    // Start a long animation so it's still in-flight
-    app.world_mut().trigger(PlayAnimation::new(
-        camera,
-        VecDeque::from([make_move(Duration::from_millis(5000))]),
-    ));
+        .insert(FitFallback::new(2.0));
+    app.world_mut().entity_mut(target).insert(FitIgnore);
     app.update();
 
-    // Clear the log to isolate the zoom trigger's events
-    app.world_mut().resource_mut::<EventLog>().0.clear();
-
-    // Trigger ZoomToFit (animated) — should be rejected with no ZoomBegin leak
-    app.world_mut().trigger(
-        ZoomToFit::new(camera, target)
-            .duration(Duration::from_millis(500))
-            .easing(EaseFunction::Linear),
-    );
+    app.world_mut().trigger(ZoomToFit::new(camera, target));
     app.update();
 
-    let log = app.world().resource::<EventLog>();
-    assert_eq!(log.0, vec![LifecycleEvent::AnimationRejected]);
+    assert_eq!(
+        app.world().resource::<EventLog>().0,
+        vec![LifecycleEvent::FitFailed(FitError::NoMesh)]
+    );
 }
 
 #[test]
-fn zoom_animated_last_wins_cancels_plain_animation() {
+fn fit_fallback_marker_waiting_for_load_fits_without_waiting() {
     let mut app = App::new();
     app.add_plugins(MinimalPlugins);
     app.add_plugins(PanOrbitCameraExtPlugin);
     add_lifecycle_log_observers(&mut app);
 
-    let camera = app
+    let (camera, _) = spawn_fit_camera_and_target(&mut app);
+    app.world_mut()
+        .entity_mut(camera)
+        .insert(FitFallback::new(2.0));
+    let marker = app
         .world_mut()
-        .spawn((PanOrbitCamera::default(), AnimationConflictPolicy::LastWins))
+        .spawn(GlobalTransform::from_translation(Vec3::new(3.0, 1.0, -2.0)))
         .id();
-
-    // Start a long plain animation (no zoom context)
-    app.world_mut().trigger(PlayAnimation::new(
-        camera,
-        VecDeque::from([make_move(Duration::from_millis(5000))]),
-    ));
     app.update();
 
-    app.world_mut().resource_mut::<EventLog>().0.clear();
-
-    // Trigger zoom animation — should cancel plain, then begin zoom
-    app.world_mut().trigger(
-        PlayAnimation::new(
-            camera,
-            VecDeque::from([make_move(Duration::from_millis(500))]),
-        )
-        .zoom_context(make_zoom_context()),
-    );
-    app.update();
+    app.world_mut()
+        .trigger(ZoomToFit::new(camera, marker).wait_for_load(Duration::from_secs(60)));
+    app.world_mut().flush();
 
-    let log = app.world().resource::<EventLog>();
+    assert!(app.world().get::<DeferredFit>(camera).is_none());
     assert_eq!(
-        log.0,
+        app.world().resource::<EventLog>().0,
         vec![
-            LifecycleEvent::AnimationCancelled,
+            LifecycleEvent::FitLimited(FitLimits {
+                min_radius: true,
+                ..default()
+            }),
             LifecycleEvent::ZoomBegin,
-            LifecycleEvent::AnimationBegin,
+            LifecycleEvent::ZoomEnd,
         ]
     );
 }
 
+#[derive(Component, Clone)]
+struct PointCloud(Vec<Vec3>);
+
+impl FitGeometry for PointCloud {
+    fn fit_shape(&self) -> Option<FitShape> {
+        (!self.0.is_empty()).then(|| FitShape::Points(self.0.clone()))
+    }
+}
+
 #[test]
-fn zoom_animated_last_wins_cancels_in_flight_zoom() {
+fn fit_geometry_component_fits_like_a_mesh_of_its_points() {
     let mut app = App::new();
     app.add_plugins(MinimalPlugins);
     app.add_plugins(PanOrbitCameraExtPlugin);
-    add_lifecycle_log_observers(&mut app);
-
-    let camera = app
+    app.register_fit_geometry::<PointCloud>();
+
+    let (camera, mesh) = spawn_fit_camera_and_target(&mut app);
+    let local_points = vec![
+        Vec3::new(-2.0, 0.0, 0.0),
+        Vec3::new(3.0, 1.0, 0.5),
+        Vec3::new(0.0, -1.0, 2.0),
+    ];
+    let transform =
+        GlobalTransform::from(Transform::from_xyz(4.0, 0.0, -1.0).with_scale(Vec3::splat(2.0)));
+    let cloud = app
         .world_mut()
-        .spawn((PanOrbitCamera::default(), AnimationConflictPolicy::LastWins))
+        .spawn((PointCloud(local_points.clone()), transform))
         .id();
-
-    // Start a long zoom animation
-    app.world_mut().trigger(
-        PlayAnimation::new(
-            camera,
-            VecDeque::from([make_move(Duration::from_millis(5000))]),
-        )
-        .zoom_context(make_zoom_context()),
-    );
-    app.update();
-
-    app.world_mut().resource_mut::<EventLog>().0.clear();
-
-    // Trigger another zoom — should cancel in-flight zoom, then begin new zoom
-    app.world_mut().trigger(
-        PlayAnimation::new(
-            camera,
-            VecDeque::from([make_move(Duration::from_millis(500))]),
-        )
-        .zoom_context(make_zoom_context()),
+    let twin_mesh = app.world_mut().resource_mut::<Assets<Mesh>>().add(
+        Mesh::new(PrimitiveTopology::PointList, RenderAssetUsages::default())
+            .with_inserted_attribute(Mesh::ATTRIBUTE_POSITION, local_points),
     );
+    let twin = app.world_mut().spawn((Mesh3d(twin_mesh), transform)).id();
     app.update();
 
-    let log = app.world().resource::<EventLog>();
-    assert_eq!(
-        log.0,
-        vec![
-            LifecycleEvent::AnimationCancelled,
-            LifecycleEvent::ZoomCancelled,
-            LifecycleEvent::ZoomBegin,
-            LifecycleEvent::AnimationBegin,
-        ]
-    );
+    let cases = [
+        (FitTarget::Entity(cloud), FitTarget::Entity(twin)),
+        (
+            FitTarget::Entities(vec![cloud, mesh]),
+            FitTarget::Entities(vec![twin, mesh]),
+        ),
+    ];
+    for (target, mesh_target) in cases {
+        let (target_fit, mesh_fit) = app
+            .world_mut()
+            .run_system_once(move |mut solver: FitSolver| {
+                let options = FitOptions::default().yaw(0.4).pitch(-0.1);
+                (
+                    solver.solve(camera, target.clone(), options),
+                    solver.solve(camera, mesh_target.clone(), options),
+                )
+            })
+            .expect("system should run");
+        let target_fit = target_fit.expect("geometry fit should succeed").solution;
+        let mesh_fit = mesh_fit.expect("mesh fit should succeed").solution;
+        assert!(target_fit.focus.distance(mesh_fit.focus) < 1e-4);
+        assert!((target_fit.radius - mesh_fit.radius).abs() < 1e-4);
+    }
 }
 
 #[test]
-fn zoom_animated_cancel_interrupt_emits_cancelled_and_zoom_cancelled() {
+fn zoom_to_fit_waits_for_fit_geometry_and_fails_once_it_is_removed() {
     let mut app = App::new();
     app.add_plugins(MinimalPlugins);
     app.add_plugins(PanOrbitCameraExtPlugin);
+    app.register_fit_geometry::<PointCloud>();
     add_lifecycle_log_observers(&mut app);
 
-    let camera = app
+    let (camera, _) = spawn_fit_camera_and_target(&mut app);
+    app.update();
+
+    let cloud = app
         .world_mut()
         .spawn((
-            PanOrbitCamera::default(),
-            CameraInputInterruptBehavior::Cancel,
+            PointCloud(vec![Vec3::ZERO, Vec3::new(1.0, 2.0, 3.0)]),
+            GlobalTransform::default(),
         ))
         .id();
-
-    // Start a zoom animation
-    app.world_mut().trigger(
-        PlayAnimation::new(
-            camera,
-            VecDeque::from([make_move(Duration::from_millis(5000))]),
-        )
-        .zoom_context(make_zoom_context()),
-    );
+    app.world_mut()
+        .trigger(ZoomToFit::new(camera, cloud).wait_for_load(Duration::from_secs(60)));
     app.update();
+    assert!(app.world().get::<DeferredFit>(camera).is_some());
 
-    // Simulate external user input by modifying camera targets
-    {
-        let mut camera = app
-            .world_mut()
-            .get_mut::<PanOrbitCamera>(camera)
-            .expect("camera should exist");
-        camera.target_focus = Vec3::new(100.0, 200.0, 300.0);
-        camera.target_yaw = 1.25;
-        camera.target_pitch = -0.75;
-        camera.target_radius = 12.5;
-    }
+    app.update();
+    assert!(app.world().get::<DeferredFit>(camera).is_none());
+    assert_eq!(
+        app.world().resource::<EventLog>().0,
+        vec![LifecycleEvent::ZoomBegin, LifecycleEvent::ZoomEnd]
+    );
 
+    app.world_mut().entity_mut(cloud).remove::<PointCloud>();
     app.update();
+    app.world_mut().resource_mut::<EventLog>().0.clear();
+    app.world_mut().trigger(ZoomToFit::new(camera, cloud));
     app.update();
-
-    let log = app.world().resource::<EventLog>();
     assert_eq!(
-        log.0,
-        vec![
-            LifecycleEvent::ZoomBegin,
-            LifecycleEvent::AnimationBegin,
-            LifecycleEvent::AnimationCancelled,
-            LifecycleEvent::ZoomCancelled,
-        ]
+        app.world().resource::<EventLog>().0,
+        vec![LifecycleEvent::FitFailed(FitError::NoMesh)]
     );
-    assert!(app.world().get::<CameraMoveList>(camera).is_none());
 }
 
 #[test]
-fn zoom_animated_complete_interrupt_emits_end_and_zoom_end() {
+fn fit_geometry_refits_changed_points_under_a_cached_volume() {
     let mut app = App::new();
     app.add_plugins(MinimalPlugins);
     app.add_plugins(PanOrbitCameraExtPlugin);
-    add_lifecycle_log_observers(&mut app);
-
-    let camera = app
+    app.register_fit_geometry::<PointCloud>();
+
+    let (camera, _) = spawn_fit_camera_and_target(&mut app);
+    let cloud_points = vec![
+        Vec3::new(-1.0, 0.0, 0.0),
+        Vec3::new(1.0, 0.5, 0.0),
+        Vec3::new(0.0, -0.5, 1.0),
+        Vec3::new(0.0, 0.0, -1.0),
+        Vec3::ZERO,
+    ];
+    let cloud = app
         .world_mut()
-        .spawn((
-            PanOrbitCamera::default(),
-            CameraInputInterruptBehavior::Complete,
-        ))
+        .spawn((PointCloud(cloud_points.clone()), GlobalTransform::default()))
         .id();
-
-    // Start a zoom animation
-    app.world_mut().trigger(
-        PlayAnimation::new(
-            camera,
-            VecDeque::from([make_move(Duration::from_millis(5000))]),
-        )
-        .zoom_context(make_zoom_context()),
-    );
     app.update();
 
-    // Simulate external user input
-    {
-        let mut camera = app
-            .world_mut()
-            .get_mut::<PanOrbitCamera>(camera)
-            .expect("camera should exist");
-        camera.target_focus = Vec3::new(-1.0, -1.0, -1.0);
-        camera.target_yaw = -1.0;
-        camera.target_pitch = -1.0;
-        camera.target_radius = 2.0;
-    }
+    let fit_radius = |app: &mut App| {
+        app.world_mut()
+            .run_system_once(move |mut solver: FitSolver| {
+                solver.solve(
+                    camera,
+                    cloud,
+                    FitOptions::default().volume(FitVolume::ConvexHull),
+                )
+            })
+            .expect("system should run")
+            .expect("fit should succeed")
+            .solution
+            .radius
+    };
+    let original = fit_radius(&mut app);
+    // A repeated fit reads the cached hull and lands on the same radius.
+    assert!((fit_radius(&mut app) - original).abs() < 1e-4);
 
-    app.update();
+    app.world_mut()
+        .get_mut::<PointCloud>(cloud)
+        .expect("cloud should exist")
+        .0 = cloud_points.iter().map(|point| *point * 2.0).collect();
     app.update();
 
-    let log = app.world().resource::<EventLog>();
-    assert_eq!(
-        log.0,
-        vec![
-            LifecycleEvent::ZoomBegin,
-            LifecycleEvent::AnimationBegin,
-            LifecycleEvent::AnimationEnd,
-            LifecycleEvent::ZoomEnd,
-        ]
+    let resized = fit_radius(&mut app);
+    assert!(
+        (resized - 2.0 * original).abs() < 0.05,
+        "radius {resized:.3} should double after the cloud doubles in size (was {original:.3})"
     );
-    assert!(app.world().get::<CameraMoveList>(camera).is_none());
 }
 
 #[test]
-fn zoom_animated_normal_completion_emits_full_lifecycle() {
+fn removing_fit_geometry_from_a_mesh_entity_leaves_it_loaded() {
     let mut app = App::new();
     app.add_plugins(MinimalPlugins);
     app.add_plugins(PanOrbitCameraExtPlugin);
+    app.register_fit_geometry::<PointCloud>();
     add_lifecycle_log_observers(&mut app);
 
-    let camera = app.world_mut().spawn(PanOrbitCamera::default()).id();
-
-    // Use a zero-duration CameraMove so the queue drains immediately
-    app.world_mut().trigger(
-        PlayAnimation::new(camera, VecDeque::from([make_move(Duration::ZERO)]))
-            .zoom_context(make_zoom_context()),
-    );
-    app.update();
+    let (camera, target) = spawn_fit_camera_and_target(&mut app);
+    app.world_mut()
+        .entity_mut(target)
+        .insert(PointCloud(vec![Vec3::splat(3.0)]));
     app.update();
+    app.world_mut().entity_mut(target).remove::<PointCloud>();
     app.update();
 
-    let log = app.world().resource::<EventLog>();
+    app.world_mut()
+        .trigger(ZoomToFit::new(camera, target).wait_for_load(Duration::from_secs(60)));
+    app.world_mut().flush();
+
+    assert!(app.world().get::<DeferredFit>(camera).is_none());
     assert_eq!(
-        log.0,
-        vec![
-            LifecycleEvent::ZoomBegin,
-            LifecycleEvent::AnimationBegin,
-            LifecycleEvent::AnimationEnd,
-            LifecycleEvent::ZoomEnd,
-        ]
+        app.world().resource::<EventLog>().0,
+        vec![LifecycleEvent::ZoomBegin, LifecycleEvent::ZoomEnd]
     );
-    assert!(app.world().get::<CameraMoveList>(camera).is_none());
+    let expected = solved_radius(&mut app, camera, target);
+    let panorbit = app
+        .world()
+        .get::<PanOrbitCamera>(camera)
+        .expect("camera should exist");
+    assert!((panorbit.target_radius - expected).abs() < 1e-4);
 }